@@ -1,11 +1,19 @@
 mod binding;
+pub mod cancellation_token;
 pub mod compilation;
+pub mod completion;
 pub mod diagnostic;
 pub mod diagnostic_kind;
+pub mod enum_symbol;
 pub mod errors;
 pub mod evaluator;
+pub mod function_symbol;
+pub mod global_scope;
+pub mod silver_integer;
 pub mod silver_type;
 pub mod silver_value;
+pub mod statistics;
 pub mod syntax;
 pub mod text;
+pub mod type_symbol;
 pub mod variable_symbol;
@@ -4,5 +4,11 @@ pub(crate) mod bound_binary_operator_kind;
 pub(crate) mod bound_expression;
 pub(crate) mod bound_node;
 pub(crate) mod bound_node_kind;
+pub(crate) mod bound_node_printer;
+pub(crate) mod bound_pattern;
+pub(crate) mod bound_program;
 pub(crate) mod bound_unary_operator;
 pub(crate) mod bound_unary_operator_kind;
+pub(crate) mod conversion;
+pub(crate) mod exhaustiveness;
+pub(crate) mod type_inference;
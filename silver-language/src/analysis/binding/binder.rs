@@ -1,39 +1,654 @@
-use std::collections::HashMap;
+use std::{collections::HashSet, rc::Rc};
 
 use crate::analysis::{
+    diagnostic::Diagnostic,
+    enum_symbol::{EnumSymbol, VariantSymbol},
     errors::error_reporter::ErrorReporter,
+    function_symbol::FunctionSymbol,
+    global_scope::GlobalScope,
+    silver_type::SilverType,
     silver_value::SilverValue,
-    syntax::{expression_syntax::ExpressionSyntax, syntax_token::SyntaxToken},
+    syntax::{
+        compilation_unit_syntax::CompilationUnitSyntax,
+        expression_syntax::{
+            ExpressionSyntax, FieldInitializerSyntax, InterpolatedStringPartSyntax, MatchArmSyntax,
+        },
+        member_syntax::{
+            FieldDeclarationSyntax, MemberSyntax, ParameterSyntax, VariantDeclarationSyntax,
+        },
+        pattern_syntax::PatternSyntax,
+        separated_syntax_list::SeparatedSyntaxList,
+        syntax_facts,
+        syntax_kind::SyntaxKind,
+        syntax_node::SyntaxNodeExt,
+        syntax_token::SyntaxToken,
+        type_syntax::{
+            TypeArgumentListSyntax, TypeClauseSyntax, TypeParameterListSyntax, TypeSyntax,
+        },
+    },
+    text::text_span::TextSpan,
+    type_symbol::TypeSymbol,
     variable_symbol::VariableSymbol,
 };
 
 use super::{
-    bound_binary_operator::BoundBinaryOperator, bound_expression::BoundExpression,
+    bound_binary_operator::BoundBinaryOperator,
+    bound_expression::{BoundExpression, BoundMatchArm},
+    bound_pattern::BoundPattern,
+    bound_program::BoundProgram,
     bound_unary_operator::BoundUnaryOperator,
+    bound_unary_operator_kind::BoundUnaryOperatorKind,
+    conversion::Conversion,
+    exhaustiveness,
+    type_inference::{self, TypeArgumentConflict, TypeInference},
 };
 
-pub(crate) struct Binder<'reporter, 'variables> {
-    error_reporter: &'reporter mut dyn ErrorReporter,
-    variables: &'variables mut HashMap<VariableSymbol, SilverValue>,
+pub(crate) struct Binder<'reporter, 'scope> {
+    error_reporter: BinderErrorReporter<'reporter>,
+    scope: &'scope GlobalScope,
+    /// Types declared by this submission, which only join the global scope
+    /// once it binds without errors.
+    declared_types: Vec<SilverType>,
+    /// Variables assigned by this submission, which shadow the global scope
+    /// for the expressions that follow them.
+    declared_variables: Vec<VariableSymbol>,
+    /// Functions declared by this submission, with their bodies.
+    declared_functions: Vec<(FunctionSymbol, BoundExpression)>,
+    /// The type parameters of the declaration being bound, which its types
+    /// may name.
+    type_parameters: Vec<String>,
+    /// The name of the struct or enum whose fields or payloads are being
+    /// bound, which may refer to it.
+    recursive_type: Option<String>,
+    /// The name of the function whose body is being bound, and its symbol
+    /// if its return type is declared so that the body may call it.
+    function: Option<(String, Option<FunctionSymbol>)>,
+    /// The variables bound by the patterns of the match arms being bound,
+    /// innermost last. Inside a function body, the first holds its
+    /// parameters and variables.
+    locals: Vec<Vec<VariableSymbol>>,
+    /// The nullable variables known not to be null in the expression being
+    /// bound, because a condition guarding it checked them.
+    non_null: Vec<VariableSymbol>,
+    /// The types inferred so far for the unannotated parameters of the
+    /// function or lambda being bound, while binding its body to infer them.
+    inference: Option<TypeInference>,
+    /// The lambdas being bound, innermost last, each with the index of the
+    /// frame in `locals` that holds its parameters and the variables of
+    /// enclosing scopes that it uses.
+    lambdas: Vec<(usize, Vec<VariableSymbol>)>,
+    /// The names of the variables that lambdas assign, which lose what
+    /// they were checked against at every call, as it may run one of them.
+    assigned_by_lambdas: HashSet<String>,
 }
 
-impl<'reporter, 'variables> Binder<'reporter, 'variables> {
+/// Forwards diagnostics to the reporter of the compilation, except while
+/// muted for a pass whose diagnostics the binder discards.
+struct BinderErrorReporter<'reporter> {
+    reporter: &'reporter mut dyn ErrorReporter,
+    muted: bool,
+}
+
+impl ErrorReporter for BinderErrorReporter<'_> {
+    fn report_error(&mut self, error: Diagnostic) {
+        if !self.muted {
+            self.reporter.report_error(error);
+        }
+    }
+
+    fn had_error(&self) -> bool {
+        self.reporter.had_error()
+    }
+
+    fn errors(&self) -> &[Diagnostic] {
+        self.reporter.errors()
+    }
+
+    fn clear(&mut self) {
+        self.reporter.clear();
+    }
+}
+
+impl<'reporter, 'scope> Binder<'reporter, 'scope> {
     pub(crate) fn new(
-        variables: &'variables mut HashMap<VariableSymbol, SilverValue>,
+        scope: &'scope GlobalScope,
         error_reporter: &'reporter mut dyn ErrorReporter,
     ) -> Self {
         Self {
-            error_reporter,
-            variables,
+            error_reporter: BinderErrorReporter {
+                reporter: error_reporter,
+                muted: false,
+            },
+            scope,
+            declared_types: vec![],
+            declared_variables: vec![],
+            declared_functions: vec![],
+            type_parameters: vec![],
+            recursive_type: None,
+            function: None,
+            locals: vec![],
+            non_null: vec![],
+            inference: None,
+            lambdas: vec![],
+            assigned_by_lambdas: scope.assigned_by_lambdas.clone(),
         }
     }
 
-    pub(crate) fn bind(&mut self, syntax: &ExpressionSyntax) -> BoundExpression {
-        self.bind_expression(syntax)
+    pub(crate) fn bind(&mut self, syntax: &CompilationUnitSyntax) -> BoundProgram {
+        let mut expressions = vec![];
+        for member in syntax.members.iter() {
+            match member {
+                MemberSyntax::StructDeclaration {
+                    identifier_token,
+                    type_parameter_list,
+                    fields,
+                    ..
+                } => {
+                    self.type_parameters = self.bind_type_parameters(type_parameter_list);
+                    self.bind_struct_declaration(identifier_token, fields);
+                }
+                MemberSyntax::EnumDeclaration {
+                    identifier_token,
+                    type_parameter_list,
+                    variants,
+                    ..
+                } => {
+                    self.type_parameters = self.bind_type_parameters(type_parameter_list);
+                    self.bind_enum_declaration(identifier_token, variants);
+                }
+                MemberSyntax::FunctionDeclaration {
+                    identifier_token,
+                    type_parameter_list,
+                    parameters,
+                    type_clause,
+                    body,
+                    ..
+                } => {
+                    self.type_parameters = self.bind_type_parameters(type_parameter_list);
+                    self.bind_function_declaration(identifier_token, parameters, type_clause, body);
+                }
+                MemberSyntax::GlobalExpression { expression } => {
+                    expressions.push(self.bind_expression(expression))
+                }
+            }
+        }
+        self.type_parameters.clear();
+        BoundProgram {
+            types: std::mem::take(&mut self.declared_types),
+            functions: std::mem::take(&mut self.declared_functions),
+            expressions,
+            assigned_by_lambdas: std::mem::take(&mut self.assigned_by_lambdas),
+        }
     }
 
-    fn bind_expression(&mut self, syntax: &ExpressionSyntax) -> BoundExpression {
+    /// The names of the type parameters of a generic declaration.
+    fn bind_type_parameters(
+        &mut self,
+        type_parameter_list: &Option<TypeParameterListSyntax>,
+    ) -> Vec<String> {
+        let mut names = Vec::<String>::new();
+        for identifier_token in type_parameter_list
+            .iter()
+            .flat_map(|list| list.parameters.iter())
+        {
+            let name = syntax_facts::normalize_identifier(identifier_token.text());
+            if names.contains(&name) {
+                self.error_reporter
+                    .report_duplicate_parameter(identifier_token.span(), &name);
+            } else {
+                names.push(name);
+            }
+        }
+        names
+    }
+
+    fn bind_struct_declaration(
+        &mut self,
+        identifier_token: &SyntaxToken,
+        fields: &SeparatedSyntaxList<FieldDeclarationSyntax>,
+    ) {
+        let name = syntax_facts::normalize_identifier(identifier_token.text());
+        self.recursive_type = Some(name.clone());
+        let mut symbols = Vec::<VariableSymbol>::new();
+        for field in fields.iter() {
+            let field_name = syntax_facts::normalize_identifier(field.identifier_token.text());
+            let ty = self.bind_type(&field.type_clause.type_syntax);
+            if symbols.iter().any(|symbol| symbol.name() == field_name) {
+                self.error_reporter
+                    .report_duplicate_field(field.identifier_token.span(), &field_name);
+            } else if let Some(ty) = ty {
+                symbols.push(VariableSymbol::new(field_name, ty));
+            }
+        }
+        self.recursive_type = None;
+        // A later declaration of the same name replaces the earlier one.
+        self.declared_types
+            .retain(|ty| ty.declared_name() != Some(&name));
+        let type_parameters = std::mem::take(&mut self.type_parameters);
+        self.declared_types
+            .push(SilverType::Struct(Rc::new(TypeSymbol::new(
+                name,
+                type_parameters,
+                symbols,
+            ))));
+    }
+
+    fn bind_enum_declaration(
+        &mut self,
+        identifier_token: &SyntaxToken,
+        variants: &SeparatedSyntaxList<VariantDeclarationSyntax>,
+    ) {
+        let name = syntax_facts::normalize_identifier(identifier_token.text());
+        self.recursive_type = Some(name.clone());
+        let mut symbols = Vec::<VariantSymbol>::new();
+        for variant in variants.iter() {
+            let variant_name = syntax_facts::normalize_identifier(variant.identifier_token.text());
+            let payload = variant
+                .payload
+                .iter()
+                .flat_map(|payload| payload.types.iter())
+                .map(|ty| self.bind_type(ty))
+                .collect::<Vec<_>>();
+            if symbols.iter().any(|symbol| symbol.name() == variant_name) {
+                self.error_reporter
+                    .report_duplicate_variant(variant.identifier_token.span(), &variant_name);
+            } else if let Some(payload) = payload.into_iter().collect() {
+                symbols.push(VariantSymbol::new(variant_name, payload));
+            }
+        }
+        self.recursive_type = None;
+        self.declared_types
+            .retain(|ty| ty.declared_name() != Some(&name));
+        let type_parameters = std::mem::take(&mut self.type_parameters);
+        self.declared_types
+            .push(SilverType::Enum(Rc::new(EnumSymbol::new(
+                name,
+                type_parameters,
+                symbols,
+            ))));
+    }
+
+    fn bind_function_declaration(
+        &mut self,
+        identifier_token: &SyntaxToken,
+        parameters: &SeparatedSyntaxList<ParameterSyntax>,
+        type_clause: &Option<TypeClauseSyntax>,
+        body: &ExpressionSyntax,
+    ) {
+        let name = syntax_facts::normalize_identifier(identifier_token.text());
+        let (mut symbols, unannotated) = self.bind_parameters(parameters);
+        let return_type = type_clause.as_ref().map(|type_clause| {
+            self.bind_type(&type_clause.type_syntax)
+                .unwrap_or(SilverType::Error)
+        });
+        let bound_body =
+            self.bind_with_inferred_parameters(&mut symbols, unannotated, |binder, symbols| {
+                binder.bind_function_body(&name, symbols, &return_type, body)
+            });
+
+        let ty = return_type.unwrap_or_else(|| bound_body.ty());
+        self.declared_functions
+            .retain(|(function, _)| function.name() != name);
+        let type_parameters = std::mem::take(&mut self.type_parameters);
+        self.declared_functions.push((
+            FunctionSymbol::new(name, type_parameters, symbols, ty),
+            bound_body,
+        ));
+    }
+
+    /// The symbols of `parameters`, with the unannotated ones by their index
+    /// among the symbols. These have an error type until inferred.
+    fn bind_parameters<'syntax>(
+        &mut self,
+        parameters: &'syntax SeparatedSyntaxList<ParameterSyntax>,
+    ) -> (Vec<VariableSymbol>, Vec<(usize, &'syntax SyntaxToken)>) {
+        let mut symbols = Vec::<VariableSymbol>::new();
+        let mut unannotated = vec![];
+        for parameter in parameters.iter() {
+            let parameter_name =
+                syntax_facts::normalize_identifier(parameter.identifier_token.text());
+            let ty = parameter
+                .type_clause
+                .as_ref()
+                .map(|type_clause| self.bind_type(&type_clause.type_syntax));
+            if symbols.iter().any(|symbol| symbol.name() == parameter_name) {
+                self.error_reporter
+                    .report_duplicate_parameter(parameter.identifier_token.span(), &parameter_name);
+                continue;
+            }
+            if ty.is_none() {
+                unannotated.push((symbols.len(), &parameter.identifier_token));
+            }
+            // A parameter whose type failed to bind still shadows any
+            // variable of the same name.
+            let ty = ty.flatten().unwrap_or(SilverType::Error);
+            symbols.push(VariableSymbol::new(parameter_name, ty));
+        }
+        (symbols, unannotated)
+    }
+
+    /// Bind a body with `bind_body`, given the symbols of its parameters,
+    /// after inferring the types of the `unannotated` ones by binding it once
+    /// with a type variable for each. In the body of a function whose own
+    /// parameters are being inferred, the variables join that inference and
+    /// the body is bound just once.
+    fn bind_with_inferred_parameters<T>(
+        &mut self,
+        symbols: &mut [VariableSymbol],
+        unannotated: Vec<(usize, &SyntaxToken)>,
+        mut bind_body: impl FnMut(&mut Self, &[VariableSymbol]) -> T,
+    ) -> T {
+        if unannotated.is_empty() {
+            return bind_body(self, symbols);
+        }
+        if let Some(inference) = &mut self.inference {
+            for (i, _) in &unannotated {
+                symbols[*i] =
+                    VariableSymbol::new(symbols[*i].name().to_string(), inference.fresh());
+            }
+            return bind_body(self, symbols);
+        }
+
+        let mut inference = TypeInference::default();
+        for (i, _) in &unannotated {
+            symbols[*i] = VariableSymbol::new(symbols[*i].name().to_string(), inference.fresh());
+        }
+        self.inference = Some(inference);
+        let muted = std::mem::replace(&mut self.error_reporter.muted, true);
+        bind_body(self, symbols);
+        self.error_reporter.muted = muted;
+        let inference = self.inference.take().unwrap();
+
+        for conflict in inference.conflicts() {
+            self.error_reporter.report_conflicting_types(
+                conflict.span.clone(),
+                conflict.expected.clone(),
+                conflict.actual.clone(),
+            );
+        }
+        let conflicted = !inference.conflicts().is_empty();
+        for (i, identifier_token) in unannotated {
+            let parameter_name = symbols[i].name().to_string();
+            let ty = inference.resolve(symbols[i].ty());
+            let ty = if inference.is_unknown(&ty) {
+                self.error_reporter
+                    .report_cannot_infer_parameter_type(identifier_token.span(), &parameter_name);
+                SilverType::Error
+            } else {
+                ty
+            };
+            symbols[i] = VariableSymbol::new(parameter_name, ty);
+        }
+
+        // The errors that a conflict leads to in the body would only repeat
+        // it.
+        let muted = self.error_reporter.muted;
+        self.error_reporter.muted |= conflicted;
+        let bound_body = bind_body(self, symbols);
+        self.error_reporter.muted = muted;
+        bound_body
+    }
+
+    /// Bind the body of the function `name`, which only sees its parameters
+    /// and its own variables.
+    fn bind_function_body(
+        &mut self,
+        name: &str,
+        parameters: &[VariableSymbol],
+        return_type: &Option<SilverType>,
+        body: &ExpressionSyntax,
+    ) -> BoundExpression {
+        let recursive_symbol = return_type.clone().map(|ty| {
+            FunctionSymbol::new(
+                name.to_string(),
+                self.type_parameters.clone(),
+                parameters.to_vec(),
+                ty,
+            )
+        });
+        let function = self.function.replace((name.to_string(), recursive_symbol));
+        let locals = std::mem::replace(&mut self.locals, vec![parameters.to_vec()]);
+        let non_null = std::mem::take(&mut self.non_null);
+        let bound_body = self.bind_expression(body);
+        let bound_body = match return_type {
+            Some(ty) => self.bind_implicit_conversion(body.span(), bound_body, ty.clone()),
+            None => bound_body,
+        };
+        self.function = function;
+        self.locals = locals;
+        self.non_null = non_null;
+        bound_body
+    }
+
+    fn bind_type(&mut self, syntax: &TypeSyntax) -> Option<SilverType> {
+        match syntax {
+            TypeSyntax::Name { identifier_token } => {
+                let name = syntax_facts::normalize_identifier(identifier_token.text());
+                match self.lookup_type(&name) {
+                    Some(ty) => self.bind_type_arguments(identifier_token.span(), ty, None),
+                    None => {
+                        self.error_reporter
+                            .report_undefined_type(identifier_token.span(), &name);
+                        None
+                    }
+                }
+            }
+            TypeSyntax::Generic {
+                identifier_token,
+                type_argument_list,
+            } => {
+                let name = syntax_facts::normalize_identifier(identifier_token.text());
+                match self.lookup_type(&name) {
+                    Some(ty) => {
+                        self.bind_type_arguments(syntax.span(), ty, Some(type_argument_list))
+                    }
+                    None => {
+                        self.error_reporter
+                            .report_undefined_type(identifier_token.span(), &name);
+                        None
+                    }
+                }
+            }
+            TypeSyntax::Array { element_type, .. } => self
+                .bind_type(element_type)
+                .map(|ty| SilverType::Array(Box::new(ty))),
+            TypeSyntax::Nullable { element_type, .. } => {
+                self.bind_type(element_type).map(SilverType::nullable)
+            }
+            TypeSyntax::Function {
+                parameters,
+                type_clause,
+                ..
+            } => {
+                let parameters = parameters
+                    .iter()
+                    .map(|parameter| self.bind_type(parameter))
+                    .collect::<Vec<_>>();
+                let ty = self.bind_type(&type_clause.type_syntax);
+                Some(SilverType::Function(
+                    parameters.into_iter().collect::<Option<_>>()?,
+                    Box::new(ty?),
+                ))
+            }
+        }
+    }
+
+    /// `ty` given the type arguments in `type_argument_list`, which a generic
+    /// type needs one of for each type parameter and other types must not
+    /// have.
+    fn bind_type_arguments(
+        &mut self,
+        span: TextSpan,
+        ty: SilverType,
+        type_argument_list: Option<&TypeArgumentListSyntax>,
+    ) -> Option<SilverType> {
+        let type_arguments = type_argument_list
+            .iter()
+            .flat_map(|list| list.arguments.iter())
+            .map(|argument| self.bind_type(argument))
+            .collect::<Vec<_>>();
+        let type_parameters = match &ty {
+            SilverType::Struct(symbol) if symbol.is_generic_definition() => {
+                symbol.type_parameters()
+            }
+            SilverType::Enum(symbol) if symbol.is_generic_definition() => symbol.type_parameters(),
+            SilverType::Recursive(..) => &self.type_parameters,
+            _ => &[],
+        };
+        if type_arguments.len() != type_parameters.len() {
+            let name = ty
+                .declared_name()
+                .map_or_else(|| ty.to_string(), str::to_string);
+            self.error_reporter.report_wrong_type_argument_count(
+                span,
+                &name,
+                type_parameters.len(),
+                type_arguments.len(),
+            );
+            return None;
+        }
+        let type_arguments = type_arguments.into_iter().collect::<Option<Vec<_>>>()?;
+        if let SilverType::Recursive(name, _) = ty {
+            // Each use of a generic type gives a reference to the type itself
+            // the type arguments of that use.
+            let regular = type_arguments
+                .iter()
+                .zip(&self.type_parameters)
+                .all(|(ty, parameter)| *ty == SilverType::Parameter(parameter.clone()));
+            if !regular {
+                self.error_reporter.report_irregular_recursive_type(
+                    span,
+                    &name,
+                    &self.type_parameters,
+                );
+                return None;
+            }
+            return Some(SilverType::Recursive(name, type_arguments));
+        }
+        Some(Self::instantiate(ty, type_arguments))
+    }
+
+    /// The generic type `ty` with `type_arguments` for its type parameters,
+    /// or `ty` itself if it takes none.
+    fn instantiate(ty: SilverType, type_arguments: Vec<SilverType>) -> SilverType {
+        match ty {
+            SilverType::Struct(symbol) if symbol.is_generic_definition() => {
+                SilverType::Struct(Rc::new(symbol.instantiate(type_arguments)))
+            }
+            SilverType::Enum(symbol) if symbol.is_generic_definition() => {
+                SilverType::Enum(Rc::new(symbol.instantiate(type_arguments)))
+            }
+            ty => ty,
+        }
+    }
+
+    /// The type argument for each of `type_parameters` of the generic `name`,
+    /// inferred from `arguments`, the types of the values given where the
+    /// types in `templates` are expected, with their spans.
+    fn infer_type_arguments(
+        &mut self,
+        span: TextSpan,
+        name: &str,
+        type_parameters: &[String],
+        templates: &[SilverType],
+        arguments: &[(TextSpan, SilverType)],
+    ) -> Option<Vec<SilverType>> {
+        let mut type_arguments = vec![None; type_parameters.len()];
+        for (template, (argument_span, ty)) in templates.iter().zip(arguments) {
+            let inferred = type_inference::infer_type_arguments(
+                type_parameters,
+                template,
+                ty,
+                &mut type_arguments,
+            );
+            if let Err(TypeArgumentConflict {
+                parameter,
+                first,
+                second,
+            }) = inferred
+            {
+                self.error_reporter.report_conflicting_type_arguments(
+                    argument_span.clone(),
+                    &parameter,
+                    first,
+                    second,
+                );
+                return None;
+            }
+        }
+        let missing = type_arguments.iter().position(Option::is_none);
+        if let Some(i) = missing {
+            // An argument of an error type may be what it was missing.
+            if arguments.iter().any(|(_, ty)| ty.is_error()) {
+                return None;
+            }
+            self.error_reporter
+                .report_cannot_infer_type_argument(span, name, &type_parameters[i]);
+            return None;
+        }
+        type_arguments.into_iter().collect()
+    }
+
+    /// The built-in or declared type called `name`, or a type parameter of
+    /// the declaration being bound, or that declaration itself.
+    fn lookup_type(&self, name: &str) -> Option<SilverType> {
+        if self
+            .type_parameters
+            .iter()
+            .any(|parameter| parameter == name)
+        {
+            return Some(SilverType::Parameter(name.to_string()));
+        }
+        if self.recursive_type.as_deref() == Some(name) {
+            return Some(SilverType::Recursive(name.to_string(), vec![]));
+        }
+        SilverType::lookup(name).or_else(|| {
+            self.declared_types
+                .iter()
+                .find(|ty| ty.declared_name() == Some(name))
+                .or_else(|| self.scope.types.get(name))
+                .cloned()
+        })
+    }
+
+    /// The type that `syntax` names, when it is a bare name that refers to a
+    /// type rather than to a variable, as in `Shape.Circle(1.0)`, or a type
+    /// with type arguments, as in `Option<int>.None`. A generic type named
+    /// bare is left for its uses to infer the type arguments of.
+    fn lookup_type_name(&mut self, syntax: &ExpressionSyntax) -> Option<SilverType> {
         match syntax {
+            ExpressionSyntax::Name { identifier_token } => {
+                let name = syntax_facts::normalize_identifier(identifier_token.text());
+                match self.lookup_variable(&name) {
+                    Some(_) => None,
+                    None => self.lookup_type(&name),
+                }
+            }
+            // A type that fails to bind is an error type, which the use
+            // passes over.
+            ExpressionSyntax::GenericName {
+                identifier_token,
+                type_argument_list,
+            } => {
+                let name = syntax_facts::normalize_identifier(identifier_token.text());
+                let ty = match self.lookup_type(&name) {
+                    Some(ty) => {
+                        self.bind_type_arguments(syntax.span(), ty, Some(type_argument_list))
+                    }
+                    None => {
+                        self.error_reporter
+                            .report_undefined_type(identifier_token.span(), &name);
+                        None
+                    }
+                };
+                Some(ty.unwrap_or(SilverType::Error))
+            }
+            _ => None,
+        }
+    }
+
+    fn bind_expression(&mut self, syntax: &ExpressionSyntax) -> BoundExpression {
+        let expression = match syntax {
             ExpressionSyntax::Literal {
                 literal_token,
                 value,
@@ -58,12 +673,105 @@ impl<'reporter, 'variables> Binder<'reporter, 'variables> {
             ExpressionSyntax::Name { identifier_token } => {
                 self.bind_name_expression(identifier_token)
             }
+            ExpressionSyntax::GenericName { .. } => {
+                unreachable!("the parser only reads a generic name before a member")
+            }
             ExpressionSyntax::Assignment {
                 identifier_token,
+                type_clause,
                 expression,
                 ..
-            } => self.bind_assignment_expression(identifier_token, expression),
-        }
+            } => self.bind_assignment_expression(identifier_token, type_clause, expression),
+            ExpressionSyntax::Call {
+                identifier_token,
+                type_argument_list,
+                arguments,
+                close_parenthesis_token,
+                ..
+            } => self.bind_call_expression(
+                identifier_token,
+                type_argument_list,
+                arguments,
+                close_parenthesis_token,
+            ),
+            ExpressionSyntax::Array {
+                open_bracket_token,
+                elements,
+                close_bracket_token,
+            } => self.bind_array_expression(open_bracket_token, elements, close_bracket_token),
+            ExpressionSyntax::Index {
+                expression, index, ..
+            } => self.bind_index_expression(expression, index),
+            ExpressionSyntax::ElementAssignment {
+                target, expression, ..
+            } => self.bind_element_assignment_expression(target, expression),
+            ExpressionSyntax::StructConstruction {
+                identifier_token,
+                type_argument_list,
+                fields,
+                ..
+            } => self.bind_struct_construction_expression(
+                identifier_token,
+                type_argument_list,
+                fields,
+            ),
+            ExpressionSyntax::FieldAccess {
+                expression,
+                dot_token,
+                identifier_token,
+            } => self.bind_field_access_expression(expression, dot_token, identifier_token),
+            ExpressionSyntax::FieldAssignment {
+                target, expression, ..
+            } => self.bind_field_assignment_expression(target, expression),
+            ExpressionSyntax::MemberCall {
+                expression,
+                identifier_token,
+                arguments,
+                ..
+            } => self.bind_member_call_expression(syntax, expression, identifier_token, arguments),
+            ExpressionSyntax::Match {
+                match_keyword,
+                expression,
+                arms,
+                ..
+            } => self.bind_match_expression(match_keyword, expression, arms),
+            ExpressionSyntax::Cast {
+                expression,
+                type_syntax,
+                ..
+            } => self.bind_cast_expression(expression, type_syntax),
+            ExpressionSyntax::Lambda {
+                parameters, body, ..
+            } => self.bind_lambda_expression(parameters, body),
+            ExpressionSyntax::Invocation {
+                expression,
+                arguments,
+                ..
+            } => {
+                let callee = self.bind_expression(expression);
+                let name = callee.ty().to_string();
+                self.bind_invocation(syntax.span(), expression.span(), callee, &name, arguments)
+            }
+            ExpressionSyntax::InterpolatedString { parts, .. } => {
+                self.bind_interpolated_string(parts)
+            }
+            ExpressionSyntax::CompoundAssignment {
+                target,
+                operator_token,
+                expression,
+            } => {
+                self.bind_update_expression(syntax, target, operator_token, Some(expression), false)
+            }
+            ExpressionSyntax::PrefixUpdate {
+                operator_token,
+                operand,
+            } => self.bind_update_expression(syntax, operand, operator_token, None, false),
+            ExpressionSyntax::PostfixUpdate {
+                operand,
+                operator_token,
+            } => self.bind_update_expression(syntax, operand, operator_token, None, true),
+        };
+        self.with_inferred_type(expression)
     }
 
     fn bind_literal_expression(
@@ -73,9 +781,83 @@ impl<'reporter, 'variables> Binder<'reporter, 'variables> {
     ) -> BoundExpression {
         value
             .map(|v| BoundExpression::Literal { value: Some(v) })
-            .unwrap_or(BoundExpression::Literal {
-                value: literal_token.value().cloned(),
+            .unwrap_or_else(|| BoundExpression::Literal {
+                value: self.literal_value(literal_token),
+            })
+    }
+
+    /// The value of a numeric literal, reporting one like `128i8` that only
+    /// fits its type once negated.
+    fn literal_value(&mut self, literal_token: &SyntaxToken) -> Option<SilverValue> {
+        if let Some((_, ty)) = Self::negated_literal(literal_token) {
+            self.error_reporter.report_number_out_of_range(
+                literal_token.span(),
+                literal_token.text(),
+                ty,
+            );
+            return None;
+        }
+        literal_token.value().cloned()
+    }
+
+    /// The value and type of `-literal_token` if the literal only fits its
+    /// type once negated, as the lexer leaves it as an `int`.
+    fn negated_literal(literal_token: &SyntaxToken) -> Option<(SilverValue, SilverType)> {
+        let value = literal_token.value()?;
+        let ty = syntax_facts::integer_suffix_type(literal_token.text())?;
+        if value.ty() == ty {
+            return None;
+        }
+        let value = SilverValue::integer(value.as_integer()?.checked_neg()?, &ty)?;
+        Some((value, ty))
+    }
+
+    /// Join the parts of an interpolated string, with each hole converted to
+    /// its string form.
+    fn bind_interpolated_string(
+        &mut self,
+        parts: &[InterpolatedStringPartSyntax],
+    ) -> BoundExpression {
+        let concatenation = BoundBinaryOperator::bind(
+            SyntaxKind::PlusToken,
+            &SilverType::String,
+            &SilverType::String,
+        )
+        .unwrap();
+        parts
+            .iter()
+            .map(|part| {
+                let expression = match part {
+                    InterpolatedStringPartSyntax::Text { text_token } => BoundExpression::Literal {
+                        value: text_token.value().cloned(),
+                    },
+                    InterpolatedStringPartSyntax::Interpolation { expression, .. } => {
+                        let bound_expression = self.bind_expression(expression);
+                        self.bind_explicit_conversion(
+                            expression.span(),
+                            bound_expression,
+                            SilverType::String,
+                        )
+                    }
+                };
+                (part.span(), expression)
             })
+            // Each part is joined on where it starts.
+            .reduce(|(_, left), (span, right)| {
+                let expression = BoundExpression::Binary {
+                    left: Box::new(left),
+                    operator: concatenation.clone(),
+                    right: Box::new(right),
+                    operator_span: span.start..span.start,
+                };
+                (span, expression)
+            })
+            .map_or(
+                BoundExpression::Literal {
+                    value: Some(SilverValue::String("".into())),
+                },
+                |(_, expression)| expression,
+            )
     }
 
     fn bind_binary_expression(
@@ -84,16 +866,115 @@ impl<'reporter, 'variables> Binder<'reporter, 'variables> {
         operator: &SyntaxToken,
         right: &ExpressionSyntax,
     ) -> BoundExpression {
-        let left = self.bind_expression(left);
-        let right = self.bind_expression(right);
-        let bound_operator = BoundBinaryOperator::bind(operator.kind(), left.ty(), right.ty());
+        let left_syntax = left;
+        let right_syntax = right;
+        if operator.kind() == SyntaxKind::QuestionQuestionToken {
+            return self.bind_coalesce_expression(left_syntax, right_syntax);
+        }
+        let left = self.bind_expression(left_syntax);
 
-        if let Some(operator) = bound_operator {
+        // The right operand of `&&` and `||` only runs once the left has
+        // decided nothing, which may rule out null for some variables.
+        let narrowed = match operator.kind() {
+            SyntaxKind::AmpersandAmpersandToken => self.non_null_when(left_syntax, true),
+            SyntaxKind::PipePipeToken => self.non_null_when(left_syntax, false),
+            _ => vec![],
+        };
+        let mut non_null = self.non_null.clone();
+        self.non_null.extend(narrowed);
+        let right = self.bind_expression(right_syntax);
+        // What the right operand assigned or called stays unchecked.
+        non_null.retain(|variable| self.non_null.contains(variable));
+        self.non_null = non_null;
+        self.bind_binary_operation(
+            operator,
+            operator.kind(),
+            left_syntax.span(),
+            left,
+            right_syntax.span(),
+            right,
+        )
+    }
+
+    /// Apply the binary operator `kind` to operands that are already bound.
+    /// Errors about the operator are reported at `operator`, which is the
+    /// `+=` of a compound assignment rather than a `+`.
+    fn bind_binary_operation(
+        &mut self,
+        operator: &SyntaxToken,
+        kind: SyntaxKind,
+        left_span: TextSpan,
+        mut left: BoundExpression,
+        right_span: TextSpan,
+        mut right: BoundExpression,
+    ) -> BoundExpression {
+        if let Some(inference) = &self.inference {
+            let conflicts = inference.conflicts().len();
+            (left, right) =
+                self.infer_operand_types(kind, left_span.clone(), left, right_span.clone(), right);
+            // The conflict would only lead to more of them.
+            if self.inference.as_ref().unwrap().conflicts().len() > conflicts {
+                return BoundExpression::Literal { value: None };
+            }
+        }
+
+        if matches!(
+            kind,
+            SyntaxKind::EqualsEqualsToken | SyntaxKind::BangEqualsToken
+        ) {
+            // Null compares with any nullable value, and other values compare
+            // as the nullable form of their common type.
+            if left.ty().is_nullable() || right.ty().is_nullable() {
+                let ty = SilverType::nullable(Self::common_type(&[left.ty(), right.ty()]));
+                if left.ty() != SilverType::Null
+                    && Conversion::classify(&left.ty(), &ty).is_implicit()
+                {
+                    left = Self::convert(left, ty.clone());
+                }
+                if right.ty() != SilverType::Null
+                    && Conversion::classify(&right.ty(), &ty).is_implicit()
+                {
+                    right = Self::convert(right, ty);
+                }
+            }
+        } else {
+            left = self.bind_non_null(left_span, left);
+            right = self.bind_non_null(right_span.clone(), right);
+        }
+        let mut bound_operator = BoundBinaryOperator::bind(kind, &left.ty(), &right.ty());
+
+        // Operators are only defined on like types, so give an integer
+        // constant the type of the other side if it fits, or else widen
+        // whichever side converts implicitly to the other.
+        if bound_operator.is_none() && left.ty() != right.ty() {
+            let (left_type, right_type) = (left.ty(), right.ty());
+            if let Some(constant) = Self::convert_constant(&right, &left_type) {
+                right = constant;
+            } else if let Some(constant) = Self::convert_constant(&left, &right_type) {
+                left = constant;
+            } else if Conversion::classify(&right_type, &left_type) == Conversion::Implicit {
+                right = Self::convert(right, left_type);
+            } else if Conversion::classify(&left_type, &right_type) == Conversion::Implicit {
+                left = Self::convert(left, right_type);
+            } else if Conversion::classify(&right_type, &left_type).exists()
+                && BoundBinaryOperator::bind(kind, &left_type, &left_type).is_some()
+            {
+                self.error_reporter
+                    .report_cannot_convert_implicitly(right_span, right_type, left_type);
+                return left;
+            }
+            bound_operator = BoundBinaryOperator::bind(kind, &left.ty(), &right.ty());
+        }
+
+        if let Some(bound_operator) = bound_operator {
             BoundExpression::Binary {
                 left: Box::new(left),
-                operator,
+                operator: bound_operator,
                 right: Box::new(right),
+                operator_span: operator.span(),
             }
+        } else if left.ty().is_error() || right.ty().is_error() {
+            BoundExpression::Literal { value: None }
         } else {
             self.error_reporter.report_undefined_binary_operator(
                 operator.span(),
@@ -105,19 +986,119 @@ impl<'reporter, 'variables> Binder<'reporter, 'variables> {
         }
     }
 
+    /// While inferring, require an operand whose type is not yet known to
+    /// have the type of the other, as operators are only defined on like
+    /// types, or to be a boolean for the logical operators. An operand whose
+    /// type was inferred conflicts with another it does not widen into or
+    /// from.
+    fn infer_operand_types(
+        &mut self,
+        kind: SyntaxKind,
+        left_span: TextSpan,
+        left: BoundExpression,
+        right_span: TextSpan,
+        right: BoundExpression,
+    ) -> (BoundExpression, BoundExpression) {
+        let inference = self.inference.as_mut().unwrap();
+        let (mut left_type, mut right_type) = (left.ty(), right.ty());
+        // Operators other than equality take the values without null, having
+        // reported that they may be null.
+        if !matches!(
+            kind,
+            SyntaxKind::EqualsEqualsToken | SyntaxKind::BangEqualsToken
+        ) {
+            left_type = left_type.non_null().clone();
+            right_type = right_type.non_null().clone();
+        }
+        let boolean = SilverType::Boolean;
+        match kind {
+            SyntaxKind::AmpersandAmpersandToken | SyntaxKind::PipePipeToken => {
+                if inference.is_unknown(&left_type) || Self::has_inferred_type(&left) {
+                    inference.unify(left_span, &boolean, &left_type);
+                }
+                if inference.is_unknown(&right_type) || Self::has_inferred_type(&right) {
+                    inference.unify(right_span, &boolean, &right_type);
+                }
+            }
+            _ if inference.is_unknown(&left_type) => {
+                inference.unify(left_span, &right_type, &left_type)
+            }
+            _ if inference.is_unknown(&right_type) => {
+                inference.unify(right_span, &left_type, &right_type)
+            }
+            _ if left_type != right_type
+                && left_type != SilverType::Null
+                && right_type != SilverType::Null
+                && !Conversion::classify(&left_type, &right_type).is_implicit()
+                && !Conversion::classify(&right_type, &left_type).is_implicit() =>
+            {
+                if Self::has_inferred_type(&left) {
+                    inference.unify(left_span, &right_type, &left_type);
+                } else if Self::has_inferred_type(&right) {
+                    inference.unify(right_span, &left_type, &right_type);
+                }
+            }
+            _ => {}
+        }
+        (
+            self.with_inferred_type(left),
+            self.with_inferred_type(right),
+        )
+    }
+
+    /// Bind `left ?? right`, which takes the non-null type of `left` unless
+    /// `right` may be null as well.
+    fn bind_coalesce_expression(
+        &mut self,
+        left: &ExpressionSyntax,
+        right: &ExpressionSyntax,
+    ) -> BoundExpression {
+        let left_syntax = left;
+        let right_syntax = right;
+        let left = self.bind_expression(left_syntax);
+        let right = self.bind_expression(right_syntax);
+        let ty = Self::common_type(&[left.ty().non_null().clone(), right.ty()]);
+        let left = self.bind_implicit_conversion(
+            left_syntax.span(),
+            left,
+            SilverType::nullable(ty.clone()),
+        );
+        let right = self.bind_implicit_conversion(right_syntax.span(), right, ty);
+        BoundExpression::Coalesce {
+            left: Box::new(left),
+            right: Box::new(right),
+        }
+    }
+
     fn bind_unary_expression(
         &mut self,
         operator: &SyntaxToken,
         operand: &ExpressionSyntax,
     ) -> BoundExpression {
-        let operand = self.bind_expression(operand);
-        let bound_operator = BoundUnaryOperator::bind(operator.kind(), operand.ty());
+        let operand_syntax = operand;
+        if let ExpressionSyntax::Literal { literal_token, .. } = operand_syntax {
+            if let Some((value, _)) = Self::negated_literal(literal_token)
+                .filter(|_| operator.kind() == SyntaxKind::MinusToken)
+            {
+                return BoundExpression::Literal { value: Some(value) };
+            }
+        }
+        let operand = self.bind_expression(operand_syntax);
+        let operand = self.bind_non_null(operand_syntax.span(), operand);
+        let operand = if operator.kind() == SyntaxKind::BangToken {
+            self.infer_type(operand_syntax.span(), operand, &SilverType::Boolean)
+        } else {
+            operand
+        };
+        let bound_operator = BoundUnaryOperator::bind(operator.kind(), &operand.ty());
 
         if let Some(operator) = bound_operator {
             BoundExpression::Unary {
                 operator,
                 operand: Box::new(operand),
             }
+        } else if operand.ty().is_error() {
+            operand
         } else {
             self.error_reporter.report_undefined_unary_operator(
                 operator.span(),
@@ -128,6 +1109,81 @@ impl<'reporter, 'variables> Binder<'reporter, 'variables> {
         }
     }
 
+    fn lookup_variable(&self, name: &str) -> Option<&VariableSymbol> {
+        self.find_variable(name).map(|(_, variable)| variable)
+    }
+
+    /// The variable called `name`, with the index of the frame in `locals`
+    /// that holds it, or `None` for a global.
+    fn find_variable(&self, name: &str) -> Option<(Option<usize>, &VariableSymbol)> {
+        let mut locals = self
+            .locals
+            .iter()
+            .enumerate()
+            .rev()
+            .flat_map(|(i, locals)| locals.iter().rev().map(move |variable| (Some(i), variable)));
+        // A function body only sees its own parameters and variables.
+        if self.function.is_some() {
+            return locals.find(|(_, variable)| variable.name() == name);
+        }
+        let globals = self
+            .declared_variables
+            .iter()
+            .rev()
+            .chain(self.scope.variables.keys())
+            .map(|variable| (None, variable));
+        locals
+            .chain(globals)
+            .find(|(_, variable)| variable.name() == name)
+    }
+
+    /// The variable called `name`, which every lambda being bound inside
+    /// the scope that declares it captures.
+    fn use_variable(&mut self, name: &str) -> Option<VariableSymbol> {
+        let (frame, variable) = self.find_variable(name)?;
+        let variable = variable.clone();
+        for (lambda_frame, captures) in &mut self.lambdas {
+            let outside = frame.is_none_or(|frame| frame < *lambda_frame);
+            if outside && !captures.contains(&variable) {
+                captures.push(variable.clone());
+            }
+        }
+        Some(variable)
+    }
+
+    /// Declare `variable` where an assignment to it is visible: beside a
+    /// local of the same name, in the lambda or function body being bound,
+    /// or else for the rest of the submission.
+    fn declare_variable(&mut self, variable: VariableSymbol) {
+        // A lambda declares its variables afresh rather than changing the
+        // type of those it captures.
+        let body = self.lambdas.last().map_or(0, |(frame, _)| *frame);
+        // Pattern variables stay local to their arm, even when reassigned
+        // with a new type.
+        match self.locals[body..]
+            .iter_mut()
+            .rev()
+            .find(|locals| locals.iter().any(|v| v.name() == variable.name()))
+        {
+            Some(locals) => locals.push(variable),
+            None if !self.lambdas.is_empty() || self.function.is_some() => {
+                self.locals[body].push(variable)
+            }
+            None => self.declared_variables.push(variable),
+        }
+    }
+
+    /// The declared function called `name`.
+    fn lookup_function(&self, name: &str) -> Option<FunctionSymbol> {
+        self.declared_functions
+            .iter()
+            .rev()
+            .map(|(function, _)| function)
+            .chain(self.scope.functions.get(name))
+            .find(|function| function.name() == name)
+            .cloned()
+    }
+
     // TODO may consider using the tokens in the future
     #[allow(unused_variables)]
     fn bind_parenthesized_expression(
@@ -140,37 +1196,1691 @@ impl<'reporter, 'variables> Binder<'reporter, 'variables> {
     }
 
     fn bind_name_expression(&mut self, identifier_token: &SyntaxToken) -> BoundExpression {
-        let name = identifier_token.text();
-        let variable = self.variables.keys().find(|var| var.name() == name);
-        if let Some(variable) = variable {
-            BoundExpression::Variable {
-                variable: variable.clone(),
+        let name = syntax_facts::normalize_identifier(identifier_token.text());
+        if let Some(variable) = self.use_variable(&name) {
+            let ty = self.resolve(variable.ty()).non_null().clone();
+            let narrowed = self.non_null.contains(&variable);
+            let expression = BoundExpression::Variable { variable };
+            if narrowed {
+                Self::convert(expression, ty)
+            } else {
+                expression
             }
+        } else if let Some(function) = self.bind_function_value(identifier_token, &name) {
+            function
         } else {
             self.error_reporter
-                .report_undefined_name(identifier_token.span(), name);
+                .report_undefined_name(identifier_token.span(), &name);
             BoundExpression::Literal {
-                value: Some(SilverValue::Integer(0)),
+                value: Some(SilverValue::Integer(0.into())),
             }
         }
     }
 
-    fn bind_assignment_expression(
+    /// The declared function called `name` as a value, if there is one. A
+    /// generic function has nothing to infer its type arguments from here.
+    fn bind_function_value(
         &mut self,
         identifier_token: &SyntaxToken,
-        expression: &ExpressionSyntax,
+        name: &str,
+    ) -> Option<BoundExpression> {
+        let function = match &self.function {
+            Some((function_name, function)) if function_name == name => function.clone(),
+            _ => Some(self.lookup_function(name)?),
+        };
+        let function = match function {
+            Some(function) => function,
+            None => {
+                self.error_reporter
+                    .report_missing_return_type(identifier_token.span(), name);
+                return Some(BoundExpression::Literal { value: None });
+            }
+        };
+        if let Some(parameter) = function.type_parameters().first() {
+            self.error_reporter.report_cannot_infer_type_argument(
+                identifier_token.span(),
+                name,
+                parameter,
+            );
+            return Some(BoundExpression::Literal { value: None });
+        }
+        Some(BoundExpression::Function { function })
+    }
+
+    fn bind_lambda_expression(
+        &mut self,
+        parameters: &SeparatedSyntaxList<ParameterSyntax>,
+        body: &ExpressionSyntax,
     ) -> BoundExpression {
-        let name = identifier_token.text();
-        let bound_expression = self.bind_expression(expression);
+        let (mut symbols, unannotated) = self.bind_parameters(parameters);
+        let (body, captures) =
+            self.bind_with_inferred_parameters(&mut symbols, unannotated, |binder, symbols| {
+                binder.bind_lambda_body(symbols, body)
+            });
+        BoundExpression::Lambda {
+            parameters: symbols,
+            captures,
+            body: Rc::new(body),
+        }
+    }
+
+    /// Bind the body of a lambda, which sees its parameters and the
+    /// variables in scope where it is written, with the variables it
+    /// captures from that scope.
+    fn bind_lambda_body(
+        &mut self,
+        parameters: &[VariableSymbol],
+        body: &ExpressionSyntax,
+    ) -> (BoundExpression, Vec<VariableSymbol>) {
+        self.locals.push(parameters.to_vec());
+        self.lambdas.push((self.locals.len() - 1, vec![]));
+        // The lambda may run after a checked variable is null again.
+        let non_null = std::mem::take(&mut self.non_null);
+        let body = self.bind_expression(body);
+        self.non_null = non_null;
+        let (_, captures) = self.lambdas.pop().unwrap();
+        self.locals.pop();
+        (body, captures)
+    }
+
+    /// Forget that the variables lambdas assign were checked against null,
+    /// after a call that may run one of the lambdas.
+    fn forget_checks_of_lambda_variables(&mut self) {
+        let assigned_by_lambdas = &self.assigned_by_lambdas;
+        self.non_null
+            .retain(|variable| !assigned_by_lambdas.contains(variable.name()));
+    }
 
-        let existing_variable = self.variables.keys().find(|v| v.name() == name);
-        if let Some(existing_variable) = existing_variable.cloned() {
-            self.variables.remove(&existing_variable);
+    /// Bind a call through `callee`, a function value bound from the
+    /// expression at `callee_span`, which errors call `name`.
+    fn bind_invocation(
+        &mut self,
+        span: TextSpan,
+        callee_span: TextSpan,
+        callee: BoundExpression,
+        name: &str,
+        arguments: &SeparatedSyntaxList<ExpressionSyntax>,
+    ) -> BoundExpression {
+        let callee = self.bind_non_null(callee_span.clone(), callee);
+        let bound_arguments = arguments
+            .iter()
+            .map(|argument| self.bind_expression(argument))
+            .collect::<Vec<_>>();
+        // A callee whose type is being inferred takes what the call gives it.
+        let callee = if self.is_unknown(&callee.ty()) {
+            let ty = SilverType::Function(
+                bound_arguments.iter().map(BoundExpression::ty).collect(),
+                Box::new(self.inference.as_mut().unwrap().fresh()),
+            );
+            self.infer_type(callee_span.clone(), callee, &ty)
+        } else {
+            callee
+        };
+        let (parameters, ty) = match callee.ty() {
+            SilverType::Function(parameters, ty) => (parameters, *ty),
+            SilverType::Error => return BoundExpression::Literal { value: None },
+            ty => {
+                self.error_reporter.report_not_a_function(callee_span, ty);
+                return BoundExpression::Literal { value: None };
+            }
+        };
+        if parameters.len() != bound_arguments.len() {
+            self.error_reporter.report_wrong_argument_count(
+                span,
+                name,
+                parameters.len(),
+                bound_arguments.len(),
+            );
+            return BoundExpression::Literal { value: None };
         }
-        let variable = VariableSymbol::new(name.to_string(), bound_expression.ty());
-        BoundExpression::Assignment {
-            variable,
-            expression: Box::new(bound_expression),
+        let arguments = arguments
+            .iter()
+            .zip(bound_arguments)
+            .zip(parameters)
+            .map(|((argument, expression), ty)| {
+                self.bind_implicit_conversion(argument.span(), expression, ty)
+            })
+            .collect();
+        self.forget_checks_of_lambda_variables();
+        BoundExpression::Invocation {
+            expression: Box::new(callee),
+            arguments,
+            ty: self.resolve(&ty),
+            span,
+        }
+    }
+
+    fn bind_call_expression(
+        &mut self,
+        identifier_token: &SyntaxToken,
+        type_argument_list: &Option<TypeArgumentListSyntax>,
+        arguments: &SeparatedSyntaxList<ExpressionSyntax>,
+        close_parenthesis_token: &SyntaxToken,
+    ) -> BoundExpression {
+        let name = syntax_facts::normalize_identifier(identifier_token.text());
+        // A variable hides a function of the same name.
+        if let Some(variable) = self.use_variable(&name) {
+            let span = identifier_token.span().start..close_parenthesis_token.span().end;
+            if let Some(type_argument_list) = type_argument_list {
+                self.error_reporter.report_wrong_type_argument_count(
+                    type_argument_list.span(),
+                    &name,
+                    0,
+                    type_argument_list.arguments.len(),
+                );
+                return BoundExpression::Literal { value: None };
+            }
+            let callee = self.with_inferred_type(BoundExpression::Variable { variable });
+            return self.bind_invocation(span, identifier_token.span(), callee, &name, arguments);
+        }
+        if let Some((function_name, function)) = &self.function {
+            if *function_name == name {
+                return match function.clone() {
+                    Some(function) => self.bind_function_call(
+                        function,
+                        identifier_token,
+                        type_argument_list,
+                        arguments,
+                        close_parenthesis_token,
+                    ),
+                    None => {
+                        self.error_reporter
+                            .report_missing_return_type(identifier_token.span(), &name);
+                        BoundExpression::Literal { value: None }
+                    }
+                };
+            }
+        }
+        if let Some(function) = self.lookup_function(&name) {
+            return self.bind_function_call(
+                function,
+                identifier_token,
+                type_argument_list,
+                arguments,
+                close_parenthesis_token,
+            );
+        }
+
+        let conversion_type = SilverType::lookup(&name);
+        if conversion_type.is_none() && name != "len" {
+            self.error_reporter
+                .report_undefined_function(identifier_token.span(), &name);
+            return BoundExpression::Literal { value: None };
+        }
+        if let Some(type_argument_list) = type_argument_list {
+            self.error_reporter.report_wrong_type_argument_count(
+                type_argument_list.span(),
+                &name,
+                0,
+                type_argument_list.arguments.len(),
+            );
+            return BoundExpression::Literal { value: None };
+        }
+
+        if arguments.len() != 1 {
+            self.error_reporter.report_wrong_argument_count(
+                identifier_token.span().start..close_parenthesis_token.span().end,
+                &name,
+                1,
+                arguments.len(),
+            );
+            return BoundExpression::Literal { value: None };
+        }
+        let argument = arguments.iter().next().unwrap();
+        match conversion_type {
+            Some(ty) => {
+                let expression = self.bind_expression(argument);
+                self.bind_explicit_conversion(argument.span(), expression, ty)
+            }
+            None => self.bind_array_length(argument),
         }
     }
+
+    /// Bind a call of `function`. The type arguments of a generic function
+    /// are inferred from the arguments unless the call gives them.
+    fn bind_function_call(
+        &mut self,
+        function: FunctionSymbol,
+        identifier_token: &SyntaxToken,
+        type_argument_list: &Option<TypeArgumentListSyntax>,
+        arguments: &SeparatedSyntaxList<ExpressionSyntax>,
+        close_parenthesis_token: &SyntaxToken,
+    ) -> BoundExpression {
+        let span = identifier_token.span().start..close_parenthesis_token.span().end;
+        if arguments.len() != function.parameters().len() {
+            self.error_reporter.report_wrong_argument_count(
+                span,
+                function.name(),
+                function.parameters().len(),
+                arguments.len(),
+            );
+            return BoundExpression::Literal { value: None };
+        }
+        let bound_arguments = arguments
+            .iter()
+            .map(|argument| self.bind_expression(argument))
+            .collect::<Vec<_>>();
+
+        let type_arguments = match type_argument_list {
+            Some(type_argument_list) => {
+                let type_arguments = type_argument_list
+                    .arguments
+                    .iter()
+                    .map(|argument| self.bind_type(argument))
+                    .collect::<Vec<_>>();
+                if type_arguments.len() != function.type_parameters().len() {
+                    self.error_reporter.report_wrong_type_argument_count(
+                        type_argument_list.span(),
+                        function.name(),
+                        function.type_parameters().len(),
+                        type_arguments.len(),
+                    );
+                    return BoundExpression::Literal { value: None };
+                }
+                type_arguments.into_iter().collect::<Option<Vec<_>>>()
+            }
+            None if function.type_parameters().is_empty() => Some(vec![]),
+            None => self.infer_type_arguments(
+                span.clone(),
+                function.name(),
+                function.type_parameters(),
+                &function
+                    .parameters()
+                    .iter()
+                    .map(|parameter| parameter.ty().clone())
+                    .collect::<Vec<_>>(),
+                &arguments
+                    .iter()
+                    .zip(&bound_arguments)
+                    .map(|(argument, expression)| (argument.span(), expression.ty()))
+                    .collect::<Vec<_>>(),
+            ),
+        };
+        let type_arguments = match type_arguments {
+            Some(type_arguments) => function
+                .type_parameters()
+                .iter()
+                .cloned()
+                .zip(type_arguments)
+                .collect::<Vec<_>>(),
+            None => return BoundExpression::Literal { value: None },
+        };
+
+        let arguments = arguments
+            .iter()
+            .zip(bound_arguments)
+            .zip(function.parameters())
+            .map(|((argument, expression), parameter)| {
+                let ty = parameter.ty().substitute(&type_arguments);
+                self.bind_implicit_conversion(argument.span(), expression, ty)
+            })
+            .collect();
+        let ty = function.ty().substitute(&type_arguments);
+        self.forget_checks_of_lambda_variables();
+        BoundExpression::Call {
+            function,
+            arguments,
+            ty,
+            span,
+        }
+    }
+
+    fn bind_cast_expression(
+        &mut self,
+        expression: &ExpressionSyntax,
+        type_syntax: &TypeSyntax,
+    ) -> BoundExpression {
+        let bound_expression = self.bind_expression(expression);
+        match self.bind_type(type_syntax) {
+            Some(ty) => self.bind_explicit_conversion(expression.span(), bound_expression, ty),
+            None => BoundExpression::Literal { value: None },
+        }
+    }
+
+    fn bind_array_length(&mut self, syntax: &ExpressionSyntax) -> BoundExpression {
+        let array = self.bind_array(syntax);
+        BoundExpression::ArrayLength {
+            array: Box::new(array),
+        }
+    }
+
+    fn bind_array_expression(
+        &mut self,
+        open_bracket_token: &SyntaxToken,
+        elements: &SeparatedSyntaxList<ExpressionSyntax>,
+        close_bracket_token: &SyntaxToken,
+    ) -> BoundExpression {
+        if elements.len() == 0 {
+            self.error_reporter.report_cannot_infer_empty_array_type(
+                open_bracket_token.span().start..close_bracket_token.span().end,
+            );
+            return BoundExpression::Literal { value: None };
+        }
+
+        let bound_elements = elements
+            .iter()
+            .map(|element| self.bind_expression(element))
+            .collect::<Vec<_>>();
+        let element_type = Self::common_type(
+            &bound_elements
+                .iter()
+                .map(BoundExpression::ty)
+                .collect::<Vec<_>>(),
+        );
+        let elements = elements
+            .iter()
+            .zip(bound_elements)
+            .map(|(syntax, element)| {
+                self.bind_implicit_conversion(syntax.span(), element, element_type.clone())
+            })
+            .collect();
+        BoundExpression::Array {
+            element_type,
+            elements,
+        }
+    }
+
+    fn bind_index_expression(
+        &mut self,
+        expression: &ExpressionSyntax,
+        index: &ExpressionSyntax,
+    ) -> BoundExpression {
+        let array = self.bind_array(expression);
+        let bound_index = self.bind_index(index);
+        if array.ty().element_type().is_none() {
+            return BoundExpression::Literal { value: None };
+        }
+        BoundExpression::Index {
+            array: Box::new(array),
+            index: Box::new(bound_index),
+            index_span: index.span(),
+        }
+    }
+
+    fn bind_element_assignment_expression(
+        &mut self,
+        target: &ExpressionSyntax,
+        expression: &ExpressionSyntax,
+    ) -> BoundExpression {
+        let (array_syntax, index) = match target {
+            ExpressionSyntax::Index {
+                expression, index, ..
+            } => (expression, index),
+            _ => unreachable!("the parser only assigns to index expressions"),
+        };
+        let array = self.bind_array(array_syntax);
+        let bound_index = self.bind_index(index);
+        let bound_expression = self.bind_expression(expression);
+        let element_type = match array.ty().element_type() {
+            Some(element_type) => element_type.clone(),
+            None => return bound_expression,
+        };
+        let bound_expression =
+            self.bind_implicit_conversion(expression.span(), bound_expression, element_type);
+        BoundExpression::ElementAssignment {
+            array: Box::new(array),
+            index: Box::new(bound_index),
+            index_span: index.span(),
+            expression: Box::new(bound_expression),
+        }
+    }
+
+    fn bind_struct_construction_expression(
+        &mut self,
+        identifier_token: &SyntaxToken,
+        type_argument_list: &Option<TypeArgumentListSyntax>,
+        fields: &SeparatedSyntaxList<FieldInitializerSyntax>,
+    ) -> BoundExpression {
+        let name = syntax_facts::normalize_identifier(identifier_token.text());
+        let symbol = match self.lookup_type(&name) {
+            Some(SilverType::Struct(symbol)) => Some(symbol),
+            Some(ty) => {
+                self.error_reporter
+                    .report_not_a_struct(identifier_token.span(), ty);
+                None
+            }
+            None => {
+                self.error_reporter
+                    .report_undefined_type(identifier_token.span(), &name);
+                None
+            }
+        };
+        let expressions = fields
+            .iter()
+            .map(|field| self.bind_expression(&field.expression))
+            .collect::<Vec<_>>();
+
+        // A generic struct takes the type arguments it is given, or infers
+        // them from the values of its fields.
+        let symbol = match symbol {
+            Some(symbol) if type_argument_list.is_some() => {
+                let span =
+                    identifier_token.span().start..type_argument_list.as_ref().unwrap().span().end;
+                match self.bind_type_arguments(
+                    span,
+                    SilverType::Struct(symbol),
+                    type_argument_list.as_ref(),
+                ) {
+                    Some(SilverType::Struct(symbol)) => Some(symbol),
+                    _ => None,
+                }
+            }
+            Some(symbol) if symbol.is_generic_definition() => {
+                let (templates, arguments) = fields
+                    .iter()
+                    .zip(&expressions)
+                    .filter_map(|(field, expression)| {
+                        let field_name =
+                            syntax_facts::normalize_identifier(field.identifier_token.text());
+                        let index = symbol.field_index(&field_name)?;
+                        Some((
+                            symbol.field_type(index),
+                            (field.expression.span(), expression.ty()),
+                        ))
+                    })
+                    .unzip::<_, _, Vec<_>, Vec<_>>();
+                self.infer_type_arguments(
+                    identifier_token.span(),
+                    &name,
+                    symbol.type_parameters(),
+                    &templates,
+                    &arguments,
+                )
+                .map(|type_arguments| Rc::new(symbol.instantiate(type_arguments)))
+            }
+            symbol => symbol,
+        };
+
+        let mut initialized = HashSet::new();
+        let mut bound_fields = vec![];
+        for (field, expression) in fields.iter().zip(expressions) {
+            let field_name = syntax_facts::normalize_identifier(field.identifier_token.text());
+            let symbol = match &symbol {
+                Some(symbol) => symbol,
+                None => continue,
+            };
+            if !initialized.insert(field_name.clone()) {
+                self.error_reporter
+                    .report_duplicate_field(field.identifier_token.span(), &field_name);
+            } else if let Some(index) = symbol.field_index(&field_name) {
+                let ty = symbol.field_type(index);
+                let expression =
+                    self.bind_implicit_conversion(field.expression.span(), expression, ty);
+                bound_fields.push((index, expression));
+            } else {
+                self.error_reporter.report_unknown_field(
+                    field.identifier_token.span(),
+                    SilverType::Struct(symbol.clone()),
+                    &field_name,
+                );
+            }
+        }
+
+        let symbol = match symbol {
+            Some(symbol) => symbol,
+            None => return BoundExpression::Literal { value: None },
+        };
+        let missing = symbol
+            .fields()
+            .iter()
+            .map(|field| field.name())
+            .filter(|name| !initialized.contains(*name))
+            .map(|name| name.to_string())
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            self.error_reporter.report_missing_fields(
+                identifier_token.span(),
+                SilverType::Struct(symbol.clone()),
+                missing,
+            );
+        }
+        BoundExpression::StructConstruction {
+            ty: symbol,
+            fields: bound_fields,
+        }
+    }
+
+    fn bind_field_access_expression(
+        &mut self,
+        expression: &ExpressionSyntax,
+        dot_token: &SyntaxToken,
+        identifier_token: &SyntaxToken,
+    ) -> BoundExpression {
+        let syntax = expression;
+        if let Some(ty) = self.lookup_type_name(syntax) {
+            let span = syntax.span().start..identifier_token.span().end;
+            return self.bind_variant_construction(span, syntax, ty, identifier_token, None);
+        }
+        let expression = self.bind_expression(syntax);
+        if dot_token.kind() == SyntaxKind::QuestionDotToken {
+            let ty = expression.ty().non_null().clone();
+            return match self.bind_field(ty, identifier_token) {
+                Some((field, index)) => BoundExpression::ConditionalFieldAccess {
+                    expression: Box::new(expression),
+                    field,
+                    index,
+                },
+                None => BoundExpression::Literal { value: None },
+            };
+        }
+        let expression = self.bind_non_null(syntax.span(), expression);
+        match self.bind_field(expression.ty(), identifier_token) {
+            Some((field, index)) => BoundExpression::FieldAccess {
+                expression: Box::new(expression),
+                field,
+                index,
+            },
+            None => BoundExpression::Literal { value: None },
+        }
+    }
+
+    fn bind_field_assignment_expression(
+        &mut self,
+        target: &ExpressionSyntax,
+        expression: &ExpressionSyntax,
+    ) -> BoundExpression {
+        let (struct_syntax, identifier_token) = match target {
+            ExpressionSyntax::FieldAccess {
+                expression,
+                identifier_token,
+                ..
+            } => (expression, identifier_token),
+            _ => unreachable!("the parser only assigns to field access expressions"),
+        };
+        let bound_target = self.bind_expression(struct_syntax);
+        let bound_target = self.bind_non_null(struct_syntax.span(), bound_target);
+        let field = self.bind_field(bound_target.ty(), identifier_token);
+        let bound_expression = self.bind_expression(expression);
+        let (field, index) = match field {
+            Some(field) => field,
+            None => return bound_expression,
+        };
+        let bound_expression =
+            self.bind_implicit_conversion(expression.span(), bound_expression, field.ty().clone());
+        BoundExpression::FieldAssignment {
+            target: Box::new(bound_target),
+            index,
+            expression: Box::new(bound_expression),
+        }
+    }
+
+    /// Resolve the field named by `identifier_token` on the struct type `ty`.
+    fn bind_field(
+        &mut self,
+        ty: SilverType,
+        identifier_token: &SyntaxToken,
+    ) -> Option<(VariableSymbol, usize)> {
+        let name = syntax_facts::normalize_identifier(identifier_token.text());
+        if let SilverType::Struct(symbol) = &ty {
+            if let Some(index) = symbol.field_index(&name) {
+                let field = VariableSymbol::new(name, symbol.field_type(index));
+                return Some((field, index));
+            }
+        }
+        if !ty.is_error() {
+            self.error_reporter
+                .report_unknown_field(identifier_token.span(), ty, &name);
+        }
+        None
+    }
+
+    fn bind_member_call_expression(
+        &mut self,
+        syntax: &ExpressionSyntax,
+        expression: &ExpressionSyntax,
+        identifier_token: &SyntaxToken,
+        arguments: &SeparatedSyntaxList<ExpressionSyntax>,
+    ) -> BoundExpression {
+        if let Some(ty) = self.lookup_type_name(expression) {
+            return self.bind_variant_construction(
+                syntax.span(),
+                expression,
+                ty,
+                identifier_token,
+                Some(arguments),
+            );
+        }
+        // Otherwise only the fields of structs that hold functions can be
+        // called.
+        let target = self.bind_expression(expression);
+        let name = syntax_facts::normalize_identifier(identifier_token.text());
+        if let SilverType::Struct(symbol) = target.ty().non_null() {
+            if let Some(index) = symbol.field_index(&name) {
+                let field = VariableSymbol::new(name.clone(), symbol.field_type(index));
+                let target = self.bind_non_null(expression.span(), target);
+                let callee = BoundExpression::FieldAccess {
+                    expression: Box::new(target),
+                    field,
+                    index,
+                };
+                let callee_span = expression.span().start..identifier_token.span().end;
+                return self.bind_invocation(syntax.span(), callee_span, callee, &name, arguments);
+            }
+        }
+        for argument in arguments.iter() {
+            self.bind_expression(argument);
+        }
+        if !target.ty().is_error() {
+            self.error_reporter
+                .report_undefined_function(identifier_token.span(), &name);
+        }
+        BoundExpression::Literal { value: None }
+    }
+
+    /// Bind `Enum.Variant` or `Enum.Variant(arguments)`, where `type_syntax`
+    /// names the type `ty`.
+    fn bind_variant_construction(
+        &mut self,
+        span: TextSpan,
+        type_syntax: &ExpressionSyntax,
+        ty: SilverType,
+        identifier_token: &SyntaxToken,
+        arguments: Option<&SeparatedSyntaxList<ExpressionSyntax>>,
+    ) -> BoundExpression {
+        let arguments = arguments.map(|arguments| arguments.iter().collect::<Vec<_>>());
+        let bound_arguments = arguments
+            .iter()
+            .flatten()
+            .map(|&argument| self.bind_expression(argument))
+            .collect::<Vec<_>>();
+        let symbol = match &ty {
+            SilverType::Enum(symbol) => symbol.clone(),
+            SilverType::Error => return BoundExpression::Literal { value: None },
+            _ => {
+                self.error_reporter
+                    .report_not_an_enum(type_syntax.span(), ty);
+                return BoundExpression::Literal { value: None };
+            }
+        };
+        let name = syntax_facts::normalize_identifier(identifier_token.text());
+        let variant = match symbol.variant_index(&name) {
+            Some(variant) => variant,
+            None => {
+                self.error_reporter
+                    .report_unknown_variant(identifier_token.span(), ty, &name);
+                return BoundExpression::Literal { value: None };
+            }
+        };
+        let payload_types = symbol.payload_types(variant);
+        if bound_arguments.len() != payload_types.len() {
+            self.error_reporter.report_wrong_argument_count(
+                span,
+                &format!("{}.{}", symbol.name(), name),
+                payload_types.len(),
+                bound_arguments.len(),
+            );
+            return BoundExpression::Literal { value: None };
+        }
+
+        // A generic enum named without type arguments infers them from the
+        // payload.
+        let symbol = if symbol.is_generic_definition() {
+            let arguments = arguments
+                .iter()
+                .flatten()
+                .zip(&bound_arguments)
+                .map(|(argument, expression)| (argument.span(), expression.ty()))
+                .collect::<Vec<_>>();
+            match self.infer_type_arguments(
+                span,
+                symbol.name(),
+                symbol.type_parameters(),
+                &payload_types,
+                &arguments,
+            ) {
+                Some(type_arguments) => Rc::new(symbol.instantiate(type_arguments)),
+                None => return BoundExpression::Literal { value: None },
+            }
+        } else {
+            symbol
+        };
+        let payload_types = symbol.payload_types(variant);
+        let payload = arguments
+            .iter()
+            .flatten()
+            .zip(bound_arguments)
+            .zip(payload_types)
+            .map(|((argument, expression), ty)| {
+                self.bind_implicit_conversion(argument.span(), expression, ty.clone())
+            })
+            .collect();
+        BoundExpression::VariantConstruction {
+            ty: symbol,
+            variant,
+            payload,
+        }
+    }
+
+    fn bind_match_expression(
+        &mut self,
+        match_keyword: &SyntaxToken,
+        expression: &ExpressionSyntax,
+        arms: &SeparatedSyntaxList<MatchArmSyntax>,
+    ) -> BoundExpression {
+        let expression_syntax = expression;
+        let expression = self.bind_expression(expression_syntax);
+        let ty = expression.ty();
+        let mut patterns = vec![];
+        let mut bodies = vec![];
+        // Once an arm has matched null, later arms only see the inner type.
+        let mut arm_type = ty.clone();
+        // The outcomes of a condition that the arms have yet to match, which
+        // rule out null in an arm as `&&` and `||` do in their right operand.
+        let mut outcomes = vec![true, false];
+        let mut non_null = self.non_null.clone();
+        for arm in arms.iter() {
+            self.locals.push(vec![]);
+            let pattern = self.bind_pattern(&arm.pattern, &arm_type);
+            let outcome = match &pattern {
+                Some(BoundPattern::Literal(SilverValue::Null)) => {
+                    arm_type = self.resolve(&ty).non_null().clone();
+                    None
+                }
+                &Some(BoundPattern::Literal(SilverValue::Boolean(outcome))) => {
+                    outcomes.retain(|&other| other != outcome);
+                    Some(outcome)
+                }
+                _ => match outcomes[..] {
+                    [outcome] => Some(outcome),
+                    _ => None,
+                },
+            };
+            if let Some(outcome) = outcome {
+                let narrowed = self.non_null_when(expression_syntax, outcome);
+                self.non_null.extend(narrowed);
+            }
+            patterns.push(pattern);
+            bodies.push(self.bind_expression(&arm.expression));
+            self.locals.pop();
+            // What an arm assigned or called stays unchecked.
+            non_null.retain(|variable| self.non_null.contains(variable));
+            self.non_null = non_null.clone();
+        }
+
+        // A pattern that failed to bind would skew the analysis.
+        let ty = self.resolve(&ty);
+        if !ty.is_error() && !self.is_unknown(&ty) {
+            if let Some(patterns) = patterns
+                .iter()
+                .map(Option::as_ref)
+                .collect::<Option<Vec<_>>>()
+            {
+                for (i, arm) in arms.iter().enumerate() {
+                    if !exhaustiveness::is_useful(&patterns[..i], patterns[i], &ty) {
+                        self.error_reporter
+                            .report_unreachable_arm(arm.pattern.span());
+                    }
+                }
+                let missing = exhaustiveness::missing_patterns(&patterns, &ty);
+                if !missing.is_empty() {
+                    self.error_reporter
+                        .report_non_exhaustive_match(match_keyword.span(), missing);
+                }
+            }
+        }
+
+        let result_type = if bodies.is_empty() {
+            SilverType::Null
+        } else {
+            Self::common_type(&bodies.iter().map(BoundExpression::ty).collect::<Vec<_>>())
+        };
+        let arms = arms
+            .iter()
+            .zip(patterns)
+            .zip(bodies)
+            .map(|((arm, pattern), body)| BoundMatchArm {
+                pattern: pattern.unwrap_or(BoundPattern::Binding(None)),
+                expression: self.bind_implicit_conversion(
+                    arm.expression.span(),
+                    body,
+                    result_type.clone(),
+                ),
+            })
+            .collect();
+        BoundExpression::Match {
+            expression: Box::new(expression),
+            arms,
+            ty: result_type,
+        }
+    }
+
+    /// Bind a pattern that matches values of type `ty`, declaring its
+    /// variables in the innermost local scope.
+    fn bind_pattern(&mut self, syntax: &PatternSyntax, ty: &SilverType) -> Option<BoundPattern> {
+        match syntax {
+            PatternSyntax::Literal {
+                literal_token,
+                value,
+            } => {
+                let value = match value {
+                    Some(value) => value.clone(),
+                    None => self.literal_value(literal_token)?,
+                };
+                self.bind_literal_pattern(literal_token, value, ty)
+                    .map(BoundPattern::Literal)
+            }
+            PatternSyntax::Binding { identifier_token } => {
+                let name = syntax_facts::normalize_identifier(identifier_token.text());
+                if name == "_" {
+                    return Some(BoundPattern::Binding(None));
+                }
+                let variable = VariableSymbol::new(name, ty.clone());
+                self.locals.last_mut().unwrap().push(variable.clone());
+                Some(BoundPattern::Binding(Some(variable)))
+            }
+            PatternSyntax::Variant {
+                type_identifier_token,
+                identifier_token,
+                payload,
+                ..
+            } => {
+                let type_name = syntax_facts::normalize_identifier(type_identifier_token.text());
+                let symbol = match self.lookup_type(&type_name) {
+                    Some(SilverType::Enum(symbol)) => symbol,
+                    Some(other) => {
+                        self.error_reporter
+                            .report_not_an_enum(type_identifier_token.span(), other);
+                        return None;
+                    }
+                    None => {
+                        self.error_reporter
+                            .report_undefined_type(type_identifier_token.span(), &type_name);
+                        return None;
+                    }
+                };
+                // The pattern of a generic enum has the type arguments of the
+                // value it matches.
+                let symbol = match self.resolve(ty).non_null() {
+                    SilverType::Enum(instance)
+                        if symbol.is_generic_definition() && instance.name() == symbol.name() =>
+                    {
+                        instance.clone()
+                    }
+                    _ => symbol,
+                };
+                let pattern_type = SilverType::Enum(symbol.clone());
+                if self.is_unknown(ty) {
+                    self.inference
+                        .as_mut()
+                        .unwrap()
+                        .unify(syntax.span(), &pattern_type, ty);
+                }
+                let ty = &self.resolve(ty);
+                if *ty.non_null() != pattern_type && !ty.is_error() {
+                    self.error_reporter.report_pattern_type_mismatch(
+                        syntax.span(),
+                        ty.clone(),
+                        pattern_type,
+                    );
+                    return None;
+                }
+                let name = syntax_facts::normalize_identifier(identifier_token.text());
+                let variant = match symbol.variant_index(&name) {
+                    Some(variant) => variant,
+                    None => {
+                        self.error_reporter.report_unknown_variant(
+                            identifier_token.span(),
+                            pattern_type,
+                            &name,
+                        );
+                        return None;
+                    }
+                };
+                let payload_types = symbol.payload_types(variant);
+                let patterns = payload
+                    .iter()
+                    .flat_map(|payload| payload.patterns.iter())
+                    .collect::<Vec<_>>();
+                if patterns.len() != payload_types.len() {
+                    self.error_reporter.report_wrong_argument_count(
+                        syntax.span(),
+                        &format!("{}.{}", symbol.name(), name),
+                        payload_types.len(),
+                        patterns.len(),
+                    );
+                }
+                // Extra patterns still declare their variables, so that the
+                // arm does not report them as undefined.
+                let payload = patterns
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, pattern)| {
+                        let ty = payload_types.get(i).cloned().unwrap_or(SilverType::Error);
+                        self.bind_pattern(pattern, &ty)
+                    })
+                    .collect::<Vec<_>>();
+                if payload.len() != payload_types.len() {
+                    return None;
+                }
+                Some(BoundPattern::Variant {
+                    variant,
+                    payload: payload.into_iter().collect::<Option<_>>()?,
+                })
+            }
+        }
+    }
+
+    /// The literal of a pattern as a value of the matched type `ty`. Integer
+    /// literals match any integer type they fit.
+    fn bind_literal_pattern(
+        &mut self,
+        literal_token: &SyntaxToken,
+        value: SilverValue,
+        ty: &SilverType,
+    ) -> Option<SilverValue> {
+        let value_type = value.ty();
+        if self.is_unknown(ty) {
+            self.inference
+                .as_mut()
+                .unwrap()
+                .unify(literal_token.span(), &value_type, ty);
+        }
+        let ty = &self.resolve(ty);
+        if value == SilverValue::Null && ty.is_nullable() {
+            return Some(value);
+        }
+        // Other literals match the inner type of a nullable type.
+        let ty = match value {
+            SilverValue::Null => ty,
+            _ => ty.non_null(),
+        };
+        if value_type == *ty {
+            return Some(value);
+        }
+        if value_type.is_integer() && ty.is_integer() {
+            let value = value
+                .as_integer()
+                .and_then(|value| SilverValue::integer(value, ty));
+            if value.is_none() {
+                self.error_reporter.report_number_out_of_range(
+                    literal_token.span(),
+                    literal_token.text(),
+                    ty.clone(),
+                );
+            }
+            return value;
+        }
+        if !ty.is_error() {
+            self.error_reporter.report_pattern_type_mismatch(
+                literal_token.span(),
+                ty.clone(),
+                value_type,
+            );
+        }
+        None
+    }
+
+    /// The first of the types that every other widens into, made nullable
+    /// if any of them is null.
+    fn common_type(types: &[SilverType]) -> SilverType {
+        // Types still being inferred take whatever type the others have.
+        let known = types
+            .iter()
+            .filter(|ty| !matches!(ty, SilverType::Variable(_)))
+            .collect::<Vec<_>>();
+        let (first, rest) = match known.split_first() {
+            Some(split) => split,
+            None => return types[0].clone(),
+        };
+        let mut ty = (*first).clone();
+        for &other in rest {
+            if Conversion::classify(&ty, other) == Conversion::Implicit {
+                ty = other.clone();
+            } else if *other == SilverType::Null {
+                ty = SilverType::nullable(ty);
+            } else {
+                let nullable = SilverType::nullable(other.clone());
+                if Conversion::classify(&ty, &nullable) == Conversion::Implicit
+                    && !Conversion::classify(other, &ty).is_implicit()
+                {
+                    ty = nullable;
+                }
+            }
+        }
+        ty
+    }
+
+    /// `expression` as a value of the inner type of its nullable type,
+    /// reporting that it may be null. Flow analysis lets a variable pass
+    /// once it has been checked against null.
+    fn bind_non_null(&mut self, span: TextSpan, expression: BoundExpression) -> BoundExpression {
+        match expression.ty() {
+            SilverType::Nullable(inner) => {
+                self.error_reporter
+                    .report_possibly_null(span, expression.ty());
+                Self::convert(expression, *inner)
+            }
+            _ => expression,
+        }
+    }
+
+    /// The nullable variables that cannot be null once `syntax` evaluates to
+    /// `outcome`, as in `x != null && x > 0` or `x == null || x > 0`.
+    fn non_null_when(&self, syntax: &ExpressionSyntax, outcome: bool) -> Vec<VariableSymbol> {
+        match syntax {
+            ExpressionSyntax::Parenthesized { expression, .. } => {
+                self.non_null_when(expression, outcome)
+            }
+            ExpressionSyntax::Unary { operator, operand }
+                if operator.kind() == SyntaxKind::BangToken =>
+            {
+                self.non_null_when(operand, !outcome)
+            }
+            ExpressionSyntax::Binary {
+                left,
+                operator,
+                right,
+            } => match (operator.kind(), outcome) {
+                (SyntaxKind::AmpersandAmpersandToken, true)
+                | (SyntaxKind::PipePipeToken, false) => {
+                    let mut variables = self.non_null_when(left, outcome);
+                    variables.extend(self.non_null_when(right, outcome));
+                    // An assignment along the way, or a call that runs a
+                    // lambda, may have made it null again.
+                    variables.retain(|variable| {
+                        let name = variable.name();
+                        let reassigned = assigns(syntax, name)
+                            || self.assigned_by_lambdas.contains(name) && calls(syntax);
+                        !reassigned
+                    });
+                    variables
+                }
+                (SyntaxKind::BangEqualsToken, true) | (SyntaxKind::EqualsEqualsToken, false) => {
+                    self.null_checked_variable(left, right)
+                        .or_else(|| self.null_checked_variable(right, left))
+                        .into_iter()
+                        .collect()
+                }
+                _ => vec![],
+            },
+            _ => vec![],
+        }
+    }
+
+    /// The nullable variable named by `name` when `other` is `null`.
+    fn null_checked_variable(
+        &self,
+        name: &ExpressionSyntax,
+        other: &ExpressionSyntax,
+    ) -> Option<VariableSymbol> {
+        match (name, other) {
+            (
+                ExpressionSyntax::Name { identifier_token },
+                ExpressionSyntax::Literal { literal_token, .. },
+            ) if literal_token.kind() == SyntaxKind::NullKeyword => {
+                let name = syntax_facts::normalize_identifier(identifier_token.text());
+                self.lookup_variable(&name)
+                    .filter(|variable| {
+                        matches!(self.resolve(variable.ty()), SilverType::Nullable(_))
+                    })
+                    .cloned()
+            }
+            _ => None,
+        }
+    }
+
+    /// Bind an expression that must produce an array.
+    fn bind_array(&mut self, syntax: &ExpressionSyntax) -> BoundExpression {
+        let array = self.bind_expression(syntax);
+        let array = self.bind_non_null(syntax.span(), array);
+        let array = if self.is_unknown(&array.ty()) {
+            let element_type = self.inference.as_mut().unwrap().fresh();
+            self.infer_type(
+                syntax.span(),
+                array,
+                &SilverType::Array(Box::new(element_type)),
+            )
+        } else {
+            array
+        };
+        let ty = array.ty();
+        if ty.element_type().is_none() && !ty.is_error() {
+            self.error_reporter.report_not_an_array(syntax.span(), ty);
+        }
+        array
+    }
+
+    /// Bind an expression used as an array index, which may be any integer.
+    fn bind_index(&mut self, syntax: &ExpressionSyntax) -> BoundExpression {
+        let index = self.bind_expression(syntax);
+        let index = self.bind_non_null(syntax.span(), index);
+        let index = self.infer_type(syntax.span(), index, &SilverType::Integer);
+        let ty = index.ty();
+        if !ty.is_integer() && !ty.is_error() {
+            self.error_reporter
+                .report_cannot_convert(syntax.span(), ty, SilverType::Integer);
+        }
+        index
+    }
+
+    /// Convert `expression` to `ty`, reporting an error unless the conversion
+    /// is implicit.
+    fn bind_implicit_conversion(
+        &mut self,
+        span: TextSpan,
+        expression: BoundExpression,
+        ty: SilverType,
+    ) -> BoundExpression {
+        let from = expression.ty();
+        if from.is_error() || ty.is_error() {
+            return expression;
+        }
+        if self.is_unknown(&from) || self.is_unknown(&ty) {
+            self.inference.as_mut().unwrap().unify(span, &ty, &from);
+            let ty = self.resolve(&ty);
+            return Self::convert(self.with_inferred_type(expression), ty);
+        }
+        let conversion = Conversion::classify(&from, &ty);
+        if !conversion.is_implicit() {
+            if let Some(constant) = Self::convert_constant(&expression, &ty) {
+                return constant;
+            }
+        }
+        match conversion {
+            Conversion::Identity | Conversion::Implicit => Self::convert(expression, ty),
+            _ if Conversion::classify(from.non_null(), &ty).is_implicit() => {
+                self.error_reporter.report_possibly_null(span, from);
+                expression
+            }
+            Conversion::Explicit => {
+                self.error_reporter
+                    .report_cannot_convert_implicitly(span, from, ty);
+                expression
+            }
+            Conversion::None => {
+                self.error_reporter.report_cannot_convert(span, from, ty);
+                expression
+            }
+        }
+    }
+
+    /// Convert `expression` to `ty`, reporting an error unless some
+    /// conversion exists.
+    fn bind_explicit_conversion(
+        &mut self,
+        span: TextSpan,
+        expression: BoundExpression,
+        ty: SilverType,
+    ) -> BoundExpression {
+        let from = expression.ty();
+        if from.is_error() {
+            return expression;
+        }
+        if Conversion::classify(&from, &ty).exists() {
+            Self::convert(expression, ty)
+        } else if Conversion::classify(from.non_null(), &ty).exists() {
+            self.error_reporter.report_possibly_null(span, from);
+            expression
+        } else {
+            self.error_reporter.report_cannot_convert(span, from, ty);
+            expression
+        }
+    }
+
+    /// While inferring, require `expression`, used at `span`, to have type
+    /// `ty` if its type is not yet known.
+    fn infer_type(
+        &mut self,
+        span: TextSpan,
+        expression: BoundExpression,
+        ty: &SilverType,
+    ) -> BoundExpression {
+        if !self.is_unknown(&expression.ty()) {
+            return expression;
+        }
+        self.inference
+            .as_mut()
+            .unwrap()
+            .unify(span, ty, &expression.ty());
+        self.with_inferred_type(expression)
+    }
+
+    /// Whether the type of `expression` was inferred, rather than given by
+    /// an annotation or a literal.
+    fn has_inferred_type(expression: &BoundExpression) -> bool {
+        match expression {
+            BoundExpression::Conversion { expression, .. } => {
+                type_inference::has_variables(&expression.ty())
+                    || Self::has_inferred_type(expression)
+            }
+            _ => false,
+        }
+    }
+
+    /// `expression` with the type inferred for it so far.
+    fn with_inferred_type(&self, expression: BoundExpression) -> BoundExpression {
+        match &self.inference {
+            Some(inference) => {
+                let ty = inference.resolve(&expression.ty());
+                Self::convert(expression, ty)
+            }
+            None => expression,
+        }
+    }
+
+    /// `ty` with the types inferred so far for any type variables in it.
+    fn resolve(&self, ty: &SilverType) -> SilverType {
+        match &self.inference {
+            Some(inference) => inference.resolve(ty),
+            None => ty.clone(),
+        }
+    }
+
+    /// Whether `ty` involves a type variable that is not yet inferred.
+    fn is_unknown(&self, ty: &SilverType) -> bool {
+        self.inference
+            .as_ref()
+            .is_some_and(|inference| inference.is_unknown(ty))
+    }
+
+    /// `expression` as a value of `ty`, if it is an integer constant that
+    /// fits `ty`. An integer constant is an unsuffixed literal, possibly negated, or an
+    /// array literal of them.
+    fn convert_constant(expression: &BoundExpression, ty: &SilverType) -> Option<BoundExpression> {
+        if let SilverType::Nullable(inner) = ty {
+            let expression = Self::convert_constant(expression, inner)?;
+            return Some(Self::convert(expression, ty.clone()));
+        }
+        let value = match expression {
+            BoundExpression::Literal {
+                value: Some(value @ SilverValue::Integer(_)),
+            } => value.as_integer(),
+            BoundExpression::Unary { operator, operand }
+                if operator.kind() == BoundUnaryOperatorKind::Negation =>
+            {
+                match &**operand {
+                    BoundExpression::Literal {
+                        value: Some(value @ SilverValue::Integer(_)),
+                    } => value.as_integer().and_then(i128::checked_neg),
+                    _ => None,
+                }
+            }
+            BoundExpression::Array { elements, .. } => {
+                let element_type = ty.element_type()?;
+                let elements = elements
+                    .iter()
+                    .map(|element| {
+                        // Look past the widening to the common type of the
+                        // elements, which keeps the value.
+                        let element = match element {
+                            BoundExpression::Conversion { ty, expression }
+                                if Conversion::classify(&expression.ty(), ty).is_implicit() =>
+                            {
+                                expression
+                            }
+                            _ => element,
+                        };
+                        if Conversion::classify(&element.ty(), element_type).is_implicit() {
+                            Some(Self::convert(element.clone(), element_type.clone()))
+                        } else {
+                            Self::convert_constant(element, element_type)
+                        }
+                    })
+                    .collect::<Option<_>>()?;
+                return Some(BoundExpression::Array {
+                    element_type: element_type.clone(),
+                    elements,
+                });
+            }
+            _ => None,
+        }?;
+        let value = SilverValue::integer(value, ty)?;
+        Some(BoundExpression::Literal { value: Some(value) })
+    }
+
+    /// Wrap `expression` in a conversion to `ty`, unless it already has that type.
+    fn convert(expression: BoundExpression, ty: SilverType) -> BoundExpression {
+        if expression.ty() == ty {
+            expression
+        } else {
+            BoundExpression::Conversion {
+                ty,
+                expression: Box::new(expression),
+            }
+        }
+    }
+
+    fn bind_assignment_expression(
+        &mut self,
+        identifier_token: &SyntaxToken,
+        type_clause: &Option<TypeClauseSyntax>,
+        expression: &ExpressionSyntax,
+    ) -> BoundExpression {
+        let name = syntax_facts::normalize_identifier(identifier_token.text());
+        let ty = type_clause
+            .as_ref()
+            .and_then(|type_clause| self.bind_type(&type_clause.type_syntax));
+        let bound_expression = self.bind_expression(expression);
+        // The variable may be null again, whatever it was checked against.
+        self.non_null.retain(|variable| variable.name() != name);
+
+        // An annotation declares the variable afresh with the type it names.
+        if let Some(ty) = ty {
+            let bound_expression =
+                self.bind_implicit_conversion(expression.span(), bound_expression, ty.clone());
+            let variable = VariableSymbol::annotated(name, ty);
+            self.declare_variable(variable.clone());
+            return BoundExpression::Assignment {
+                variable,
+                expression: Box::new(bound_expression),
+            };
+        }
+
+        let existing_variable = self.use_variable(&name);
+        let constant = existing_variable
+            .as_ref()
+            .and_then(|variable| Self::convert_constant(&bound_expression, variable.ty()));
+        let (variable, bound_expression) = match existing_variable {
+            // A parameter whose type is being inferred takes the type of the
+            // value assigned to it.
+            Some(existing_variable) if self.is_unknown(existing_variable.ty()) => {
+                let bound_expression =
+                    self.infer_type(expression.span(), bound_expression, existing_variable.ty());
+                (existing_variable, bound_expression)
+            }
+            // A variable declared with an annotation keeps its type, so the
+            // new value must convert to it implicitly.
+            Some(existing_variable) if existing_variable.is_annotated() => {
+                let ty = existing_variable.ty().clone();
+                let bound_expression =
+                    self.bind_implicit_conversion(expression.span(), bound_expression, ty);
+                (existing_variable, bound_expression)
+            }
+            // A variable keeps its type when the new value widens into it,
+            // or is an integer constant that fits it.
+            Some(existing_variable)
+                if Conversion::classify(&bound_expression.ty(), existing_variable.ty())
+                    .is_implicit() =>
+            {
+                let ty = existing_variable.ty().clone();
+                (existing_variable, Self::convert(bound_expression, ty))
+            }
+            Some(existing_variable) if constant.is_some() => (existing_variable, constant.unwrap()),
+            // Otherwise the assignment declares it afresh with the new type.
+            _ => {
+                let variable = VariableSymbol::new(name, bound_expression.ty());
+                self.declare_variable(variable.clone());
+                (variable, bound_expression)
+            }
+        };
+        if self
+            .lambdas
+            .iter()
+            .any(|(_, captures)| captures.contains(&variable))
+        {
+            self.assigned_by_lambdas.insert(variable.name().to_string());
+        }
+        BoundExpression::Assignment {
+            variable,
+            expression: Box::new(bound_expression),
+        }
+    }
+
+    /// Bind `target op= expression`, or an increment or decrement of
+    /// `target` when there is no `expression`, as an assignment of
+    /// `target op expression` to `target`. Whatever the target is taken from
+    /// is held in temporaries, so that `a[i()] += 1` calls `i` once. A
+    /// postfix update produces the value from before the assignment.
+    fn bind_update_expression(
+        &mut self,
+        syntax: &ExpressionSyntax,
+        target: &ExpressionSyntax,
+        operator_token: &SyntaxToken,
+        expression: Option<&ExpressionSyntax>,
+        postfix: bool,
+    ) -> BoundExpression {
+        let kind = syntax_facts::update_operator_kind(operator_token.kind())
+            .expect("the parser only reads update operators here");
+        let mut temporaries = vec![];
+        let (place, current) = match self.bind_place(target, &mut temporaries) {
+            Some(place) => place,
+            None => {
+                if let Some(expression) = expression {
+                    self.bind_expression(expression);
+                }
+                return BoundExpression::Literal { value: None };
+            }
+        };
+        let ty = self.resolve(&place.ty());
+
+        let (left, right_span, right) = match expression {
+            Some(expression) => {
+                let right = self.bind_expression(expression);
+                (current, expression.span(), right)
+            }
+            None => {
+                let current = self.infer_type(target.span(), current, &SilverType::Integer);
+                let one = match current.ty().non_null() {
+                    SilverType::Float => Some(SilverValue::Float(1.0)),
+                    ty => SilverValue::integer(1, ty),
+                };
+                let Some(one) = one else {
+                    if !current.ty().is_error() {
+                        self.error_reporter.report_undefined_unary_operator(
+                            operator_token.span(),
+                            operator_token.clone(),
+                            current.ty(),
+                        );
+                    }
+                    return BoundExpression::Literal { value: None };
+                };
+                let left = if postfix {
+                    self.temporary("old", current, &mut temporaries)
+                } else {
+                    current
+                };
+                (
+                    left,
+                    operator_token.span(),
+                    BoundExpression::Literal { value: Some(one) },
+                )
+            }
+        };
+        let old = postfix.then(|| left.clone());
+        let operation = self.bind_binary_operation(
+            operator_token,
+            kind,
+            target.span(),
+            left,
+            right_span,
+            right,
+        );
+        let value = self.bind_implicit_conversion(syntax.span(), operation, ty);
+        let assignment = place.assign(value);
+
+        // The postfix form evaluates the assignment and then discards it.
+        let body = match old {
+            Some(old) => BoundExpression::Match {
+                expression: Box::new(assignment),
+                ty: old.ty(),
+                arms: vec![BoundMatchArm {
+                    pattern: BoundPattern::Binding(None),
+                    expression: old,
+                }],
+            },
+            None => assignment,
+        };
+        temporaries
+            .into_iter()
+            .rev()
+            .fold(body, |body, (variable, value)| BoundExpression::Match {
+                expression: Box::new(value),
+                ty: body.ty(),
+                arms: vec![BoundMatchArm {
+                    pattern: BoundPattern::Binding(Some(variable)),
+                    expression: body,
+                }],
+            })
+    }
+
+    /// Bind the target of a compound assignment or an update, with its
+    /// current value. Any part of it that must only be evaluated once is
+    /// added to `temporaries`.
+    fn bind_place(
+        &mut self,
+        target: &ExpressionSyntax,
+        temporaries: &mut Vec<(VariableSymbol, BoundExpression)>,
+    ) -> Option<(Place, BoundExpression)> {
+        match target {
+            ExpressionSyntax::Parenthesized { expression, .. } => {
+                self.bind_place(expression, temporaries)
+            }
+            ExpressionSyntax::Name { identifier_token } => {
+                let name = syntax_facts::normalize_identifier(identifier_token.text());
+                if self.lookup_variable(&name).is_none() {
+                    // Report the undefined name, unless it names a function.
+                    if self.lookup_function(&name).is_some() {
+                        self.error_reporter.report_not_assignable(target.span());
+                    } else {
+                        self.bind_name_expression(identifier_token);
+                    }
+                    return None;
+                }
+                // Whatever it was checked against, the variable keeps the
+                // non-null type of its new value.
+                let current = self.bind_name_expression(identifier_token);
+                let variable = self.use_variable(&name).unwrap();
+                Some((Place::Variable(variable), current))
+            }
+            ExpressionSyntax::Index {
+                expression, index, ..
+            } => {
+                let array = self.bind_array(expression);
+                let bound_index = self.bind_index(index);
+                array.ty().element_type()?;
+                let array = self.temporary("array", array, temporaries);
+                let bound_index = self.temporary("index", bound_index, temporaries);
+                let current = BoundExpression::Index {
+                    array: Box::new(array.clone()),
+                    index: Box::new(bound_index.clone()),
+                    index_span: index.span(),
+                };
+                let place = Place::Element {
+                    array,
+                    index: bound_index,
+                    index_span: index.span(),
+                };
+                Some((place, current))
+            }
+            ExpressionSyntax::FieldAccess {
+                expression,
+                dot_token,
+                identifier_token,
+            } if dot_token.kind() == SyntaxKind::DotToken => {
+                let bound_target = self.bind_expression(expression);
+                let bound_target = self.bind_non_null(expression.span(), bound_target);
+                let (field, index) = self.bind_field(bound_target.ty(), identifier_token)?;
+                let bound_target = self.temporary("target", bound_target, temporaries);
+                let current = BoundExpression::FieldAccess {
+                    expression: Box::new(bound_target.clone()),
+                    field: field.clone(),
+                    index,
+                };
+                let place = Place::Field {
+                    target: bound_target,
+                    field,
+                    index,
+                };
+                Some((place, current))
+            }
+            _ => {
+                self.bind_expression(target);
+                self.error_reporter.report_not_assignable(target.span());
+                None
+            }
+        }
+    }
+
+    /// A variable holding `value`, for use wherever `value` is needed more
+    /// than once. A literal is simply repeated. The variable is called
+    /// `name`, numbered if that already names something in scope, so that
+    /// it hides nothing the expression refers to.
+    fn temporary(
+        &self,
+        name: &str,
+        value: BoundExpression,
+        temporaries: &mut Vec<(VariableSymbol, BoundExpression)>,
+    ) -> BoundExpression {
+        if let BoundExpression::Literal { .. } = value {
+            return value;
+        }
+        let name = std::iter::once(name.to_string())
+            .chain((2..).map(|n| format!("{}{}", name, n)))
+            .find(|name| {
+                self.lookup_variable(name).is_none()
+                    && self.lookup_function(name).is_none()
+                    && temporaries.iter().all(|(t, _)| t.name() != name)
+            })
+            .unwrap();
+        let variable = VariableSymbol::new(name, value.ty());
+        temporaries.push((variable.clone(), value));
+        BoundExpression::Variable { variable }
+    }
+}
+
+/// Somewhere a compound assignment or an update stores its result.
+enum Place {
+    Variable(VariableSymbol),
+    Element {
+        array: BoundExpression,
+        index: BoundExpression,
+        index_span: TextSpan,
+    },
+    Field {
+        target: BoundExpression,
+        field: VariableSymbol,
+        index: usize,
+    },
+}
+
+impl Place {
+    fn ty(&self) -> SilverType {
+        match self {
+            Place::Variable(variable) => variable.ty().clone(),
+            Place::Element { array, .. } => array.ty().element_type().unwrap().clone(),
+            Place::Field { field, .. } => field.ty().clone(),
+        }
+    }
+
+    fn assign(self, value: BoundExpression) -> BoundExpression {
+        match self {
+            Place::Variable(variable) => BoundExpression::Assignment {
+                variable,
+                expression: Box::new(value),
+            },
+            Place::Element {
+                array,
+                index,
+                index_span,
+            } => BoundExpression::ElementAssignment {
+                array: Box::new(array),
+                index: Box::new(index),
+                index_span,
+                expression: Box::new(value),
+            },
+            Place::Field { target, index, .. } => BoundExpression::FieldAssignment {
+                target: Box::new(target),
+                index,
+                expression: Box::new(value),
+            },
+        }
+    }
+}
+
+/// Whether `node` contains a call, which may run a lambda.
+fn calls(node: &dyn SyntaxNodeExt) -> bool {
+    matches!(
+        node.kind(),
+        SyntaxKind::CallExpression
+            | SyntaxKind::InvocationExpression
+            | SyntaxKind::MemberCallExpression
+    ) || node.children().into_iter().any(calls)
+}
+
+/// Whether `node` contains an assignment to the variable called `name`.
+fn assigns(node: &dyn SyntaxNodeExt, name: &str) -> bool {
+    let children = node.children();
+    let assigned = node.kind() == SyntaxKind::AssignmentExpression
+        && children[0].text().map(syntax_facts::normalize_identifier) == Some(name.to_string());
+    assigned || children.into_iter().any(|child| assigns(child, name))
 }
@@ -16,102 +16,156 @@ pub(crate) struct BoundBinaryOperator {
 }
 
 impl BoundBinaryOperator {
+    pub(crate) fn syntax_kind(&self) -> SyntaxKind {
+        self.syntax_kind
+    }
+
     pub(crate) fn kind(&self) -> BoundBinaryOperatorKind {
         self.kind
     }
 
-    pub(crate) fn result_type(&self) -> SilverType {
-        self.result_type
+    pub(crate) fn result_type(&self) -> &SilverType {
+        &self.result_type
     }
 
     fn operators() -> Vec<Self> {
-        vec![
-            BoundBinaryOperator::new(
-                SyntaxKind::PlusToken,
-                BoundBinaryOperatorKind::Addition,
-                SilverType::Integer,
-                SilverType::Integer,
-                SilverType::Integer,
-            ),
-            BoundBinaryOperator::new(
-                SyntaxKind::MinusToken,
-                BoundBinaryOperatorKind::Subtraction,
-                SilverType::Integer,
-                SilverType::Integer,
-                SilverType::Integer,
-            ),
-            BoundBinaryOperator::new(
-                SyntaxKind::StarToken,
-                BoundBinaryOperatorKind::Multiplication,
-                SilverType::Integer,
-                SilverType::Integer,
-                SilverType::Integer,
-            ),
-            BoundBinaryOperator::new(
-                SyntaxKind::SlashToken,
-                BoundBinaryOperatorKind::Division,
-                SilverType::Integer,
-                SilverType::Integer,
-                SilverType::Integer,
-            ),
-            BoundBinaryOperator::new(
-                SyntaxKind::AmpersandAmpersandToken,
-                BoundBinaryOperatorKind::LogicalAnd,
-                SilverType::Boolean,
-                SilverType::Boolean,
-                SilverType::Boolean,
-            ),
-            BoundBinaryOperator::new(
-                SyntaxKind::PipePipeToken,
-                BoundBinaryOperatorKind::LogicalOr,
-                SilverType::Boolean,
-                SilverType::Boolean,
-                SilverType::Boolean,
-            ),
-            BoundBinaryOperator::new(
-                SyntaxKind::EqualsEqualsToken,
-                BoundBinaryOperatorKind::Equality,
-                SilverType::Integer,
-                SilverType::Integer,
-                SilverType::Boolean,
-            ),
-            BoundBinaryOperator::new(
+        let mut operators = vec![];
+        let numeric_types = SilverType::integer_types()
+            .iter()
+            .cloned()
+            .chain(std::iter::once(SilverType::Float))
+            .collect::<Vec<_>>();
+        for ty in numeric_types.iter() {
+            for &(syntax_kind, kind) in [
+                (SyntaxKind::PlusToken, BoundBinaryOperatorKind::Addition),
+                (SyntaxKind::MinusToken, BoundBinaryOperatorKind::Subtraction),
+                (
+                    SyntaxKind::StarToken,
+                    BoundBinaryOperatorKind::Multiplication,
+                ),
+                (SyntaxKind::SlashToken, BoundBinaryOperatorKind::Division),
+                (SyntaxKind::PercentToken, BoundBinaryOperatorKind::Remainder),
+            ]
+            .iter()
+            {
+                operators.push(BoundBinaryOperator::new(
+                    syntax_kind,
+                    kind,
+                    ty.clone(),
+                    ty.clone(),
+                    ty.clone(),
+                ));
+            }
+            for &(syntax_kind, kind) in [
+                (SyntaxKind::LessToken, BoundBinaryOperatorKind::Less),
+                (
+                    SyntaxKind::LessEqualsToken,
+                    BoundBinaryOperatorKind::LessOrEquals,
+                ),
+                (SyntaxKind::GreaterToken, BoundBinaryOperatorKind::Greater),
+                (
+                    SyntaxKind::GreaterEqualsToken,
+                    BoundBinaryOperatorKind::GreaterOrEquals,
+                ),
+            ]
+            .iter()
+            {
+                operators.push(BoundBinaryOperator::new(
+                    syntax_kind,
+                    kind,
+                    ty.clone(),
+                    ty.clone(),
+                    SilverType::Boolean,
+                ));
+            }
+        }
+        operators.push(BoundBinaryOperator::new(
+            SyntaxKind::AmpersandAmpersandToken,
+            BoundBinaryOperatorKind::LogicalAnd,
+            SilverType::Boolean,
+            SilverType::Boolean,
+            SilverType::Boolean,
+        ));
+        operators.push(BoundBinaryOperator::new(
+            SyntaxKind::PipePipeToken,
+            BoundBinaryOperatorKind::LogicalOr,
+            SilverType::Boolean,
+            SilverType::Boolean,
+            SilverType::Boolean,
+        ));
+        operators.push(BoundBinaryOperator::new(
+            SyntaxKind::PlusToken,
+            BoundBinaryOperatorKind::Concatenation,
+            SilverType::String,
+            SilverType::String,
+            SilverType::String,
+        ));
+        for ty in numeric_types
+            .iter()
+            .chain([SilverType::Boolean, SilverType::String].iter())
+        {
+            operators.push(BoundBinaryOperator::new(
                 SyntaxKind::EqualsEqualsToken,
                 BoundBinaryOperatorKind::Equality,
+                ty.clone(),
+                ty.clone(),
                 SilverType::Boolean,
-                SilverType::Boolean,
-                SilverType::Boolean,
-            ),
-            BoundBinaryOperator::new(
-                SyntaxKind::BangEqualsToken,
-                BoundBinaryOperatorKind::Inequality,
-                SilverType::Integer,
-                SilverType::Integer,
-                SilverType::Boolean,
-            ),
-            BoundBinaryOperator::new(
+            ));
+            operators.push(BoundBinaryOperator::new(
                 SyntaxKind::BangEqualsToken,
                 BoundBinaryOperatorKind::Inequality,
+                ty.clone(),
+                ty.clone(),
                 SilverType::Boolean,
-                SilverType::Boolean,
-                SilverType::Boolean,
-            ),
-        ]
+            ));
+        }
+        operators
     }
 
     pub(crate) fn bind(
         syntax_kind: SyntaxKind,
-        left_type: SilverType,
-        right_type: SilverType,
+        left_type: &SilverType,
+        right_type: &SilverType,
     ) -> Option<Self> {
         Self::operators()
             .iter()
             .find(|op| {
                 op.syntax_kind == syntax_kind
-                    && op.left_type == left_type
-                    && op.right_type == right_type
+                    && &op.left_type == left_type
+                    && &op.right_type == right_type
             })
             .cloned()
+            .or_else(|| Self::bind_nullable_equality(syntax_kind, left_type, right_type))
+    }
+
+    /// `==` and `!=` on nullable types, where null equals only itself. Any
+    /// nullable value compares with null; otherwise the inner types must.
+    fn bind_nullable_equality(
+        syntax_kind: SyntaxKind,
+        left_type: &SilverType,
+        right_type: &SilverType,
+    ) -> Option<Self> {
+        let kind = match syntax_kind {
+            SyntaxKind::EqualsEqualsToken => BoundBinaryOperatorKind::Equality,
+            SyntaxKind::BangEqualsToken => BoundBinaryOperatorKind::Inequality,
+            _ => return None,
+        };
+        let comparable = match (left_type, right_type) {
+            (SilverType::Null, other) | (other, SilverType::Null) => other.is_nullable(),
+            (SilverType::Nullable(left), SilverType::Nullable(right)) => {
+                Self::bind(syntax_kind, left, right).is_some()
+            }
+            _ => false,
+        };
+        comparable.then(|| {
+            BoundBinaryOperator::new(
+                syntax_kind,
+                kind,
+                left_type.clone(),
+                right_type.clone(),
+                SilverType::Boolean,
+            )
+        })
     }
 }
 
@@ -123,4 +177,15 @@ impl BoundNode for BoundBinaryOperator {
     fn children(&self) -> Vec<&dyn BoundNode> {
         vec![]
     }
+
+    fn properties(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("kind", self.kind.to_string()),
+            (
+                "operands",
+                format!("{}, {}", self.left_type, self.right_type),
+            ),
+            ("type", self.result_type.to_string()),
+        ]
+    }
 }
@@ -6,8 +6,14 @@ pub(crate) enum BoundBinaryOperatorKind {
     Subtraction,
     Multiplication,
     Division,
+    Remainder,
+    Concatenation,
     LogicalAnd,
     LogicalOr,
     Equality,
     Inequality,
+    Less,
+    LessOrEquals,
+    Greater,
+    GreaterOrEquals,
 }
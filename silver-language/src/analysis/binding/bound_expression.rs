@@ -1,10 +1,18 @@
+use std::rc::Rc;
+
 use crate::analysis::{
-    silver_type::SilverType, silver_value::SilverValue, variable_symbol::VariableSymbol,
+    enum_symbol::EnumSymbol, function_symbol::FunctionSymbol, silver_type::SilverType,
+    silver_value::SilverValue, text::text_span::TextSpan, type_symbol::TypeSymbol,
+    variable_symbol::VariableSymbol,
 };
 
 use super::{
-    bound_binary_operator::BoundBinaryOperator, bound_node::BoundNode,
-    bound_node_kind::BoundNodeKind, bound_unary_operator::BoundUnaryOperator,
+    bound_binary_operator::BoundBinaryOperator,
+    bound_node::BoundNode,
+    bound_node_kind::BoundNodeKind,
+    bound_node_printer::{self, Literal, Pattern},
+    bound_pattern::BoundPattern,
+    bound_unary_operator::BoundUnaryOperator,
 };
 
 #[derive(Debug, Clone)]
@@ -16,10 +24,13 @@ pub(crate) enum BoundExpression {
         operator: BoundUnaryOperator,
         operand: Box<BoundExpression>,
     },
+    /// `operator_span` locates the operator in the source for division by
+    /// zero.
     Binary {
         left: Box<BoundExpression>,
         operator: BoundBinaryOperator,
         right: Box<BoundExpression>,
+        operator_span: TextSpan,
     },
     Variable {
         variable: VariableSymbol,
@@ -28,18 +39,143 @@ pub(crate) enum BoundExpression {
         variable: VariableSymbol,
         expression: Box<BoundExpression>,
     },
+    Conversion {
+        ty: SilverType,
+        expression: Box<BoundExpression>,
+    },
+    /// `span` locates the call in the source for errors at runtime. `ty` is
+    /// the return type, with the type arguments of a generic call in place.
+    Call {
+        function: FunctionSymbol,
+        arguments: Vec<BoundExpression>,
+        ty: SilverType,
+        span: TextSpan,
+    },
+    /// A call through a function value; `ty` is the return type.
+    Invocation {
+        expression: Box<BoundExpression>,
+        arguments: Vec<BoundExpression>,
+        ty: SilverType,
+        span: TextSpan,
+    },
+    /// A lambda, which captures `captures`, the variables of enclosing
+    /// scopes that its body uses, when it is evaluated.
+    Lambda {
+        parameters: Vec<VariableSymbol>,
+        captures: Vec<VariableSymbol>,
+        body: Rc<BoundExpression>,
+    },
+    /// A declared function used as a value.
+    Function {
+        function: FunctionSymbol,
+    },
+    Array {
+        element_type: SilverType,
+        elements: Vec<BoundExpression>,
+    },
+    /// `index_span` locates the index in the source for out-of-bounds errors.
+    Index {
+        array: Box<BoundExpression>,
+        index: Box<BoundExpression>,
+        index_span: TextSpan,
+    },
+    ElementAssignment {
+        array: Box<BoundExpression>,
+        index: Box<BoundExpression>,
+        index_span: TextSpan,
+        expression: Box<BoundExpression>,
+    },
+    ArrayLength {
+        array: Box<BoundExpression>,
+    },
+    /// `fields` pairs each field's index in `ty` with its initializer, in
+    /// source order.
+    StructConstruction {
+        ty: Rc<TypeSymbol>,
+        fields: Vec<(usize, BoundExpression)>,
+    },
+    FieldAccess {
+        expression: Box<BoundExpression>,
+        field: VariableSymbol,
+        index: usize,
+    },
+    /// `expression?.field`, which is null when the struct is.
+    ConditionalFieldAccess {
+        expression: Box<BoundExpression>,
+        field: VariableSymbol,
+        index: usize,
+    },
+    FieldAssignment {
+        target: Box<BoundExpression>,
+        index: usize,
+        expression: Box<BoundExpression>,
+    },
+    /// `left ?? right`, which only evaluates `right` when `left` is null.
+    Coalesce {
+        left: Box<BoundExpression>,
+        right: Box<BoundExpression>,
+    },
+    VariantConstruction {
+        ty: Rc<EnumSymbol>,
+        variant: usize,
+        payload: Vec<BoundExpression>,
+    },
+    /// `ty` is the type every arm converts to; arms are tried in order.
+    Match {
+        expression: Box<BoundExpression>,
+        arms: Vec<BoundMatchArm>,
+        ty: SilverType,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct BoundMatchArm {
+    pub(crate) pattern: BoundPattern,
+    pub(crate) expression: BoundExpression,
 }
 
 impl BoundExpression {
     pub(crate) fn ty(&self) -> SilverType {
         match self {
             BoundExpression::Literal { value } => {
-                value.as_ref().map(|v| v.ty()).unwrap_or(SilverType::Null)
+                value.as_ref().map(|v| v.ty()).unwrap_or(SilverType::Error)
             }
-            BoundExpression::Unary { operator, .. } => operator.result_type(),
-            BoundExpression::Binary { operator, .. } => operator.result_type(),
-            BoundExpression::Variable { variable } => variable.ty(),
+            BoundExpression::Unary { operator, .. } => operator.result_type().clone(),
+            BoundExpression::Binary { operator, .. } => operator.result_type().clone(),
+            BoundExpression::Variable { variable } => variable.ty().clone(),
             BoundExpression::Assignment { expression, .. } => expression.ty(),
+            BoundExpression::Conversion { ty, .. } => ty.clone(),
+            BoundExpression::Call { ty, .. } => ty.clone(),
+            BoundExpression::Invocation { ty, .. } => ty.clone(),
+            BoundExpression::Lambda {
+                parameters, body, ..
+            } => SilverType::Function(
+                parameters.iter().map(|p| p.ty().clone()).collect(),
+                Box::new(body.ty()),
+            ),
+            BoundExpression::Function { function } => SilverType::Function(
+                function
+                    .parameters()
+                    .iter()
+                    .map(|p| p.ty().clone())
+                    .collect(),
+                Box::new(function.ty().clone()),
+            ),
+            BoundExpression::Array { element_type, .. } => {
+                SilverType::Array(Box::new(element_type.clone()))
+            }
+            BoundExpression::Index { array, .. } => array.ty().element_type().unwrap().clone(),
+            BoundExpression::ElementAssignment { expression, .. } => expression.ty(),
+            BoundExpression::ArrayLength { .. } => SilverType::Integer,
+            BoundExpression::StructConstruction { ty, .. } => SilverType::Struct(ty.clone()),
+            BoundExpression::FieldAccess { field, .. } => field.ty().clone(),
+            BoundExpression::ConditionalFieldAccess { field, .. } => {
+                SilverType::nullable(field.ty().clone())
+            }
+            BoundExpression::FieldAssignment { expression, .. } => expression.ty(),
+            BoundExpression::Coalesce { right, .. } => right.ty(),
+            BoundExpression::VariantConstruction { ty, .. } => SilverType::Enum(ty.clone()),
+            BoundExpression::Match { ty, .. } => ty.clone(),
         }
     }
 }
@@ -52,6 +188,28 @@ impl BoundNode for BoundExpression {
             BoundExpression::Binary { .. } => BoundNodeKind::BinaryExpression,
             BoundExpression::Variable { .. } => BoundNodeKind::VariableExpression,
             BoundExpression::Assignment { .. } => BoundNodeKind::AssignmentExpression,
+            BoundExpression::Conversion { .. } => BoundNodeKind::ConversionExpression,
+            BoundExpression::Call { .. } => BoundNodeKind::CallExpression,
+            BoundExpression::Invocation { .. } => BoundNodeKind::InvocationExpression,
+            BoundExpression::Lambda { .. } => BoundNodeKind::LambdaExpression,
+            BoundExpression::Function { .. } => BoundNodeKind::FunctionExpression,
+            BoundExpression::Array { .. } => BoundNodeKind::ArrayExpression,
+            BoundExpression::Index { .. } => BoundNodeKind::IndexExpression,
+            BoundExpression::ElementAssignment { .. } => BoundNodeKind::ElementAssignmentExpression,
+            BoundExpression::ArrayLength { .. } => BoundNodeKind::ArrayLengthExpression,
+            BoundExpression::StructConstruction { .. } => {
+                BoundNodeKind::StructConstructionExpression
+            }
+            BoundExpression::FieldAccess { .. } => BoundNodeKind::FieldAccessExpression,
+            BoundExpression::ConditionalFieldAccess { .. } => {
+                BoundNodeKind::ConditionalFieldAccessExpression
+            }
+            BoundExpression::FieldAssignment { .. } => BoundNodeKind::FieldAssignmentExpression,
+            BoundExpression::Coalesce { .. } => BoundNodeKind::CoalesceExpression,
+            BoundExpression::VariantConstruction { .. } => {
+                BoundNodeKind::VariantConstructionExpression
+            }
+            BoundExpression::Match { .. } => BoundNodeKind::MatchExpression,
         }
     }
 
@@ -63,9 +221,117 @@ impl BoundNode for BoundExpression {
                 left,
                 operator,
                 right,
+                ..
             } => vec![left.as_ref(), operator, right.as_ref()],
             BoundExpression::Variable { .. } => vec![],
             BoundExpression::Assignment { expression, .. } => vec![expression.as_ref()],
+            BoundExpression::Conversion { expression, .. } => vec![expression.as_ref()],
+            BoundExpression::Call { arguments, .. } => {
+                arguments.iter().map(|e| e as &dyn BoundNode).collect()
+            }
+            BoundExpression::Invocation {
+                expression,
+                arguments,
+                ..
+            } => {
+                let mut children: Vec<&dyn BoundNode> = vec![expression.as_ref()];
+                children.extend(arguments.iter().map(|e| e as &dyn BoundNode));
+                children
+            }
+            BoundExpression::Lambda { body, .. } => vec![body.as_ref()],
+            BoundExpression::Function { .. } => vec![],
+            BoundExpression::Array { elements, .. } => {
+                elements.iter().map(|e| e as &dyn BoundNode).collect()
+            }
+            BoundExpression::Index { array, index, .. } => vec![array.as_ref(), index.as_ref()],
+            BoundExpression::ElementAssignment {
+                array,
+                index,
+                expression,
+                ..
+            } => vec![array.as_ref(), index.as_ref(), expression.as_ref()],
+            BoundExpression::ArrayLength { array } => vec![array.as_ref()],
+            BoundExpression::StructConstruction { fields, .. } => fields
+                .iter()
+                .map(|(_, field)| field as &dyn BoundNode)
+                .collect(),
+            BoundExpression::FieldAccess { expression, .. } => vec![expression.as_ref()],
+            BoundExpression::ConditionalFieldAccess { expression, .. } => vec![expression.as_ref()],
+            BoundExpression::FieldAssignment {
+                target, expression, ..
+            } => vec![target.as_ref(), expression.as_ref()],
+            BoundExpression::Coalesce { left, right } => vec![left.as_ref(), right.as_ref()],
+            BoundExpression::VariantConstruction { payload, .. } => {
+                payload.iter().map(|e| e as &dyn BoundNode).collect()
+            }
+            BoundExpression::Match {
+                expression, arms, ..
+            } => {
+                let mut children: Vec<&dyn BoundNode> = vec![expression.as_ref()];
+                children.extend(arms.iter().map(|arm| &arm.expression as &dyn BoundNode));
+                children
+            }
         }
     }
+
+    fn properties(&self) -> Vec<(&'static str, String)> {
+        let names = |variables: &[VariableSymbol]| {
+            let names: Vec<_> = variables.iter().map(|variable| variable.name()).collect();
+            names.join(", ")
+        };
+        let mut properties = match self {
+            BoundExpression::Literal { value: Some(value) } => {
+                vec![("value", Literal(value).to_string())]
+            }
+            BoundExpression::Variable { variable }
+            | BoundExpression::Assignment { variable, .. } => {
+                vec![("variable", variable.name().to_string())]
+            }
+            BoundExpression::Call { function, .. } | BoundExpression::Function { function } => {
+                vec![("function", function.name().to_string())]
+            }
+            BoundExpression::Lambda {
+                parameters,
+                captures,
+                ..
+            } => {
+                let mut properties = vec![("parameters", names(parameters))];
+                if !captures.is_empty() {
+                    properties.push(("captures", names(captures)));
+                }
+                properties
+            }
+            BoundExpression::StructConstruction { ty, fields } => {
+                let fields: Vec<_> = fields
+                    .iter()
+                    .map(|&(index, _)| ty.fields()[index].name())
+                    .collect();
+                vec![("fields", fields.join(", "))]
+            }
+            BoundExpression::FieldAccess { field, .. }
+            | BoundExpression::ConditionalFieldAccess { field, .. } => {
+                vec![("field", field.name().to_string())]
+            }
+            BoundExpression::FieldAssignment { target, index, .. } => {
+                let field = bound_node_printer::field_name(&target.ty(), *index).to_string();
+                vec![("field", field)]
+            }
+            BoundExpression::VariantConstruction { ty, variant, .. } => {
+                vec![("variant", ty.variants()[*variant].name().to_string())]
+            }
+            BoundExpression::Match {
+                expression, arms, ..
+            } => {
+                let ty = expression.ty();
+                let patterns: Vec<_> = arms
+                    .iter()
+                    .map(|arm| Pattern(&arm.pattern, &ty).to_string())
+                    .collect();
+                vec![("patterns", patterns.join(" | "))]
+            }
+            _ => vec![],
+        };
+        properties.push(("type", self.ty().to_string()));
+        properties
+    }
 }
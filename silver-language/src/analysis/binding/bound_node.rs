@@ -1,6 +1,44 @@
+use std::io::{self, Write};
+
 use super::bound_node_kind::BoundNodeKind;
 
 pub(crate) trait BoundNode {
     fn kind(&self) -> BoundNodeKind;
     fn children(&self) -> Vec<&dyn BoundNode>;
+    /// What the binder resolved for the node, such as its type and the
+    /// symbols it refers to, as names and values to show beside its kind.
+    fn properties(&self) -> Vec<(&'static str, String)>;
+}
+
+/// Write the tree under `root`, one node per line with its properties.
+pub(crate) fn pretty_print(root: &dyn BoundNode, writer: &mut dyn Write) -> io::Result<()> {
+    pretty_print_recursive(root, writer, String::new(), true)
+}
+
+fn pretty_print_recursive(
+    root: &dyn BoundNode,
+    writer: &mut dyn Write,
+    mut indent: String,
+    is_last: bool,
+) -> io::Result<()> {
+    write!(writer, "{}", indent)?;
+    write!(writer, "{}", if is_last { "\\--" } else { "+--" })?;
+    write!(writer, "{}", root.kind())?;
+    for (i, (name, value)) in root.properties().iter().enumerate() {
+        write!(
+            writer,
+            "{} {}: {}",
+            if i > 0 { "," } else { "" },
+            name,
+            value
+        )?;
+    }
+    writeln!(writer)?;
+
+    indent += if is_last { "   " } else { "|  " };
+    let children = root.children();
+    for (i, &child) in children.iter().enumerate() {
+        pretty_print_recursive(child, writer, indent.clone(), i == children.len() - 1)?;
+    }
+    Ok(())
 }
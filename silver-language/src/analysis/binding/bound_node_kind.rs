@@ -7,6 +7,22 @@ pub(crate) enum BoundNodeKind {
     BinaryExpression,
     VariableExpression,
     AssignmentExpression,
+    ConversionExpression,
+    CallExpression,
+    InvocationExpression,
+    LambdaExpression,
+    FunctionExpression,
+    ArrayExpression,
+    IndexExpression,
+    ElementAssignmentExpression,
+    ArrayLengthExpression,
+    StructConstructionExpression,
+    FieldAccessExpression,
+    ConditionalFieldAccessExpression,
+    FieldAssignmentExpression,
+    CoalesceExpression,
+    VariantConstructionExpression,
+    MatchExpression,
     UnaryOperator,
     BinaryOperator,
 }
@@ -0,0 +1,350 @@
+use std::fmt::{self, Display, Formatter, Write as _};
+
+use crate::analysis::{
+    function_symbol::FunctionSymbol,
+    silver_type::SilverType,
+    silver_value::SilverValue,
+    syntax::{syntax_facts::SyntaxKindWithText, syntax_kind::SyntaxKind},
+    variable_symbol::VariableSymbol,
+};
+
+use super::{bound_expression::BoundExpression, bound_pattern::BoundPattern};
+
+/// A bound expression written back as Silver source. Conversions, including
+/// the implicit ones, are written out with `as`, and any operand that is not
+/// a simple expression is put in parentheses, so that the text shows what
+/// the binder made of the expression rather than how it was written.
+pub(crate) struct Source<'a>(pub(crate) &'a BoundExpression);
+
+impl Display for Source<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            BoundExpression::Literal { value: Some(value) } => write!(f, "{}", Literal(value)),
+            // An expression that failed to bind.
+            BoundExpression::Literal { value: None } => f.write_str("?"),
+            BoundExpression::Unary { operator, operand } => {
+                write!(
+                    f,
+                    "{}{}",
+                    operator_text(operator.syntax_kind()),
+                    Operand(operand)
+                )
+            }
+            BoundExpression::Binary {
+                left,
+                operator,
+                right,
+                ..
+            } => write!(
+                f,
+                "{} {} {}",
+                Operand(left),
+                operator_text(operator.syntax_kind()),
+                Operand(right)
+            ),
+            BoundExpression::Variable { variable } => f.write_str(variable.name()),
+            BoundExpression::Assignment {
+                variable,
+                expression,
+            } => write!(f, "{} = {}", variable.name(), Source(expression)),
+            BoundExpression::Conversion { ty, expression } => {
+                write!(f, "{} as {}", Operand(expression), ty)
+            }
+            BoundExpression::Call {
+                function,
+                arguments,
+                ..
+            } => write!(f, "{}({})", function.name(), Arguments(arguments)),
+            BoundExpression::Invocation {
+                expression,
+                arguments,
+                ..
+            } => write!(f, "{}({})", Operand(expression), Arguments(arguments)),
+            BoundExpression::Lambda {
+                parameters, body, ..
+            } => write!(f, "({}) => {}", Parameters(parameters), Source(body)),
+            BoundExpression::Function { function } => f.write_str(function.name()),
+            BoundExpression::Array { elements, .. } => write!(f, "[{}]", Arguments(elements)),
+            BoundExpression::Index { array, index, .. } => {
+                write!(f, "{}[{}]", Operand(array), Source(index))
+            }
+            BoundExpression::ElementAssignment {
+                array,
+                index,
+                expression,
+                ..
+            } => write!(
+                f,
+                "{}[{}] = {}",
+                Operand(array),
+                Source(index),
+                Source(expression)
+            ),
+            BoundExpression::ArrayLength { array } => write!(f, "len({})", Source(array)),
+            BoundExpression::StructConstruction { ty, fields } => {
+                write!(f, "{} {{", SilverType::Struct(ty.clone()))?;
+                for (i, (index, expression)) in fields.iter().enumerate() {
+                    let name = ty.fields()[*index].name();
+                    let separator = if i > 0 { "," } else { "" };
+                    write!(f, "{} {}: {}", separator, name, Source(expression))?;
+                }
+                f.write_str(" }")
+            }
+            BoundExpression::FieldAccess {
+                expression, field, ..
+            } => write!(f, "{}.{}", Operand(expression), field.name()),
+            BoundExpression::ConditionalFieldAccess {
+                expression, field, ..
+            } => write!(f, "{}?.{}", Operand(expression), field.name()),
+            BoundExpression::FieldAssignment {
+                target,
+                index,
+                expression,
+            } => write!(
+                f,
+                "{}.{} = {}",
+                Operand(target),
+                field_name(&target.ty(), *index),
+                Source(expression)
+            ),
+            BoundExpression::Coalesce { left, right } => {
+                write!(f, "{} ?? {}", Operand(left), Operand(right))
+            }
+            BoundExpression::VariantConstruction {
+                ty,
+                variant,
+                payload,
+            } => {
+                write!(f, "{}.{}", ty.name(), ty.variants()[*variant].name())?;
+                if !payload.is_empty() {
+                    write!(f, "({})", Arguments(payload))?;
+                }
+                Ok(())
+            }
+            BoundExpression::Match {
+                expression, arms, ..
+            } => {
+                let ty = expression.ty();
+                write!(f, "match {} {{", Operand(expression))?;
+                for (i, arm) in arms.iter().enumerate() {
+                    let separator = if i > 0 { "," } else { "" };
+                    let pattern = Pattern(&arm.pattern, &ty);
+                    write!(
+                        f,
+                        "{} {} => {}",
+                        separator,
+                        pattern,
+                        Source(&arm.expression)
+                    )?;
+                }
+                f.write_str(" }")
+            }
+        }
+    }
+}
+
+/// An operand of an operator or a postfix expression, in parentheses unless
+/// it is a simple expression that binds tighter than any operator.
+struct Operand<'a>(&'a BoundExpression);
+
+impl Display for Operand<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let is_simple = match self.0 {
+            // A negative number would read as a negation.
+            BoundExpression::Literal { value } => {
+                !Literal::is_negative(value.as_ref().unwrap_or(&SilverValue::Null))
+            }
+            BoundExpression::Variable { .. }
+            | BoundExpression::Call { .. }
+            | BoundExpression::Invocation { .. }
+            | BoundExpression::Function { .. }
+            | BoundExpression::Array { .. }
+            | BoundExpression::Index { .. }
+            | BoundExpression::ArrayLength { .. }
+            | BoundExpression::FieldAccess { .. }
+            | BoundExpression::ConditionalFieldAccess { .. }
+            | BoundExpression::VariantConstruction { .. } => true,
+            _ => false,
+        };
+        if is_simple {
+            write!(f, "{}", Source(self.0))
+        } else {
+            write!(f, "({})", Source(self.0))
+        }
+    }
+}
+
+/// The expressions of an argument list or an array, separated by commas.
+struct Arguments<'a>(&'a [BoundExpression]);
+
+impl Display for Arguments<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, expression) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{}", Source(expression))?;
+        }
+        Ok(())
+    }
+}
+
+/// Parameters with their types, separated by commas.
+struct Parameters<'a>(&'a [VariableSymbol]);
+
+impl Display for Parameters<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, parameter) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{}: {}", parameter.name(), parameter.ty())?;
+        }
+        Ok(())
+    }
+}
+
+/// A value as the literal that stands for it, with the suffix of its type
+/// unless it is an `int` or a `f64`.
+pub(crate) struct Literal<'a>(pub(crate) &'a SilverValue);
+
+impl Literal<'_> {
+    fn is_negative(value: &SilverValue) -> bool {
+        value.ty().is_numeric() && value.to_string().starts_with('-')
+    }
+}
+
+impl Display for Literal<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            SilverValue::String(text) => {
+                f.write_char('"')?;
+                for c in text.chars() {
+                    match c {
+                        '\n' => f.write_str("\\n")?,
+                        '\r' => f.write_str("\\r")?,
+                        '\t' => f.write_str("\\t")?,
+                        '\0' => f.write_str("\\0")?,
+                        '"' | '\\' => write!(f, "\\{}", c)?,
+                        c => f.write_char(c)?,
+                    }
+                }
+                f.write_char('"')
+            }
+            value if value.ty().is_integer() && value.ty() != SilverType::Integer => {
+                write!(f, "{}{}", value, value.ty())
+            }
+            value => write!(f, "{}", value),
+        }
+    }
+}
+
+/// A pattern that matches values of type `.1`.
+pub(crate) struct Pattern<'a>(pub(crate) &'a BoundPattern, pub(crate) &'a SilverType);
+
+impl Display for Pattern<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            BoundPattern::Literal(value) => write!(f, "{}", Literal(value)),
+            BoundPattern::Binding(Some(variable)) => f.write_str(variable.name()),
+            BoundPattern::Binding(None) => f.write_str("_"),
+            BoundPattern::Variant { variant, payload } => {
+                let ty = match self.1.non_null() {
+                    SilverType::Enum(ty) => ty,
+                    _ => unreachable!("the binder only matches variants of enums"),
+                };
+                let variant = &ty.variants()[*variant];
+                write!(f, "{}.{}", ty.name(), variant.name())?;
+                if !payload.is_empty() {
+                    f.write_char('(')?;
+                    for (i, (pattern, ty)) in payload.iter().zip(variant.payload()).enumerate() {
+                        if i > 0 {
+                            f.write_str(", ")?;
+                        }
+                        write!(f, "{}", Pattern(pattern, ty))?;
+                    }
+                    f.write_char(')')?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// The head of a function declaration, up to the `=>` before its body.
+pub(crate) struct FunctionHead<'a>(pub(crate) &'a FunctionSymbol);
+
+impl Display for FunctionHead<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let function = self.0;
+        write!(f, "fn {}", function.name())?;
+        if !function.type_parameters().is_empty() {
+            write!(f, "<{}>", function.type_parameters().join(", "))?;
+        }
+        write!(
+            f,
+            "({}): {}",
+            Parameters(function.parameters()),
+            function.ty()
+        )
+    }
+}
+
+/// The declaration of a struct or an enum type.
+pub(crate) struct TypeDeclaration<'a>(pub(crate) &'a SilverType);
+
+impl Display for TypeDeclaration<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            SilverType::Struct(symbol) => {
+                write!(f, "struct {} {{", self.0)?;
+                write_list(f, symbol.fields(), |f, field| {
+                    write!(f, "{}: {}", field.name(), field.ty())
+                })
+            }
+            SilverType::Enum(symbol) => {
+                write!(f, "enum {} {{", self.0)?;
+                write_list(f, symbol.variants(), |f, variant| {
+                    f.write_str(variant.name())?;
+                    if !variant.payload().is_empty() {
+                        f.write_char('(')?;
+                        for (i, ty) in variant.payload().iter().enumerate() {
+                            if i > 0 {
+                                f.write_str(", ")?;
+                            }
+                            write!(f, "{}", ty)?;
+                        }
+                        f.write_char(')')?;
+                    }
+                    Ok(())
+                })
+            }
+            ty => write!(f, "{}", ty),
+        }
+    }
+}
+
+/// Write ` a, b }`, the members of a declaration after its `{`.
+fn write_list<T>(
+    f: &mut Formatter<'_>,
+    items: &[T],
+    mut write_item: impl FnMut(&mut Formatter<'_>, &T) -> fmt::Result,
+) -> fmt::Result {
+    for (i, item) in items.iter().enumerate() {
+        f.write_str(if i > 0 { ", " } else { " " })?;
+        write_item(f, item)?;
+    }
+    f.write_str(" }")
+}
+
+/// The name of field `index` of a struct of type `ty`.
+pub(crate) fn field_name(ty: &SilverType, index: usize) -> &str {
+    match ty.non_null() {
+        SilverType::Struct(symbol) => symbol.fields()[index].name(),
+        _ => unreachable!("the binder only assigns fields of structs"),
+    }
+}
+
+fn operator_text(kind: SyntaxKind) -> &'static str {
+    kind.get_text().unwrap()
+}
@@ -0,0 +1,15 @@
+use crate::analysis::{silver_value::SilverValue, variable_symbol::VariableSymbol};
+
+#[derive(Debug, Clone)]
+pub(crate) enum BoundPattern {
+    /// Matches a value equal to the literal, which has the scrutinee's type.
+    Literal(SilverValue),
+    /// Matches anything. `None` discards the value, as `_` does.
+    Binding(Option<VariableSymbol>),
+    /// Matches the variant of the matched enum type with index `variant`,
+    /// when its payload matches `payload`.
+    Variant {
+        variant: usize,
+        payload: Vec<BoundPattern>,
+    },
+}
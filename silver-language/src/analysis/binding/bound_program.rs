@@ -0,0 +1,17 @@
+use std::collections::HashSet;
+
+use crate::analysis::{function_symbol::FunctionSymbol, silver_type::SilverType};
+
+use super::bound_expression::BoundExpression;
+
+/// A bound submission: the types and functions it declares and the
+/// expressions to run.
+#[derive(Debug, Clone)]
+pub(crate) struct BoundProgram {
+    pub(crate) types: Vec<SilverType>,
+    pub(crate) functions: Vec<(FunctionSymbol, BoundExpression)>,
+    pub(crate) expressions: Vec<BoundExpression>,
+    /// The names of the variables that its lambdas, or those of earlier
+    /// submissions, assign.
+    pub(crate) assigned_by_lambdas: HashSet<String>,
+}
@@ -16,41 +16,51 @@ pub(crate) struct BoundUnaryOperator {
 }
 
 impl BoundUnaryOperator {
+    pub(crate) fn syntax_kind(&self) -> SyntaxKind {
+        self.syntax_kind
+    }
+
     pub(crate) fn kind(&self) -> BoundUnaryOperatorKind {
         self.kind
     }
 
-    pub(crate) fn result_type(&self) -> SilverType {
-        self.result_type
+    pub(crate) fn result_type(&self) -> &SilverType {
+        &self.result_type
     }
 
     fn operators() -> Vec<Self> {
-        vec![
-            BoundUnaryOperator::new(
-                SyntaxKind::BangToken,
-                BoundUnaryOperatorKind::LogicalNegation,
-                SilverType::Boolean,
-                SilverType::Boolean,
-            ),
-            BoundUnaryOperator::new(
+        let mut operators = vec![BoundUnaryOperator::new(
+            SyntaxKind::BangToken,
+            BoundUnaryOperatorKind::LogicalNegation,
+            SilverType::Boolean,
+            SilverType::Boolean,
+        )];
+        for ty in SilverType::integer_types()
+            .iter()
+            .chain(std::iter::once(&SilverType::Float))
+        {
+            operators.push(BoundUnaryOperator::new(
                 SyntaxKind::PlusToken,
                 BoundUnaryOperatorKind::Identity,
-                SilverType::Integer,
-                SilverType::Integer,
-            ),
-            BoundUnaryOperator::new(
-                SyntaxKind::MinusToken,
-                BoundUnaryOperatorKind::Negation,
-                SilverType::Integer,
-                SilverType::Integer,
-            ),
-        ]
+                ty.clone(),
+                ty.clone(),
+            ));
+            if ty.is_signed_integer() || *ty == SilverType::Float {
+                operators.push(BoundUnaryOperator::new(
+                    SyntaxKind::MinusToken,
+                    BoundUnaryOperatorKind::Negation,
+                    ty.clone(),
+                    ty.clone(),
+                ));
+            }
+        }
+        operators
     }
 
-    pub(crate) fn bind(syntax_kind: SyntaxKind, operand_type: SilverType) -> Option<Self> {
+    pub(crate) fn bind(syntax_kind: SyntaxKind, operand_type: &SilverType) -> Option<Self> {
         Self::operators()
             .iter()
-            .find(|op| op.syntax_kind == syntax_kind && op.operand_type == operand_type)
+            .find(|op| op.syntax_kind == syntax_kind && &op.operand_type == operand_type)
             .cloned()
     }
 }
@@ -63,4 +73,12 @@ impl BoundNode for BoundUnaryOperator {
     fn children(&self) -> Vec<&dyn BoundNode> {
         vec![]
     }
+
+    fn properties(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("kind", self.kind.to_string()),
+            ("operand", self.operand_type.to_string()),
+            ("type", self.result_type.to_string()),
+        ]
+    }
 }
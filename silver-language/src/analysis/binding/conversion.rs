@@ -0,0 +1,143 @@
+use crate::analysis::silver_type::SilverType;
+
+/// How a value of one type may become a value of another.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum Conversion {
+    /// The types are the same.
+    Identity,
+    /// Every value fits the target, so the binder converts without being asked.
+    Implicit,
+    /// Values may be truncated, rounded or reinterpreted, so only a cast converts.
+    Explicit,
+    /// No conversion exists.
+    None,
+}
+
+impl Conversion {
+    pub(crate) fn classify(from: &SilverType, to: &SilverType) -> Self {
+        if from == to {
+            return Conversion::Identity;
+        }
+        match to {
+            // Null and every value of the inner type are values of `T?`.
+            SilverType::Nullable(_) if *from == SilverType::Null => return Conversion::Implicit,
+            SilverType::Nullable(inner) => {
+                return match Conversion::classify(from.non_null(), inner) {
+                    Conversion::Identity => Conversion::Implicit,
+                    conversion => conversion,
+                }
+            }
+            // Every value has a string form, but only a cast asks for it.
+            SilverType::String => return Conversion::Explicit,
+            _ => {}
+        }
+        match (from.integer_bits(), to.integer_bits()) {
+            (Some(from_bits), Some(to_bits)) => {
+                let widens = if from.is_signed_integer() == to.is_signed_integer() {
+                    from_bits < to_bits
+                } else {
+                    // Only unsigned to strictly wider signed keeps every value.
+                    !from.is_signed_integer() && from_bits < to_bits
+                };
+                if widens {
+                    Conversion::Implicit
+                } else {
+                    Conversion::Explicit
+                }
+            }
+            _ if from.is_numeric() && to.is_numeric() => Conversion::Explicit,
+            _ => Conversion::None,
+        }
+    }
+
+    pub(crate) fn exists(self) -> bool {
+        self != Conversion::None
+    }
+
+    pub(crate) fn is_implicit(self) -> bool {
+        matches!(self, Conversion::Identity | Conversion::Implicit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nullable(ty: SilverType) -> SilverType {
+        SilverType::nullable(ty)
+    }
+
+    #[test]
+    fn classifies_conversions() {
+        for (from, to, expected) in [
+            (SilverType::Int8, SilverType::Int8, Conversion::Identity),
+            (SilverType::Int8, SilverType::Int64, Conversion::Implicit),
+            (SilverType::Int64, SilverType::Int8, Conversion::Explicit),
+            (SilverType::UInt8, SilverType::UInt16, Conversion::Implicit),
+            (SilverType::UInt8, SilverType::Int16, Conversion::Implicit),
+            (SilverType::UInt8, SilverType::Int8, Conversion::Explicit),
+            (SilverType::Int8, SilverType::UInt64, Conversion::Explicit),
+            (
+                SilverType::UInt64,
+                SilverType::Integer,
+                Conversion::Implicit,
+            ),
+            (SilverType::Integer, SilverType::Float, Conversion::Explicit),
+            (SilverType::Float, SilverType::UInt8, Conversion::Explicit),
+            (SilverType::Boolean, SilverType::Integer, Conversion::None),
+            (SilverType::Float, SilverType::Boolean, Conversion::None),
+            (
+                SilverType::Int8,
+                nullable(SilverType::Int8),
+                Conversion::Implicit,
+            ),
+            (
+                SilverType::Int8,
+                nullable(SilverType::Int64),
+                Conversion::Implicit,
+            ),
+            (
+                SilverType::Null,
+                nullable(SilverType::Float),
+                Conversion::Implicit,
+            ),
+            (
+                nullable(SilverType::Int8),
+                nullable(SilverType::Int64),
+                Conversion::Implicit,
+            ),
+            (
+                nullable(SilverType::Int64),
+                nullable(SilverType::Int8),
+                Conversion::Explicit,
+            ),
+            (
+                nullable(SilverType::Int8),
+                SilverType::Int8,
+                Conversion::None,
+            ),
+            (SilverType::Null, SilverType::Int8, Conversion::None),
+            (
+                SilverType::Boolean,
+                SilverType::String,
+                Conversion::Explicit,
+            ),
+            (
+                nullable(SilverType::Int8),
+                SilverType::String,
+                Conversion::Explicit,
+            ),
+            (SilverType::String, SilverType::Integer, Conversion::None),
+        ]
+        .iter()
+        {
+            assert_eq!(
+                *expected,
+                Conversion::classify(from, to),
+                "{} -> {}",
+                from,
+                to
+            );
+        }
+    }
+}
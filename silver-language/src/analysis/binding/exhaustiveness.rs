@@ -0,0 +1,208 @@
+//! Checks of match arms against the values they must cover, following the
+//! usefulness algorithm of Maranget's "Warnings for pattern matching".
+//!
+//! Patterns are laid out as rows of a matrix, one column per value being
+//! matched. A wildcard, written `None`, stands for a binding pattern.
+
+use crate::analysis::{silver_type::SilverType, silver_value::SilverValue};
+
+use super::bound_pattern::BoundPattern;
+
+type Row<'a> = Vec<Option<&'a BoundPattern>>;
+
+/// What a pattern requires of the outermost shape of a value.
+#[derive(Debug, Clone, PartialEq)]
+enum Constructor {
+    Literal(SilverValue),
+    Variant(usize),
+}
+
+impl Constructor {
+    fn of(pattern: Option<&BoundPattern>) -> Option<Self> {
+        match pattern? {
+            BoundPattern::Literal(value) => Some(Constructor::Literal(value.clone())),
+            BoundPattern::Binding(_) => None,
+            BoundPattern::Variant { variant, .. } => Some(Constructor::Variant(*variant)),
+        }
+    }
+
+    /// Every constructor of `ty`, if it has finitely many.
+    fn all(ty: &SilverType) -> Option<Vec<Self>> {
+        match ty {
+            SilverType::Nullable(inner) => {
+                let mut constructors = Constructor::all(inner)?;
+                constructors.push(Constructor::Literal(SilverValue::Null));
+                Some(constructors)
+            }
+            SilverType::Boolean => Some(vec![
+                Constructor::Literal(SilverValue::Boolean(true)),
+                Constructor::Literal(SilverValue::Boolean(false)),
+            ]),
+            SilverType::Enum(symbol) => Some(
+                (0..symbol.variants().len())
+                    .map(Constructor::Variant)
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+
+    /// The types of the values nested in a value of type `ty` built by this.
+    fn field_types(&self, ty: &SilverType) -> Vec<SilverType> {
+        match (self, ty.non_null()) {
+            (Constructor::Variant(variant), SilverType::Enum(symbol)) => {
+                symbol.payload_types(*variant)
+            }
+            _ => vec![],
+        }
+    }
+
+    /// Render the constructor of `ty` applied to patterns for its fields.
+    fn display(&self, ty: &SilverType, fields: &[String]) -> String {
+        match (self, ty.non_null()) {
+            (Constructor::Variant(variant), SilverType::Enum(symbol)) => {
+                let name = format!("{}.{}", symbol.name(), symbol.variants()[*variant].name());
+                if fields.is_empty() {
+                    name
+                } else {
+                    format!("{}({})", name, fields.join(", "))
+                }
+            }
+            (Constructor::Literal(value), _) => value.to_string(),
+            (Constructor::Variant(_), _) => unreachable!("only enums have variants"),
+        }
+    }
+}
+
+/// Whether some value matched by `pattern` is matched by none of `earlier`,
+/// all of which match values of type `ty`.
+pub(crate) fn is_useful(
+    earlier: &[&BoundPattern],
+    pattern: &BoundPattern,
+    ty: &SilverType,
+) -> bool {
+    let rows = earlier.iter().map(|&p| vec![Some(p)]).collect::<Vec<_>>();
+    is_useful_row(&rows, &[Some(pattern)], std::slice::from_ref(ty))
+}
+
+/// Patterns for the values of type `ty` that none of `patterns` match, or an
+/// empty list if the patterns are exhaustive.
+pub(crate) fn missing_patterns(patterns: &[&BoundPattern], ty: &SilverType) -> Vec<String> {
+    let rows = patterns.iter().map(|&p| vec![Some(p)]).collect::<Vec<_>>();
+    witnesses(&rows, std::slice::from_ref(ty))
+        .into_iter()
+        .map(|mut witness| witness.remove(0))
+        .collect()
+}
+
+fn is_useful_row(rows: &[Row], row: &[Option<&BoundPattern>], types: &[SilverType]) -> bool {
+    let (head, rest) = match row.split_first() {
+        Some(split) => split,
+        None => return rows.is_empty(),
+    };
+    let ty = &types[0];
+    match Constructor::of(*head) {
+        Some(constructor) => {
+            let field_types = constructor.field_types(ty);
+            is_useful_row(
+                &specialize(rows, &constructor, field_types.len()),
+                &specialize_row(row, &constructor, field_types.len()).unwrap(),
+                &[field_types.as_slice(), &types[1..]].concat(),
+            )
+        }
+        None => match complete_constructors(rows, ty) {
+            Some(constructors) => constructors.iter().any(|constructor| {
+                let field_types = constructor.field_types(ty);
+                is_useful_row(
+                    &specialize(rows, constructor, field_types.len()),
+                    &specialize_row(row, constructor, field_types.len()).unwrap(),
+                    &[field_types.as_slice(), &types[1..]].concat(),
+                )
+            }),
+            None => is_useful_row(&default_rows(rows), rest, &types[1..]),
+        },
+    }
+}
+
+/// Rows of patterns, one per column of `types`, that no row of `rows`
+/// matches.
+fn witnesses(rows: &[Row], types: &[SilverType]) -> Vec<Vec<String>> {
+    if rows.is_empty() {
+        return vec![vec!["_".to_string(); types.len()]];
+    }
+    let ty = match types.first() {
+        Some(ty) => ty,
+        None => return vec![],
+    };
+    let mentioned = rows.iter().any(|row| Constructor::of(row[0]).is_some());
+    match Constructor::all(ty) {
+        // Name the missing constructors when the arms single any out.
+        Some(constructors) if mentioned => constructors
+            .iter()
+            .flat_map(|constructor| {
+                let field_types = constructor.field_types(ty);
+                let arity = field_types.len();
+                witnesses(
+                    &specialize(rows, constructor, arity),
+                    &[field_types.as_slice(), &types[1..]].concat(),
+                )
+                .into_iter()
+                .map(move |witness| {
+                    let mut row = vec![constructor.display(ty, &witness[..arity])];
+                    row.extend_from_slice(&witness[arity..]);
+                    row
+                })
+            })
+            .collect(),
+        _ => witnesses(&default_rows(rows), &types[1..])
+            .into_iter()
+            .map(|witness| {
+                let mut row = vec!["_".to_string()];
+                row.extend(witness);
+                row
+            })
+            .collect(),
+    }
+}
+
+/// All constructors of `ty`, if the first column of `rows` uses every one.
+fn complete_constructors(rows: &[Row], ty: &SilverType) -> Option<Vec<Constructor>> {
+    let used = rows
+        .iter()
+        .filter_map(|row| Constructor::of(row[0]))
+        .collect::<Vec<_>>();
+    Constructor::all(ty).filter(|all| all.iter().all(|constructor| used.contains(constructor)))
+}
+
+/// The rows that match a value built by `constructor`, with the first column
+/// replaced by the `arity` patterns for its fields.
+fn specialize<'a>(rows: &[Row<'a>], constructor: &Constructor, arity: usize) -> Vec<Row<'a>> {
+    rows.iter()
+        .filter_map(|row| specialize_row(row, constructor, arity))
+        .collect()
+}
+
+fn specialize_row<'a>(
+    row: &[Option<&'a BoundPattern>],
+    constructor: &Constructor,
+    arity: usize,
+) -> Option<Row<'a>> {
+    let mut specialized = match row[0] {
+        None | Some(BoundPattern::Binding(_)) => vec![None; arity],
+        Some(pattern) if Constructor::of(Some(pattern)).as_ref() != Some(constructor) => {
+            return None
+        }
+        Some(BoundPattern::Variant { payload, .. }) => payload.iter().map(Some).collect(),
+        Some(BoundPattern::Literal(_)) => vec![],
+    };
+    specialized.extend_from_slice(&row[1..]);
+    Some(specialized)
+}
+
+/// The rows whose first column matches anything, without that column.
+fn default_rows<'a>(rows: &[Row<'a>]) -> Vec<Row<'a>> {
+    rows.iter()
+        .filter(|row| Constructor::of(row[0]).is_none())
+        .map(|row| row[1..].to_vec())
+        .collect()
+}
@@ -0,0 +1,360 @@
+//! Inference of the types of unannotated function parameters.
+//!
+//! While the body of a function binds to infer them, each such parameter
+//! has a type variable for its type. Each use of a value whose type is not
+//! yet known unifies that type with the type the use requires, so that the
+//! variables come to stand for the types the body needs.
+//!
+//! The type arguments of a generic function or type are inferred apart from
+//! that, by matching the types its declaration expects against the types of
+//! the values a use gives it.
+
+use crate::analysis::{silver_type::SilverType, text::text_span::TextSpan};
+
+use super::conversion::Conversion;
+
+/// A use of a value as one type where its uses so far require another.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct TypeConflict {
+    pub(crate) span: TextSpan,
+    pub(crate) expected: SilverType,
+    pub(crate) actual: SilverType,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct TypeInference {
+    /// What each type variable stands for, once known.
+    variables: Vec<Option<SilverType>>,
+    conflicts: Vec<TypeConflict>,
+}
+
+impl TypeInference {
+    /// A new type variable that stands for nothing yet.
+    pub(crate) fn fresh(&mut self) -> SilverType {
+        self.variables.push(None);
+        SilverType::Variable(self.variables.len() - 1)
+    }
+
+    pub(crate) fn conflicts(&self) -> &[TypeConflict] {
+        &self.conflicts
+    }
+
+    /// `ty` with each variable known so far replaced by what it stands for.
+    pub(crate) fn resolve(&self, ty: &SilverType) -> SilverType {
+        match ty {
+            SilverType::Variable(variable) => match &self.variables[*variable] {
+                Some(ty) => self.resolve(ty),
+                None => ty.clone(),
+            },
+            SilverType::Array(element_type) => {
+                SilverType::Array(Box::new(self.resolve(element_type)))
+            }
+            SilverType::Nullable(inner) => SilverType::nullable(self.resolve(inner)),
+            SilverType::Function(parameters, ty) => SilverType::Function(
+                parameters
+                    .iter()
+                    .map(|parameter| self.resolve(parameter))
+                    .collect(),
+                Box::new(self.resolve(ty)),
+            ),
+            ty => ty.clone(),
+        }
+    }
+
+    /// Whether `ty` involves a variable that stands for nothing yet.
+    pub(crate) fn is_unknown(&self, ty: &SilverType) -> bool {
+        contains_variable(&self.resolve(ty), None)
+    }
+
+    /// Require `actual`, the type of a value used at `span`, to be
+    /// `expected`, recording a conflict if it cannot be.
+    pub(crate) fn unify(&mut self, span: TextSpan, expected: &SilverType, actual: &SilverType) {
+        let expected = self.resolve(expected);
+        let actual = self.resolve(actual);
+        if !self.unify_resolved(&expected, &actual) {
+            self.conflicts.push(TypeConflict {
+                span,
+                expected: self.resolve(&expected),
+                actual: self.resolve(&actual),
+            });
+        }
+    }
+
+    fn unify_resolved(&mut self, left: &SilverType, right: &SilverType) -> bool {
+        match (left, right) {
+            _ if left == right => true,
+            _ if left.is_error() || right.is_error() => true,
+            // Null is a value of any nullable type.
+            (SilverType::Variable(variable), SilverType::Null)
+            | (SilverType::Null, SilverType::Variable(variable)) => {
+                let inner = self.fresh();
+                self.variables[*variable] = Some(SilverType::Nullable(Box::new(inner)));
+                true
+            }
+            (SilverType::Nullable(_), SilverType::Null)
+            | (SilverType::Null, SilverType::Nullable(_)) => true,
+            (SilverType::Variable(variable), ty) | (ty, SilverType::Variable(variable)) => {
+                // A type cannot contain itself.
+                if contains_variable(ty, Some(*variable)) {
+                    return false;
+                }
+                self.variables[*variable] = Some(ty.clone());
+                true
+            }
+            (SilverType::Array(left), SilverType::Array(right))
+            | (SilverType::Nullable(left), SilverType::Nullable(right)) => {
+                self.unify_resolved(left, right)
+            }
+            (
+                SilverType::Function(left_parameters, left),
+                SilverType::Function(right_parameters, right),
+            ) if left_parameters.len() == right_parameters.len() => {
+                // Earlier parts may have bound variables that later parts
+                // mention, so each part is resolved again first.
+                left_parameters
+                    .iter()
+                    .zip(right_parameters)
+                    .chain(std::iter::once((left.as_ref(), right.as_ref())))
+                    .all(|(left, right)| {
+                        let left = self.resolve(left);
+                        let right = self.resolve(right);
+                        self.unify_resolved(&left, &right)
+                    })
+            }
+            _ => false,
+        }
+    }
+}
+
+/// A type parameter given values of two types, neither of which widens into
+/// the other.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct TypeArgumentConflict {
+    pub(crate) parameter: String,
+    pub(crate) first: SilverType,
+    pub(crate) second: SilverType,
+}
+
+/// Match `template`, a type written in terms of `type_parameters`, against
+/// `actual`, the type of a value given for it, recording in `arguments` the
+/// type each parameter stands for. A parameter given values of several
+/// types stands for the one the others widen into.
+pub(crate) fn infer_type_arguments(
+    type_parameters: &[String],
+    template: &SilverType,
+    actual: &SilverType,
+    arguments: &mut [Option<SilverType>],
+) -> Result<(), TypeArgumentConflict> {
+    // Null fits any nullable type, and says nothing about the inner one.
+    if matches!(actual, SilverType::Null | SilverType::Error) || has_variables(actual) {
+        return Ok(());
+    }
+    match (template, actual) {
+        (SilverType::Parameter(name), _) => {
+            let i = match type_parameters
+                .iter()
+                .position(|parameter| parameter == name)
+            {
+                Some(i) => i,
+                None => return Ok(()),
+            };
+            match &arguments[i] {
+                None => arguments[i] = Some(actual.clone()),
+                Some(ty) if Conversion::classify(actual, ty).is_implicit() => {}
+                Some(ty) if Conversion::classify(ty, actual).is_implicit() => {
+                    arguments[i] = Some(actual.clone())
+                }
+                Some(ty) => {
+                    return Err(TypeArgumentConflict {
+                        parameter: name.clone(),
+                        first: ty.clone(),
+                        second: actual.clone(),
+                    })
+                }
+            }
+            Ok(())
+        }
+        (SilverType::Array(template), SilverType::Array(actual)) => {
+            infer_type_arguments(type_parameters, template, actual, arguments)
+        }
+        (
+            SilverType::Function(template_parameters, template),
+            SilverType::Function(actual_parameters, actual),
+        ) => template_parameters
+            .iter()
+            .zip(actual_parameters)
+            .chain(std::iter::once((template.as_ref(), actual.as_ref())))
+            .try_for_each(|(template, actual)| {
+                infer_type_arguments(type_parameters, template, actual, arguments)
+            }),
+        // A value of `T` is also a value of `T?`.
+        (SilverType::Nullable(template), actual) => {
+            infer_type_arguments(type_parameters, template, actual.non_null(), arguments)
+        }
+        (SilverType::Struct(template), SilverType::Struct(actual))
+            if template.name() == actual.name() =>
+        {
+            template
+                .type_arguments()
+                .iter()
+                .zip(actual.type_arguments())
+                .try_for_each(|(template, actual)| {
+                    infer_type_arguments(type_parameters, template, actual, arguments)
+                })
+        }
+        (SilverType::Enum(template), SilverType::Enum(actual))
+            if template.name() == actual.name() =>
+        {
+            template
+                .type_arguments()
+                .iter()
+                .zip(actual.type_arguments())
+                .try_for_each(|(template, actual)| {
+                    infer_type_arguments(type_parameters, template, actual, arguments)
+                })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Whether `ty` mentions a type variable, whether or not it is known yet.
+pub(crate) fn has_variables(ty: &SilverType) -> bool {
+    contains_variable(ty, None)
+}
+
+/// Whether `ty` contains the type variable `variable`, or any variable if
+/// it is `None`.
+fn contains_variable(ty: &SilverType, variable: Option<usize>) -> bool {
+    match ty {
+        SilverType::Variable(v) => variable.is_none() || variable == Some(*v),
+        SilverType::Array(inner) | SilverType::Nullable(inner) => {
+            contains_variable(inner, variable)
+        }
+        SilverType::Function(parameters, ty) => {
+            parameters
+                .iter()
+                .any(|parameter| contains_variable(parameter, variable))
+                || contains_variable(ty, variable)
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unifies_variables_with_types() {
+        let mut inference = TypeInference::default();
+        let a = inference.fresh();
+        let b = inference.fresh();
+        inference.unify(0..1, &a, &b);
+        assert!(inference.is_unknown(&a));
+        inference.unify(0..1, &SilverType::Array(Box::new(SilverType::Integer)), &b);
+        assert_eq!(
+            SilverType::Array(Box::new(SilverType::Integer)),
+            inference.resolve(&a)
+        );
+        let c = inference.fresh();
+        inference.unify(
+            0..1,
+            &SilverType::nullable(SilverType::Boolean),
+            &SilverType::Nullable(Box::new(c.clone())),
+        );
+        assert_eq!(SilverType::Boolean, inference.resolve(&c));
+        assert!(inference.conflicts().is_empty());
+    }
+
+    #[test]
+    fn unifies_function_types() {
+        let mut inference = TypeInference::default();
+        let a = inference.fresh();
+        let b = inference.fresh();
+        let f = SilverType::Function(vec![a.clone()], Box::new(a.clone()));
+        inference.unify(
+            0..1,
+            &SilverType::Function(vec![SilverType::Boolean], Box::new(b.clone())),
+            &f,
+        );
+        assert_eq!(SilverType::Boolean, inference.resolve(&b));
+        inference.unify(
+            2..3,
+            &SilverType::Function(vec![], Box::new(SilverType::Boolean)),
+            &f,
+        );
+        assert_eq!(2..3, inference.conflicts()[0].span);
+    }
+
+    #[test]
+    fn null_makes_a_variable_nullable() {
+        let mut inference = TypeInference::default();
+        let a = inference.fresh();
+        inference.unify(0..1, &a, &SilverType::Null);
+        inference.unify(0..1, &SilverType::nullable(SilverType::Float), &a);
+        assert_eq!(
+            SilverType::nullable(SilverType::Float),
+            inference.resolve(&a)
+        );
+        assert!(inference.conflicts().is_empty());
+    }
+
+    #[test]
+    fn records_conflicts() {
+        let mut inference = TypeInference::default();
+        let a = inference.fresh();
+        inference.unify(0..1, &SilverType::Boolean, &a);
+        inference.unify(2..3, &SilverType::Integer, &a);
+        let b = inference.fresh();
+        inference.unify(4..5, &SilverType::Array(Box::new(b.clone())), &b);
+        assert_eq!(
+            &[
+                TypeConflict {
+                    span: 2..3,
+                    expected: SilverType::Integer,
+                    actual: SilverType::Boolean,
+                },
+                TypeConflict {
+                    span: 4..5,
+                    expected: SilverType::Array(Box::new(b.clone())),
+                    actual: b,
+                },
+            ],
+            inference.conflicts()
+        );
+    }
+
+    #[test]
+    fn infers_type_arguments() {
+        let parameters = ["T".to_string(), "U".to_string()];
+        let t = SilverType::Parameter("T".to_string());
+        let u = SilverType::Parameter("U".to_string());
+        let mut arguments = [None, None];
+        for (template, actual) in [
+            (t.clone(), SilverType::Int8),
+            (t.clone(), SilverType::Integer),
+            (t.clone(), SilverType::Null),
+            (
+                SilverType::nullable(SilverType::Array(Box::new(u.clone()))),
+                SilverType::Array(Box::new(SilverType::Boolean)),
+            ),
+        ] {
+            assert_eq!(
+                Ok(()),
+                infer_type_arguments(&parameters, &template, &actual, &mut arguments)
+            );
+        }
+        assert_eq!(
+            [Some(SilverType::Integer), Some(SilverType::Boolean)],
+            arguments
+        );
+        assert_eq!(
+            Err(TypeArgumentConflict {
+                parameter: "U".to_string(),
+                first: SilverType::Boolean,
+                second: SilverType::Float,
+            }),
+            infer_type_arguments(&parameters, &u, &SilverType::Float, &mut arguments)
+        );
+    }
+}
@@ -1,13 +1,31 @@
-use std::collections::HashMap;
+use std::{
+    io::{self, Write},
+    rc::Rc,
+    time::Instant,
+};
 
 use super::{
-    binding::binder::Binder, errors::error_reporter::ErrorReporter, evaluator::Evaluator,
-    silver_value::SilverValue, syntax::syntax_tree::SyntaxTree, variable_symbol::VariableSymbol,
+    binding::{
+        binder::Binder,
+        bound_node,
+        bound_node_printer::{FunctionHead, Source, TypeDeclaration},
+        bound_program::BoundProgram,
+    },
+    cancellation_token::CancellationToken,
+    errors::error_reporter::ErrorReporter,
+    evaluator::{Evaluator, DEFAULT_MAX_CALL_DEPTH},
+    global_scope::GlobalScope,
+    silver_type::SilverType,
+    silver_value::SilverValue,
+    statistics::Statistics,
+    syntax::syntax_tree::SyntaxTree,
 };
 
 pub struct Compilation<'syntax, 'reporter> {
     syntax: &'syntax SyntaxTree,
     error_reporter: &'reporter mut dyn ErrorReporter,
+    statistics: Statistics,
+    max_call_depth: usize,
 }
 
 impl<'syntax, 'reporter> Compilation<'syntax, 'reporter> {
@@ -16,21 +34,257 @@ impl<'syntax, 'reporter> Compilation<'syntax, 'reporter> {
         error_reporter: &'reporter mut dyn ErrorReporter,
     ) -> Self {
         Self {
+            statistics: Statistics {
+                lexing_time: syntax.lexing_time(),
+                parsing_time: syntax.parsing_time(),
+                ..Statistics::default()
+            },
             syntax,
             error_reporter,
+            max_call_depth: DEFAULT_MAX_CALL_DEPTH,
         }
     }
 
-    pub fn evaluate(
+    /// Let calls nest `depth` deep before evaluation reports an error. Every
+    /// call takes native stack, so a deeper limit than the default needs
+    /// the evaluation to run on a thread with a larger stack.
+    pub fn set_max_call_depth(&mut self, depth: usize) {
+        self.max_call_depth = depth;
+    }
+
+    /// What parsing the submission and the last `evaluate` measured.
+    pub fn statistics(&self) -> &Statistics {
+        &self.statistics
+    }
+
+    /// Bind and run the submission against `scope`. Returns the value of its
+    /// last expression, or `None` if an error was reported or it has no
+    /// expressions.
+    pub fn evaluate(&mut self, scope: &mut GlobalScope) -> Option<SilverValue> {
+        self.evaluate_cancellable(scope, &CancellationToken::new())
+    }
+
+    /// Like `evaluate`, but once `cancellation_token` is cancelled, the next
+    /// call reports an error and the evaluation stops. As after any runtime
+    /// error, `scope` keeps the declarations and the assignments made before
+    /// then.
+    pub fn evaluate_cancellable(
         &mut self,
-        variables: &mut HashMap<VariableSymbol, SilverValue>,
+        scope: &mut GlobalScope,
+        cancellation_token: &CancellationToken,
     ) -> Option<SilverValue> {
-        let mut binder = Binder::new(variables, self.error_reporter);
-        let bound_tree = binder.bind(self.syntax.root());
+        let start = Instant::now();
+        let program = self.bind(scope);
+        self.statistics.binding_time = start.elapsed();
         if self.error_reporter.had_error() {
             return None;
         }
-        let mut evaluator = Evaluator::new(variables);
-        Some(evaluator.evaluate(&bound_tree))
+        for ty in program.types {
+            let name = ty.declared_name().unwrap().to_string();
+            scope.types.insert(name, ty);
+        }
+        for (function, body) in program.functions {
+            scope
+                .functions
+                .insert(function.name().to_string(), function.clone());
+            scope.function_bodies.insert(function, Rc::new(body));
+        }
+        scope.assigned_by_lambdas = program.assigned_by_lambdas;
+        let start = Instant::now();
+        let mut evaluator = Evaluator::new(
+            &mut scope.variables,
+            &scope.function_bodies,
+            self.max_call_depth,
+            cancellation_token,
+            self.error_reporter,
+        );
+        let value = evaluator.evaluate(&program.expressions);
+        self.statistics.evaluation_time = start.elapsed();
+        self.statistics.evaluated_nodes = evaluator.evaluated_nodes();
+        self.statistics.peak_variables = evaluator.peak_variables();
+        value
+    }
+
+    /// Bind the submission against `scope` without running it. Returns the
+    /// type of its last expression, or `None` if an error was reported or it
+    /// has no expressions.
+    pub fn type_of(&mut self, scope: &GlobalScope) -> Option<SilverType> {
+        let program = self.bind(scope);
+        if self.error_reporter.had_error() {
+            return None;
+        }
+        program.expressions.last().map(|expression| expression.ty())
+    }
+
+    /// Write the submission as bound against `scope`: the types it declares,
+    /// then the tree of each function body and of each expression to run,
+    /// with the kind, type and symbols of every node.
+    pub fn print_program(&mut self, scope: &GlobalScope, writer: &mut dyn Write) -> io::Result<()> {
+        let program = self.bind(scope);
+        for ty in &program.types {
+            writeln!(writer, "{}", TypeDeclaration(ty))?;
+        }
+        for (function, body) in &program.functions {
+            writeln!(writer, "{} =>", FunctionHead(function))?;
+            bound_node::pretty_print(body, writer)?;
+        }
+        for expression in &program.expressions {
+            bound_node::pretty_print(expression, writer)?;
+        }
+        Ok(())
+    }
+
+    /// Write the submission as bound against `scope` back as Silver source,
+    /// one declaration or expression per line, with the conversions that the
+    /// binder inserted written out.
+    pub fn print_program_source(
+        &mut self,
+        scope: &GlobalScope,
+        writer: &mut dyn Write,
+    ) -> io::Result<()> {
+        let program = self.bind(scope);
+        for ty in &program.types {
+            writeln!(writer, "{}", TypeDeclaration(ty))?;
+        }
+        for (function, body) in &program.functions {
+            writeln!(writer, "{} => {}", FunctionHead(function), Source(body))?;
+        }
+        for expression in &program.expressions {
+            writeln!(writer, "{}", Source(expression))?;
+        }
+        Ok(())
+    }
+
+    fn bind(&mut self, scope: &GlobalScope) -> BoundProgram {
+        Binder::new(scope, self.error_reporter).bind(self.syntax.root())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::errors::string_error_reporter::StringErrorReporter;
+    use std::time::Duration;
+
+    fn type_of(text: &str, scope: &GlobalScope) -> Option<SilverType> {
+        let mut error_reporter = StringErrorReporter::new();
+        let syntax_tree = SyntaxTree::parse_str(text, &mut error_reporter);
+        Compilation::new(&syntax_tree, &mut error_reporter).type_of(scope)
+    }
+
+    #[test]
+    fn finds_types_without_evaluating() {
+        let mut scope = GlobalScope::new();
+        let mut error_reporter = StringErrorReporter::new();
+        let syntax_tree = SyntaxTree::parse_str("x = 1i8 fn f(a: int) => a", &mut error_reporter);
+        Compilation::new(&syntax_tree, &mut error_reporter).evaluate(&mut scope);
+        assert!(!error_reporter.had_error());
+
+        assert_eq!(Some(SilverType::Int8), type_of("x + 1i8", &scope));
+        assert_eq!(Some(SilverType::Float), type_of("f(1) as f64", &scope));
+        assert_eq!(Some(SilverType::String), type_of("y = \"a\" y", &scope));
+        assert_eq!(None, type_of("x + true", &scope));
+        assert_eq!(None, type_of("fn g() => 1", &scope));
+        // The assignment was only bound, not run.
+        assert!(scope
+            .variables()
+            .all(|(variable, _)| variable.name() != "y"));
+    }
+
+    fn measure(text: &str) -> Statistics {
+        let mut error_reporter = StringErrorReporter::new();
+        let syntax_tree = SyntaxTree::parse_str(text, &mut error_reporter);
+        let mut compilation = Compilation::new(&syntax_tree, &mut error_reporter);
+        compilation.evaluate(&mut GlobalScope::new());
+        compilation.statistics().clone()
+    }
+
+    #[test]
+    fn measures_evaluations() {
+        // Two assignments and their literals.
+        let statistics = measure("x = 1 y = 2");
+        assert_eq!(4, statistics.evaluated_nodes());
+        assert_eq!(2, statistics.peak_variables());
+
+        // The outer calls hold their parameter and the binding of their arm
+        // until the innermost returns.
+        let statistics = measure("fn f(n: int): int => match n { 0 => 0, m => f(m - 1) } x = f(2)");
+        assert_eq!(5, statistics.peak_variables());
+        // A call lets go of its parameter when it returns.
+        let statistics = measure("x = 1 y = 2 fn f(n: int) => n f(1) f(2) z = 3");
+        assert_eq!(3, statistics.peak_variables());
+
+        let statistics = measure("x = 1 + true");
+        assert_eq!(0, statistics.evaluated_nodes());
+        assert_eq!(Duration::ZERO, statistics.evaluation_time());
+    }
+
+    fn print(text: &str, as_source: bool) -> String {
+        let mut error_reporter = StringErrorReporter::new();
+        let syntax_tree = SyntaxTree::parse_str(text, &mut error_reporter);
+        let mut compilation = Compilation::new(&syntax_tree, &mut error_reporter);
+        let mut output = vec![];
+        let scope = GlobalScope::new();
+        if as_source {
+            compilation
+                .print_program_source(&scope, &mut output)
+                .unwrap();
+        } else {
+            compilation.print_program(&scope, &mut output).unwrap();
+        }
+        assert!(error_reporter.errors().is_empty(), "{}", text);
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn prints_programs_as_trees() {
+        let expected = r"fn f(a: i8): i128 =>
+\--BinaryExpression type: i128
+   +--ConversionExpression type: i128
+   |  \--VariableExpression variable: a, type: i8
+   +--BinaryOperator kind: Addition, operands: i128, i128, type: i128
+   \--LiteralExpression value: 1000, type: i128
+\--CallExpression function: f, type: i128
+   \--LiteralExpression value: 2i8, type: i8
+";
+        assert_eq!(expected, print("fn f(a: i8) => a + 1000 f(2)", false));
+    }
+
+    #[test]
+    fn prints_programs_as_source() {
+        let text = r#"enum E<T> { A(T), B } struct P { x: int, s: string }
+            fn f<T>(e: E<T>, d: T): T => match e { E.A(v) => v, E.B => d }
+            p = P { x: -f(E.A(1), 2) * 3, s: $"\"{1u8}" }
+            p?.s ?? (() => [1.5][0])() as string"#;
+        let expected = r#"enum E<T> { A(T), B }
+struct P { x: i128, s: string }
+fn f<T>(e: E<T>, d: T): T => match e { E.A(v) => v, E.B => d }
+p = P { x: (-f(E.A(1), 2)) * 3, s: "\"" + (1u8 as string) }
+p?.s ?? ((() => [1.5][0])() as string)
+"#;
+        let source = print(text, true);
+        assert_eq!(expected, source);
+        // The source is a program that binds to the same tree.
+        assert_eq!(source, print(&source, true));
+
+        // Temporaries get names that hide nothing the program refers to.
+        for (text, expected) in [
+            (
+                "x = 1 x++",
+                "x = 1\nmatch x { old => match (x = old + 1) { _ => old } }\n",
+            ),
+            (
+                "x = 1 old = 2 x++ + old",
+                "x = 1\nold = 2\n(match x { old2 => match (x = old2 + 1) { _ => old2 } }) + old\n",
+            ),
+            (
+                "xs = [1] index = 0 xs[index] += index",
+                "xs = [1]\nindex = 0\nmatch xs { array => match index { index2 => array[index2] = array[index2] + index } }\n",
+            ),
+        ] {
+            let source = print(text, true);
+            assert_eq!(expected, source, "{}", text);
+            assert_eq!(source, print(&source, true), "{}", text);
+        }
     }
 }
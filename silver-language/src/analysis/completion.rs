@@ -0,0 +1,300 @@
+use std::sync::Arc;
+
+use super::{
+    errors::null_error_reporter::NullErrorReporter,
+    global_scope::GlobalScope,
+    silver_type::SilverType,
+    syntax::{
+        syntax_facts::{self, KEYWORDS},
+        syntax_kind::SyntaxKind,
+        syntax_tree::SyntaxTree,
+    },
+    text::{source_text::SourceText, text_span::TextSpan},
+};
+
+/// What a completion candidate names.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub enum CompletionKind {
+    Variable,
+    Function,
+    Type,
+    Keyword,
+    /// One of the commands given to `complete`, such as a REPL's
+    /// meta-commands.
+    Command,
+}
+
+/// Text that may replace the word being completed.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Completion {
+    text: String,
+    kind: CompletionKind,
+    detail: String,
+}
+
+impl Completion {
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn kind(&self) -> CompletionKind {
+        self.kind
+    }
+
+    /// What to show beside the candidate: the type of a variable or a
+    /// function, or the description of a command.
+    pub fn detail(&self) -> &str {
+        &self.detail
+    }
+}
+
+/// The candidates for completing the word in `span`, sorted by their text.
+/// The span takes in the whole word, including any of it after the cursor.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Completions {
+    span: TextSpan,
+    candidates: Vec<Completion>,
+}
+
+impl Completions {
+    /// The candidates that begin with `prefix`, if there are any.
+    fn matching(span: TextSpan, prefix: &str, candidates: Vec<Completion>) -> Option<Self> {
+        let mut candidates: Vec<_> = candidates
+            .into_iter()
+            .filter(|candidate| candidate.text.starts_with(prefix))
+            .collect();
+        if candidates.is_empty() {
+            return None;
+        }
+        candidates.sort_by(|a, b| (&a.text, a.kind).cmp(&(&b.text, b.kind)));
+        Some(Self { span, candidates })
+    }
+
+    pub fn span(&self) -> TextSpan {
+        self.span.clone()
+    }
+
+    pub fn candidates(&self) -> &[Completion] {
+        &self.candidates
+    }
+
+    /// The longest text that every candidate begins with.
+    pub fn common_prefix(&self) -> &str {
+        let first = self.candidates[0].text();
+        let length = self.candidates[1..]
+            .iter()
+            .map(|candidate| {
+                first
+                    .char_indices()
+                    .zip(candidate.text().chars())
+                    .find(|&((_, a), b)| a != b)
+                    .map_or(first.len().min(candidate.text().len()), |((i, _), _)| i)
+            })
+            .min()
+            .unwrap_or(first.len());
+        &first[..length]
+    }
+}
+
+/// Complete the word under byte `position` of `text` with the names in
+/// `scope` and the keywords that begin with its text before `position`, or with `commands`, given with their
+/// descriptions, when the word starts the text with a `#`. `None` means
+/// there is no word to complete, as inside a string or after a `.`, or
+/// nothing completes it.
+pub fn complete(
+    text: &str,
+    position: usize,
+    scope: &GlobalScope,
+    commands: &[(&str, &str)],
+) -> Option<Completions> {
+    let before = &text[..position];
+    if before.starts_with('#') && !before.contains(char::is_whitespace) {
+        let candidates = commands
+            .iter()
+            .map(|&(command, description)| Completion {
+                text: command.to_string(),
+                kind: CompletionKind::Command,
+                detail: description.to_string(),
+            })
+            .collect();
+        return Completions::matching(0..position, before, candidates);
+    }
+
+    // Only the text before the cursor decides what is being completed.
+    let tokens = SyntaxTree::parse_tokens(
+        Arc::new(SourceText::from(before.to_string())),
+        &mut NullErrorReporter::new(),
+    );
+    let mut tokens = tokens
+        .iter()
+        .rev()
+        .filter(|token| token.kind() != SyntaxKind::EndOfFileToken);
+    let word = tokens.next()?;
+    let is_word = word.kind() == SyntaxKind::IdentifierToken
+        || syntax_facts::keyword_kind(word.text()) == word.kind();
+    if !is_word || word.span().end != position {
+        return None;
+    }
+    // Fields and variants are not completed.
+    if let Some(previous) = tokens.next() {
+        if matches!(
+            previous.kind(),
+            SyntaxKind::DotToken | SyntaxKind::QuestionDotToken
+        ) {
+            return None;
+        }
+    }
+
+    let mut candidates = vec![];
+    for (variable, _) in scope.variables() {
+        candidates.push(Completion {
+            text: variable.name().to_string(),
+            kind: CompletionKind::Variable,
+            detail: variable.ty().to_string(),
+        });
+    }
+    for function in scope.functions() {
+        let parameters = function.parameters().iter();
+        let ty = SilverType::Function(
+            parameters.map(|parameter| parameter.ty().clone()).collect(),
+            Box::new(function.ty().clone()),
+        );
+        candidates.push(Completion {
+            text: function.name().to_string(),
+            kind: CompletionKind::Function,
+            detail: ty.to_string(),
+        });
+    }
+    for ty in scope.types() {
+        let detail = match ty {
+            SilverType::Enum(_) => "enum",
+            _ => "struct",
+        };
+        candidates.push(Completion {
+            text: ty.declared_name().unwrap_or_default().to_string(),
+            kind: CompletionKind::Type,
+            detail: detail.to_string(),
+        });
+    }
+    for (keyword, _) in KEYWORDS {
+        candidates.push(Completion {
+            text: keyword.to_string(),
+            kind: CompletionKind::Keyword,
+            detail: "keyword".to_string(),
+        });
+    }
+    let prefix = syntax_facts::normalize_identifier(word.text());
+    let end = text[position..]
+        .find(|c| !syntax_facts::is_identifier_continue(c))
+        .map_or(text.len(), |length| position + length);
+    Completions::matching(word.span().start..end, &prefix, candidates)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::analysis::{
+        compilation::Compilation,
+        errors::{error_reporter::ErrorReporter, string_error_reporter::StringErrorReporter},
+    };
+
+    use super::*;
+
+    const COMMANDS: &[(&str, &str)] = &[
+        ("#showTree", "Show parse trees"),
+        ("#cls", "Clear the console"),
+        ("#showProgram", "Show bound trees"),
+    ];
+
+    fn scope() -> GlobalScope {
+        let mut scope = GlobalScope::new();
+        let text =
+            "mass = 1 max = 2.5 fn maximum(a: int) => a struct Matrix { m: int } enum Mode { On }";
+        let mut error_reporter = StringErrorReporter::new();
+        let syntax_tree = SyntaxTree::parse_str(text, &mut error_reporter);
+        Compilation::new(&syntax_tree, &mut error_reporter).evaluate(&mut scope);
+        assert!(error_reporter.errors().is_empty());
+        scope
+    }
+
+    fn candidates(completions: &Completions) -> Vec<(&str, CompletionKind, &str)> {
+        completions
+            .candidates()
+            .iter()
+            .map(|c| (c.text(), c.kind(), c.detail()))
+            .collect()
+    }
+
+    #[test]
+    fn completes_names_and_keywords() {
+        let scope = scope();
+        let completions = complete("x = ma + 1", 6, &scope, COMMANDS).unwrap();
+        assert_eq!(4..6, completions.span());
+        assert_eq!(
+            vec![
+                ("mass", CompletionKind::Variable, "i128"),
+                ("match", CompletionKind::Keyword, "keyword"),
+                ("max", CompletionKind::Variable, "f64"),
+                ("maximum", CompletionKind::Function, "fn(i128): i128"),
+            ],
+            candidates(&completions)
+        );
+        assert_eq!("ma", completions.common_prefix());
+
+        let completions = complete("M", 1, &scope, COMMANDS).unwrap();
+        assert_eq!(
+            vec![
+                ("Matrix", CompletionKind::Type, "struct"),
+                ("Mode", CompletionKind::Type, "enum"),
+            ],
+            candidates(&completions)
+        );
+        // A keyword may be the start of a longer name.
+        let completions = complete("f(fa", 4, &scope, COMMANDS).unwrap();
+        assert_eq!(
+            vec![("false", CompletionKind::Keyword, "keyword")],
+            candidates(&completions)
+        );
+        // Only the text before the cursor counts, but the whole word is
+        // replaced.
+        let completions = complete("maxi + 1", 3, &scope, COMMANDS).unwrap();
+        assert_eq!("max", completions.common_prefix());
+        assert_eq!(0..4, completions.span());
+        let completions = complete("maxim + 1", 4, &scope, COMMANDS).unwrap();
+        assert_eq!(0..5, completions.span());
+        assert_eq!(
+            vec![("maximum", CompletionKind::Function, "fn(i128): i128")],
+            candidates(&completions)
+        );
+    }
+
+    #[test]
+    fn completes_commands() {
+        let completions = complete("#sh", 3, &GlobalScope::new(), COMMANDS).unwrap();
+        assert_eq!(0..3, completions.span());
+        assert_eq!(
+            vec![
+                ("#showProgram", CompletionKind::Command, "Show bound trees"),
+                ("#showTree", CompletionKind::Command, "Show parse trees"),
+            ],
+            candidates(&completions)
+        );
+        assert_eq!("#show", completions.common_prefix());
+        assert_eq!(None, complete("#x", 2, &GlobalScope::new(), COMMANDS));
+        assert_eq!(None, complete("1 #sh", 5, &GlobalScope::new(), COMMANDS));
+    }
+
+    #[test]
+    fn completes_nothing_outside_a_word() {
+        let scope = scope();
+        for (text, position) in [
+            ("ma ", 3),
+            (r#""ma"#, 3),
+            ("1ma", 3),
+            ("p.ma", 4),
+            ("zz", 2),
+            ("", 0),
+        ] {
+            assert_eq!(None, complete(text, position, &scope, COMMANDS), "{}", text);
+        }
+    }
+}
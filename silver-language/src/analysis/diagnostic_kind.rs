@@ -4,7 +4,20 @@ use crate::analysis::syntax::syntax_kind::SyntaxKind;
 #[derive(Debug, PartialEq)]
 pub enum DiagnosticKind {
     BadCharacter,
-    BadLiteral(SilverType),
+    InvalidDigit {
+        digit: char,
+        radix: u32,
+    },
+    MissingDigits,
+    TrailingDigitSeparator,
+    InvalidNumberSuffix {
+        suffix: String,
+    },
+    NumberOutOfRange(SilverType),
+    UnterminatedString,
+    InvalidEscapeSequence {
+        character: char,
+    },
     UnexpectedToken {
         expected_kind: SyntaxKind,
         actual_kind: SyntaxKind,
@@ -21,4 +34,94 @@ pub enum DiagnosticKind {
     UndefinedName {
         name: String,
     },
+    UndefinedFunction {
+        name: String,
+    },
+    WrongArgumentCount {
+        name: String,
+        expected: usize,
+        actual: usize,
+    },
+    CannotConvert {
+        from: SilverType,
+        to: SilverType,
+    },
+    CannotConvertImplicitly {
+        from: SilverType,
+        to: SilverType,
+    },
+    CannotInferEmptyArrayType,
+    NotAnArray(SilverType),
+    IndexOutOfBounds {
+        length: usize,
+    },
+    DivisionByZero,
+    UndefinedType {
+        name: String,
+    },
+    NotAStruct(SilverType),
+    UnknownField {
+        ty: SilverType,
+        name: String,
+    },
+    MissingFields {
+        ty: SilverType,
+        names: Vec<String>,
+    },
+    DuplicateField {
+        name: String,
+    },
+    DuplicateVariant {
+        name: String,
+    },
+    NotAnEnum(SilverType),
+    UnknownVariant {
+        ty: SilverType,
+        name: String,
+    },
+    PatternTypeMismatch {
+        expected: SilverType,
+        actual: SilverType,
+    },
+    NonExhaustiveMatch {
+        missing: Vec<String>,
+    },
+    UnreachableArm,
+    PossiblyNull(SilverType),
+    CannotInferParameterType {
+        name: String,
+    },
+    ConflictingTypes {
+        expected: SilverType,
+        actual: SilverType,
+    },
+    DuplicateParameter {
+        name: String,
+    },
+    MissingReturnType {
+        name: String,
+    },
+    CallDepthExceeded {
+        depth: usize,
+    },
+    WrongTypeArgumentCount {
+        name: String,
+        expected: usize,
+        actual: usize,
+    },
+    IrregularRecursiveType {
+        name: String,
+    },
+    CannotInferTypeArgument {
+        name: String,
+        parameter: String,
+    },
+    ConflictingTypeArguments {
+        parameter: String,
+        first: SilverType,
+        second: SilverType,
+    },
+    NotAFunction(SilverType),
+    NotAssignable,
+    EvaluationCancelled,
 }
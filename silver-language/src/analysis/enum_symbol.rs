@@ -0,0 +1,139 @@
+use std::rc::Rc;
+
+use super::silver_type::SilverType;
+
+/// An enum type declared by a program, with its variants in declaration
+/// order. Like a struct, an enum may be generic over type parameters that
+/// its payloads use.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct EnumSymbol {
+    name: String,
+    type_parameters: Vec<String>,
+    type_arguments: Vec<SilverType>,
+    variants: Vec<VariantSymbol>,
+}
+
+impl EnumSymbol {
+    pub(crate) fn new(
+        name: String,
+        type_parameters: Vec<String>,
+        variants: Vec<VariantSymbol>,
+    ) -> Self {
+        Self {
+            name,
+            type_parameters,
+            type_arguments: vec![],
+            variants,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn type_parameters(&self) -> &[String] {
+        &self.type_parameters
+    }
+
+    /// The types given for the type parameters, which are empty for the
+    /// declaration of a generic enum itself.
+    pub fn type_arguments(&self) -> &[SilverType] {
+        &self.type_arguments
+    }
+
+    /// Whether this is a generic enum still waiting for type arguments.
+    pub fn is_generic_definition(&self) -> bool {
+        !self.type_parameters.is_empty() && self.type_arguments.is_empty()
+    }
+
+    /// The variants as declared, where the enum itself is named by a
+    /// recursive reference.
+    pub fn variants(&self) -> &[VariantSymbol] {
+        &self.variants
+    }
+
+    /// The payload types of the variant at `index`, with this enum in place
+    /// of any reference to it.
+    pub fn payload_types(self: &Rc<Self>, index: usize) -> Vec<SilverType> {
+        let ty = |type_arguments: &[SilverType]| {
+            // Inside a generic declaration, the reference is to the enum with
+            // its own type parameters as the type arguments.
+            if self.is_generic_definition() {
+                SilverType::Enum(Rc::new(self.instantiate(type_arguments.to_vec())))
+            } else {
+                SilverType::Enum(self.clone())
+            }
+        };
+        self.variants[index]
+            .payload()
+            .iter()
+            .map(|payload| payload.unfold(&self.name, &ty))
+            .collect()
+    }
+
+    /// The position of the variant called `name`.
+    pub fn variant_index(&self, name: &str) -> Option<usize> {
+        self.variants
+            .iter()
+            .position(|variant| variant.name() == name)
+    }
+
+    /// The enum with `type_arguments` given for its type parameters.
+    pub(crate) fn instantiate(&self, type_arguments: Vec<SilverType>) -> Self {
+        let arguments = self
+            .type_parameters
+            .iter()
+            .cloned()
+            .zip(type_arguments)
+            .collect::<Vec<_>>();
+        let mut symbol = self.substitute(&arguments);
+        symbol.type_arguments = arguments.into_iter().map(|(_, ty)| ty).collect();
+        symbol
+    }
+
+    /// The enum with each type parameter named in `arguments` replaced by
+    /// the type given for it.
+    pub(crate) fn substitute(&self, arguments: &[(String, SilverType)]) -> Self {
+        self.map_types(&|ty| ty.substitute(arguments))
+    }
+
+    /// The enum with `map` applied to its type arguments and payload types.
+    pub(crate) fn map_types(&self, map: &dyn Fn(&SilverType) -> SilverType) -> Self {
+        Self {
+            name: self.name.clone(),
+            type_parameters: self.type_parameters.clone(),
+            type_arguments: self.type_arguments.iter().map(map).collect(),
+            variants: self
+                .variants
+                .iter()
+                .map(|variant| {
+                    VariantSymbol::new(
+                        variant.name().to_string(),
+                        variant.payload().iter().map(map).collect(),
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+/// One alternative of an enum, carrying a payload of zero or more values.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct VariantSymbol {
+    name: String,
+    payload: Vec<SilverType>,
+}
+
+impl VariantSymbol {
+    pub(crate) fn new(name: String, payload: Vec<SilverType>) -> Self {
+        Self { name, payload }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn payload(&self) -> &[SilverType] {
+        &self.payload
+    }
+}
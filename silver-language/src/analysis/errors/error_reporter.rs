@@ -11,15 +11,71 @@ pub trait ErrorReporter {
         let message = format!("There is a bad character in the input: '{}'.", character);
         self.report_error(Diagnostic::new(span, message, DiagnosticKind::BadCharacter));
     }
-    fn report_invalid_number(&mut self, span: TextSpan, literal: &str, ty: SilverType) {
+    fn report_invalid_digit(&mut self, span: TextSpan, digit: char, radix: u32) {
         let message = format!(
-            "The numeric literal '{}' is invalid for the internal integer type '{}'.",
+            "The digit '{}' is not valid in a base-{} literal.",
+            digit, radix
+        );
+        self.report_error(Diagnostic::new(
+            span,
+            message,
+            DiagnosticKind::InvalidDigit { digit, radix },
+        ));
+    }
+    fn report_missing_digits(&mut self, span: TextSpan, literal: &str) {
+        let message = format!("The numeric literal '{}' has no digits.", literal);
+        self.report_error(Diagnostic::new(
+            span,
+            message,
+            DiagnosticKind::MissingDigits,
+        ));
+    }
+    fn report_trailing_digit_separator(&mut self, span: TextSpan, literal: &str) {
+        let message = format!(
+            "The numeric literal '{}' ends its digits with a separator '_'.",
+            literal
+        );
+        self.report_error(Diagnostic::new(
+            span,
+            message,
+            DiagnosticKind::TrailingDigitSeparator,
+        ));
+    }
+    fn report_invalid_number_suffix(&mut self, span: TextSpan, suffix: &str) {
+        let message = format!("'{}' is not a valid numeric literal suffix.", suffix);
+        self.report_error(Diagnostic::new(
+            span,
+            message,
+            DiagnosticKind::InvalidNumberSuffix {
+                suffix: suffix.to_string(),
+            },
+        ));
+    }
+    fn report_number_out_of_range(&mut self, span: TextSpan, literal: &str, ty: SilverType) {
+        let message = format!(
+            "The numeric literal '{}' is out of range for type '{}'.",
             literal, ty
         );
         self.report_error(Diagnostic::new(
             span,
             message,
-            DiagnosticKind::BadLiteral(ty),
+            DiagnosticKind::NumberOutOfRange(ty),
+        ));
+    }
+    fn report_unterminated_string(&mut self, span: TextSpan) {
+        let message = "The string literal is missing its closing quote.".to_string();
+        self.report_error(Diagnostic::new(
+            span,
+            message,
+            DiagnosticKind::UnterminatedString,
+        ));
+    }
+    fn report_invalid_escape_sequence(&mut self, span: TextSpan, character: char) {
+        let message = format!("'\\{}' is not a valid escape sequence.", character);
+        self.report_error(Diagnostic::new(
+            span,
+            message,
+            DiagnosticKind::InvalidEscapeSequence { character },
         ));
     }
     fn report_unexpected_token(
@@ -94,6 +150,392 @@ pub trait ErrorReporter {
             },
         ));
     }
+    fn report_undefined_function(&mut self, span: TextSpan, name: &str) {
+        let message = format!("The function '{}' does not exist.", name);
+        self.report_error(Diagnostic::new(
+            span,
+            message,
+            DiagnosticKind::UndefinedFunction {
+                name: name.to_string(),
+            },
+        ));
+    }
+    fn report_wrong_argument_count(
+        &mut self,
+        span: TextSpan,
+        name: &str,
+        expected: usize,
+        actual: usize,
+    ) {
+        let message = format!(
+            "The function '{}' takes {} argument(s) but was given {}.",
+            name, expected, actual
+        );
+        self.report_error(Diagnostic::new(
+            span,
+            message,
+            DiagnosticKind::WrongArgumentCount {
+                name: name.to_string(),
+                expected,
+                actual,
+            },
+        ));
+    }
+    fn report_cannot_convert(&mut self, span: TextSpan, from: SilverType, to: SilverType) {
+        let message = format!("Cannot convert type '{}' to '{}'.", from, to);
+        self.report_error(Diagnostic::new(
+            span,
+            message,
+            DiagnosticKind::CannotConvert { from, to },
+        ));
+    }
+    fn report_cannot_convert_implicitly(
+        &mut self,
+        span: TextSpan,
+        from: SilverType,
+        to: SilverType,
+    ) {
+        let message = format!(
+            "Cannot convert type '{}' to '{}' implicitly; an explicit conversion exists, as in '{}(...)'.",
+            from,
+            to,
+            to
+        );
+        self.report_error(Diagnostic::new(
+            span,
+            message,
+            DiagnosticKind::CannotConvertImplicitly { from, to },
+        ));
+    }
+    fn report_cannot_infer_empty_array_type(&mut self, span: TextSpan) {
+        let message = "Cannot infer the element type of an empty array.".to_string();
+        self.report_error(Diagnostic::new(
+            span,
+            message,
+            DiagnosticKind::CannotInferEmptyArrayType,
+        ));
+    }
+    fn report_not_an_array(&mut self, span: TextSpan, ty: SilverType) {
+        let message = format!("Expected an array, but found a value of type '{}'.", ty);
+        self.report_error(Diagnostic::new(
+            span,
+            message,
+            DiagnosticKind::NotAnArray(ty),
+        ));
+    }
+    fn report_index_out_of_bounds(&mut self, span: TextSpan, index: &str, length: usize) {
+        let message = format!(
+            "The index {} is out of bounds for an array of length {}.",
+            index, length
+        );
+        self.report_error(Diagnostic::new(
+            span,
+            message,
+            DiagnosticKind::IndexOutOfBounds { length },
+        ));
+    }
+    fn report_division_by_zero(&mut self, span: TextSpan) {
+        self.report_error(Diagnostic::new(
+            span,
+            "Division by zero.".to_string(),
+            DiagnosticKind::DivisionByZero,
+        ));
+    }
+    fn report_undefined_type(&mut self, span: TextSpan, name: &str) {
+        let message = format!("The type '{}' has not been declared.", name);
+        self.report_error(Diagnostic::new(
+            span,
+            message,
+            DiagnosticKind::UndefinedType {
+                name: name.to_string(),
+            },
+        ));
+    }
+    fn report_not_a_struct(&mut self, span: TextSpan, ty: SilverType) {
+        let message = format!("The type '{}' is not a struct.", ty);
+        self.report_error(Diagnostic::new(
+            span,
+            message,
+            DiagnosticKind::NotAStruct(ty),
+        ));
+    }
+    fn report_unknown_field(&mut self, span: TextSpan, ty: SilverType, name: &str) {
+        let message = format!("The type '{}' has no field '{}'.", ty, name);
+        self.report_error(Diagnostic::new(
+            span,
+            message,
+            DiagnosticKind::UnknownField {
+                ty,
+                name: name.to_string(),
+            },
+        ));
+    }
+    fn report_missing_fields(&mut self, span: TextSpan, ty: SilverType, names: Vec<String>) {
+        let message = format!(
+            "The construction of '{}' is missing the field(s) {}.",
+            ty,
+            names
+                .iter()
+                .map(|name| format!("'{}'", name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        self.report_error(Diagnostic::new(
+            span,
+            message,
+            DiagnosticKind::MissingFields { ty, names },
+        ));
+    }
+    fn report_duplicate_field(&mut self, span: TextSpan, name: &str) {
+        let message = format!("The field '{}' appears more than once.", name);
+        self.report_error(Diagnostic::new(
+            span,
+            message,
+            DiagnosticKind::DuplicateField {
+                name: name.to_string(),
+            },
+        ));
+    }
+    fn report_duplicate_variant(&mut self, span: TextSpan, name: &str) {
+        let message = format!("The variant '{}' appears more than once.", name);
+        self.report_error(Diagnostic::new(
+            span,
+            message,
+            DiagnosticKind::DuplicateVariant {
+                name: name.to_string(),
+            },
+        ));
+    }
+    fn report_not_an_enum(&mut self, span: TextSpan, ty: SilverType) {
+        let message = format!("The type '{}' is not an enum.", ty);
+        self.report_error(Diagnostic::new(
+            span,
+            message,
+            DiagnosticKind::NotAnEnum(ty),
+        ));
+    }
+    fn report_unknown_variant(&mut self, span: TextSpan, ty: SilverType, name: &str) {
+        let message = format!("The type '{}' has no variant '{}'.", ty, name);
+        self.report_error(Diagnostic::new(
+            span,
+            message,
+            DiagnosticKind::UnknownVariant {
+                ty,
+                name: name.to_string(),
+            },
+        ));
+    }
+    fn report_pattern_type_mismatch(
+        &mut self,
+        span: TextSpan,
+        expected: SilverType,
+        actual: SilverType,
+    ) {
+        let message = format!(
+            "A pattern of type '{}' cannot match a value of type '{}'.",
+            actual, expected
+        );
+        self.report_error(Diagnostic::new(
+            span,
+            message,
+            DiagnosticKind::PatternTypeMismatch { expected, actual },
+        ));
+    }
+    fn report_non_exhaustive_match(&mut self, span: TextSpan, missing: Vec<String>) {
+        let message = format!(
+            "The match does not cover every value; it is missing {}.",
+            missing
+                .iter()
+                .map(|pattern| format!("'{}'", pattern))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        self.report_error(Diagnostic::new(
+            span,
+            message,
+            DiagnosticKind::NonExhaustiveMatch { missing },
+        ));
+    }
+    fn report_unreachable_arm(&mut self, span: TextSpan) {
+        let message =
+            "The arm is unreachable, because earlier arms match everything it does.".to_string();
+        self.report_error(Diagnostic::new(
+            span,
+            message,
+            DiagnosticKind::UnreachableArm,
+        ));
+    }
+    fn report_possibly_null(&mut self, span: TextSpan, ty: SilverType) {
+        let message = format!(
+            "The value of type '{}' may be null here; check it against null or use '??'.",
+            ty
+        );
+        self.report_error(Diagnostic::new(
+            span,
+            message,
+            DiagnosticKind::PossiblyNull(ty),
+        ));
+    }
+    fn report_cannot_infer_parameter_type(&mut self, span: TextSpan, name: &str) {
+        let message = format!(
+            "The type of the parameter '{}' cannot be inferred from its uses; annotate it.",
+            name
+        );
+        self.report_error(Diagnostic::new(
+            span,
+            message,
+            DiagnosticKind::CannotInferParameterType {
+                name: name.to_string(),
+            },
+        ));
+    }
+    fn report_conflicting_types(
+        &mut self,
+        span: TextSpan,
+        expected: SilverType,
+        actual: SilverType,
+    ) {
+        let message = format!(
+            "This is used as '{}' here, but its type is inferred as '{}' from other uses.",
+            expected, actual
+        );
+        self.report_error(Diagnostic::new(
+            span,
+            message,
+            DiagnosticKind::ConflictingTypes { expected, actual },
+        ));
+    }
+    fn report_duplicate_parameter(&mut self, span: TextSpan, name: &str) {
+        let message = format!("The parameter '{}' is already declared.", name);
+        self.report_error(Diagnostic::new(
+            span,
+            message,
+            DiagnosticKind::DuplicateParameter {
+                name: name.to_string(),
+            },
+        ));
+    }
+    fn report_missing_return_type(&mut self, span: TextSpan, name: &str) {
+        let message = format!(
+            "The function '{}' needs a declared return type to call itself.",
+            name
+        );
+        self.report_error(Diagnostic::new(
+            span,
+            message,
+            DiagnosticKind::MissingReturnType {
+                name: name.to_string(),
+            },
+        ));
+    }
+    fn report_call_depth_exceeded(&mut self, span: TextSpan, depth: usize) {
+        let message = format!("The call nests more than {} calls deep.", depth);
+        self.report_error(Diagnostic::new(
+            span,
+            message,
+            DiagnosticKind::CallDepthExceeded { depth },
+        ));
+    }
+    fn report_evaluation_cancelled(&mut self, span: TextSpan) {
+        self.report_error(Diagnostic::new(
+            span,
+            "The evaluation was cancelled.".to_string(),
+            DiagnosticKind::EvaluationCancelled,
+        ));
+    }
+    fn report_wrong_type_argument_count(
+        &mut self,
+        span: TextSpan,
+        name: &str,
+        expected: usize,
+        actual: usize,
+    ) {
+        let message = format!(
+            "'{}' takes {} type argument(s) but was given {}.",
+            name, expected, actual
+        );
+        self.report_error(Diagnostic::new(
+            span,
+            message,
+            DiagnosticKind::WrongTypeArgumentCount {
+                name: name.to_string(),
+                expected,
+                actual,
+            },
+        ));
+    }
+    fn report_irregular_recursive_type(
+        &mut self,
+        span: TextSpan,
+        name: &str,
+        parameters: &[String],
+    ) {
+        let message = format!(
+            "'{}' can only refer to itself with its own type parameters, as '{}<{}>'.",
+            name,
+            name,
+            parameters.join(", ")
+        );
+        self.report_error(Diagnostic::new(
+            span,
+            message,
+            DiagnosticKind::IrregularRecursiveType {
+                name: name.to_string(),
+            },
+        ));
+    }
+    fn report_cannot_infer_type_argument(&mut self, span: TextSpan, name: &str, parameter: &str) {
+        let message = format!(
+            "The type argument '{}' of '{}' cannot be inferred here; give it, as in '{}<...>'.",
+            parameter, name, name
+        );
+        self.report_error(Diagnostic::new(
+            span,
+            message,
+            DiagnosticKind::CannotInferTypeArgument {
+                name: name.to_string(),
+                parameter: parameter.to_string(),
+            },
+        ));
+    }
+    fn report_conflicting_type_arguments(
+        &mut self,
+        span: TextSpan,
+        parameter: &str,
+        first: SilverType,
+        second: SilverType,
+    ) {
+        let message = format!(
+            "The type argument '{}' is inferred as both '{}' and '{}'.",
+            parameter, first, second
+        );
+        self.report_error(Diagnostic::new(
+            span,
+            message,
+            DiagnosticKind::ConflictingTypeArguments {
+                parameter: parameter.to_string(),
+                first,
+                second,
+            },
+        ));
+    }
+    fn report_not_a_function(&mut self, span: TextSpan, ty: SilverType) {
+        let message = format!("A value of type '{}' cannot be called.", ty);
+        self.report_error(Diagnostic::new(
+            span,
+            message,
+            DiagnosticKind::NotAFunction(ty),
+        ));
+    }
+    fn report_not_assignable(&mut self, span: TextSpan) {
+        let message =
+            "Only a variable, an array element or a struct field can be assigned to.".to_string();
+        self.report_error(Diagnostic::new(
+            span,
+            message,
+            DiagnosticKind::NotAssignable,
+        ));
+    }
     fn had_error(&self) -> bool;
     fn errors(&self) -> &[Diagnostic];
     fn clear(&mut self);
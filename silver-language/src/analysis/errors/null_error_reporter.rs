@@ -1,5 +1,6 @@
 use super::error_reporter::ErrorReporter;
 
+#[derive(Default)]
 pub struct NullErrorReporter {
     had_error: bool,
 }
@@ -10,12 +11,6 @@ impl NullErrorReporter {
     }
 }
 
-impl Default for NullErrorReporter {
-    fn default() -> Self {
-        Self { had_error: false }
-    }
-}
-
 impl ErrorReporter for NullErrorReporter {
     fn report_error(&mut self, _error: crate::analysis::diagnostic::Diagnostic) {
         self.had_error = true;
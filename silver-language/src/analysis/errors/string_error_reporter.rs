@@ -1,6 +1,7 @@
 use super::error_reporter::ErrorReporter;
 use crate::analysis::diagnostic::Diagnostic;
 
+#[derive(Default)]
 pub struct StringErrorReporter {
     errors: Vec<Diagnostic>,
 }
@@ -11,12 +12,6 @@ impl StringErrorReporter {
     }
 }
 
-impl Default for StringErrorReporter {
-    fn default() -> Self {
-        Self { errors: vec![] }
-    }
-}
-
 impl ErrorReporter for StringErrorReporter {
     fn report_error(&mut self, error: Diagnostic) {
         self.errors.push(error);
@@ -1,32 +1,118 @@
-use std::collections::HashMap;
+use std::{cell::RefCell, cmp::Ordering, collections::HashMap, convert::TryFrom, rc::Rc};
 
 use super::{
     binding::{
         bound_binary_operator::BoundBinaryOperator,
-        bound_binary_operator_kind::BoundBinaryOperatorKind, bound_expression::BoundExpression,
+        bound_binary_operator_kind::BoundBinaryOperatorKind,
+        bound_expression::{BoundExpression, BoundMatchArm},
+        bound_pattern::BoundPattern,
         bound_unary_operator::BoundUnaryOperator,
         bound_unary_operator_kind::BoundUnaryOperatorKind,
     },
-    silver_value::SilverValue,
+    cancellation_token::CancellationToken,
+    enum_symbol::EnumSymbol,
+    errors::error_reporter::ErrorReporter,
+    function_symbol::FunctionSymbol,
+    silver_integer::{self, SilverInteger},
+    silver_type::SilverType,
+    silver_value::{Closure, SilverValue},
+    text::text_span::TextSpan,
+    type_symbol::TypeSymbol,
     variable_symbol::VariableSymbol,
 };
 
-pub struct Evaluator<'variables> {
-    variables: &'variables mut HashMap<VariableSymbol, SilverValue>,
+/// How deeply calls may nest before evaluation gives up, unless the
+/// compilation sets another limit. This is well within the native stack of
+/// any thread, as the evaluation recurses on it.
+pub const DEFAULT_MAX_CALL_DEPTH: usize = 200;
+
+/// Variables with their values. Each value sits in its own cell, which the
+/// closures that capture the variable share.
+pub(crate) type Variables = HashMap<VariableSymbol, Rc<RefCell<SilverValue>>>;
+
+pub struct Evaluator<'scope, 'reporter> {
+    variables: &'scope mut Variables,
+    functions: &'scope HashMap<FunctionSymbol, Rc<BoundExpression>>,
+    /// The variables bound by the patterns of the match arms being
+    /// evaluated, innermost last. Inside a call, the first holds the
+    /// parameters and variables of the function, and the variables a
+    /// closure captured.
+    locals: Vec<Variables>,
+    /// The number of calls being evaluated.
+    depth: usize,
+    /// The number of calls that may be evaluated at once, which keeps the
+    /// native stack from overflowing.
+    max_call_depth: usize,
+    /// How many variables `locals` holds, together with the frames of the
+    /// calls being evaluated, which `locals` does not hold while inside a
+    /// call.
+    local_count: usize,
+    /// The number of expressions evaluated so far.
+    evaluated_nodes: u64,
+    /// The most variables held at once so far, globals and locals together.
+    peak_variables: usize,
+    /// The outermost call being evaluated. Errors inside it are reported
+    /// there, as the function may come from an earlier submission, whose
+    /// text the reporter does not have.
+    call_span: TextSpan,
+    /// Checked at every call, the only place where evaluation can go on for
+    /// long, as the language has no loops and only recursion repeats.
+    cancellation_token: &'reporter CancellationToken,
+    error_reporter: &'reporter mut dyn ErrorReporter,
 }
 
-impl<'variables> Evaluator<'variables> {
-    pub(crate) fn new(variables: &'variables mut HashMap<VariableSymbol, SilverValue>) -> Self {
-        Self { variables }
+impl<'scope, 'reporter> Evaluator<'scope, 'reporter> {
+    pub(crate) fn new(
+        variables: &'scope mut Variables,
+        functions: &'scope HashMap<FunctionSymbol, Rc<BoundExpression>>,
+        max_call_depth: usize,
+        cancellation_token: &'reporter CancellationToken,
+        error_reporter: &'reporter mut dyn ErrorReporter,
+    ) -> Self {
+        Self {
+            variables,
+            functions,
+            locals: vec![],
+            depth: 0,
+            max_call_depth,
+            local_count: 0,
+            evaluated_nodes: 0,
+            peak_variables: 0,
+            call_span: 0..0,
+            cancellation_token,
+            error_reporter,
+        }
+    }
+
+    /// Evaluate each expression in turn, returning the value of the last.
+    /// Returns `None` after reporting a runtime error.
+    pub(crate) fn evaluate(&mut self, expressions: &[BoundExpression]) -> Option<SilverValue> {
+        self.count_variables();
+        let mut value = None;
+        for expression in expressions {
+            value = Some(self.evaluate_expression(expression)?);
+        }
+        value
+    }
+
+    pub(crate) fn evaluated_nodes(&self) -> u64 {
+        self.evaluated_nodes
+    }
+
+    pub(crate) fn peak_variables(&self) -> usize {
+        self.peak_variables
     }
 
-    pub(crate) fn evaluate(&mut self, bound_tree: &BoundExpression) -> SilverValue {
-        self.evaluate_expression(bound_tree)
+    /// Take the current number of variables into account for the peak.
+    fn count_variables(&mut self) {
+        let count = self.variables.len() + self.local_count;
+        self.peak_variables = self.peak_variables.max(count);
     }
 
-    fn evaluate_expression<'a>(&'a mut self, root: &'a BoundExpression) -> SilverValue {
+    fn evaluate_expression(&mut self, root: &BoundExpression) -> Option<SilverValue> {
+        self.evaluated_nodes += 1;
         match root {
-            BoundExpression::Literal { value } => value.clone().unwrap(),
+            BoundExpression::Literal { value } => Some(value.clone().unwrap()),
             BoundExpression::Unary { operator, operand } => {
                 self.evaluate_unary_expression(operator, operand)
             }
@@ -34,51 +120,517 @@ impl<'variables> Evaluator<'variables> {
                 left,
                 operator,
                 right,
-            } => self.evaluate_binary_expression(left, operator, right),
-            BoundExpression::Variable { variable } => self.evaluate_variable_expression(variable),
+                operator_span,
+            } => self.evaluate_binary_expression(left, operator, right, operator_span),
+            BoundExpression::Variable { variable } => {
+                Some(self.evaluate_variable_expression(variable))
+            }
             BoundExpression::Assignment {
                 variable,
                 expression,
             } => self.evaluate_assignment_expression(variable, expression),
+            BoundExpression::Conversion { ty, expression } => {
+                self.evaluate_conversion_expression(ty, expression)
+            }
+            BoundExpression::Array {
+                element_type,
+                elements,
+            } => self.evaluate_array_expression(element_type, elements),
+            BoundExpression::Index {
+                array,
+                index,
+                index_span,
+            } => self.evaluate_index_expression(array, index, index_span),
+            BoundExpression::ElementAssignment {
+                array,
+                index,
+                index_span,
+                expression,
+            } => self.evaluate_element_assignment_expression(array, index, index_span, expression),
+            BoundExpression::ArrayLength { array } => self.evaluate_array_length_expression(array),
+            BoundExpression::StructConstruction { ty, fields } => {
+                self.evaluate_struct_construction_expression(ty, fields)
+            }
+            BoundExpression::FieldAccess {
+                expression, index, ..
+            } => self.evaluate_field_access_expression(expression, *index),
+            BoundExpression::ConditionalFieldAccess {
+                expression, index, ..
+            } => self.evaluate_conditional_field_access_expression(expression, *index),
+            BoundExpression::FieldAssignment {
+                target,
+                index,
+                expression,
+                ..
+            } => self.evaluate_field_assignment_expression(target, *index, expression),
+            BoundExpression::Coalesce { left, right } => {
+                self.evaluate_coalesce_expression(left, right)
+            }
+            BoundExpression::VariantConstruction {
+                ty,
+                variant,
+                payload,
+            } => self.evaluate_variant_construction_expression(ty, *variant, payload),
+            BoundExpression::Match {
+                expression, arms, ..
+            } => self.evaluate_match_expression(expression, arms),
+            BoundExpression::Call {
+                function,
+                arguments,
+                span,
+                ..
+            } => self.evaluate_call_expression(function, arguments, span),
+            BoundExpression::Invocation {
+                expression,
+                arguments,
+                span,
+                ..
+            } => self.evaluate_invocation_expression(expression, arguments, span),
+            BoundExpression::Lambda {
+                parameters,
+                captures,
+                body,
+            } => Some(self.evaluate_lambda_expression(root.ty(), parameters, captures, body)),
+            BoundExpression::Function { function } => {
+                Some(SilverValue::Function(Rc::new(Closure {
+                    ty: root.ty(),
+                    parameters: function.parameters().to_vec(),
+                    body: Rc::clone(&self.functions[function]),
+                    captures: vec![],
+                })))
+            }
         }
     }
 
+    fn evaluate_conversion_expression(
+        &mut self,
+        ty: &SilverType,
+        expression: &BoundExpression,
+    ) -> Option<SilverValue> {
+        let value = self.evaluate_expression(expression)?;
+        if *ty == SilverType::String {
+            return Some(SilverValue::String(value.to_string().into()));
+        }
+        // A nullable type holds null as it is, and other values as its inner
+        // type.
+        let ty = ty.non_null();
+        let value = match value {
+            SilverValue::Null => SilverValue::Null,
+            SilverValue::Float(f) if ty.is_integer() => SilverValue::integer_saturating(f, ty),
+            SilverValue::Integer(i) if *ty == SilverType::Float => {
+                SilverValue::Float(silver_integer::to_f64(&i))
+            }
+            value if *ty == SilverType::Float => {
+                SilverValue::Float(value.as_integer().unwrap() as f64)
+            }
+            value if ty.is_integer() => {
+                SilverValue::integer_wrapping(value.as_integer_wrapping().unwrap(), ty)
+            }
+            value => value,
+        };
+        Some(value)
+    }
+
     fn evaluate_variable_expression(&self, variable: &VariableSymbol) -> SilverValue {
-        self.variables[variable].clone()
+        self.cell(variable).borrow().clone()
+    }
+
+    fn cell(&self, variable: &VariableSymbol) -> &Rc<RefCell<SilverValue>> {
+        self.locals
+            .iter()
+            .rev()
+            .find_map(|locals| locals.get(variable))
+            .unwrap_or_else(|| &self.variables[variable])
     }
 
     fn evaluate_assignment_expression(
         &mut self,
         variable: &VariableSymbol,
         expression: &BoundExpression,
-    ) -> SilverValue {
-        let value = self.evaluate_expression(expression);
-        self.variables.insert(variable.clone(), value.clone());
+    ) -> Option<SilverValue> {
+        let value = self.evaluate_expression(expression)?;
+        // Pattern variables stay local to their arm, even when reassigned.
+        let (variables, is_local) = match self
+            .locals
+            .iter_mut()
+            .rev()
+            .find(|locals| locals.keys().any(|v| v.name() == variable.name()))
+        {
+            Some(locals) => (locals, true),
+            None if self.depth > 0 => (&mut self.locals[0], true),
+            None => (&mut *self.variables, false),
+        };
+        let count = variables.len();
+        match variables.get(variable) {
+            // Closures that captured the variable see the new value.
+            Some(cell) => *cell.borrow_mut() = value.clone(),
+            // A reassignment with a new type replaces the old declaration,
+            // leaving closures with the old one.
+            None => {
+                variables.retain(|v, _| v.name() != variable.name());
+                variables.insert(variable.clone(), Rc::new(RefCell::new(value.clone())));
+            }
+        }
+        let added = variables.len() - count;
+        if is_local {
+            self.local_count += added;
+        }
+        self.count_variables();
+        Some(value)
+    }
+
+    fn evaluate_call_expression(
+        &mut self,
+        function: &FunctionSymbol,
+        arguments: &[BoundExpression],
+        span: &TextSpan,
+    ) -> Option<SilverValue> {
+        let arguments = arguments
+            .iter()
+            .map(|argument| self.evaluate_expression(argument))
+            .collect::<Option<Vec<_>>>()?;
+        let body = Rc::clone(&self.functions[function]);
+        self.call(function.parameters(), arguments, vec![], &body, span)
+    }
+
+    fn evaluate_invocation_expression(
+        &mut self,
+        expression: &BoundExpression,
+        arguments: &[BoundExpression],
+        span: &TextSpan,
+    ) -> Option<SilverValue> {
+        let closure = match self.evaluate_expression(expression)? {
+            SilverValue::Function(closure) => closure,
+            _ => unreachable!("the binder only invokes functions"),
+        };
+        let arguments = arguments
+            .iter()
+            .map(|argument| self.evaluate_expression(argument))
+            .collect::<Option<Vec<_>>>()?;
+        self.call(
+            &closure.parameters,
+            arguments,
+            closure.captures.clone(),
+            &closure.body,
+            span,
+        )
+    }
+
+    /// Evaluate `body` in a frame of its own, holding `captures` and the
+    /// parameters with the values of the arguments.
+    fn call(
+        &mut self,
+        parameters: &[VariableSymbol],
+        arguments: Vec<SilverValue>,
+        captures: Vec<(VariableSymbol, Rc<RefCell<SilverValue>>)>,
+        body: &BoundExpression,
+        span: &TextSpan,
+    ) -> Option<SilverValue> {
+        if self.depth == self.max_call_depth {
+            self.error_reporter
+                .report_call_depth_exceeded(self.call_span.clone(), self.max_call_depth);
+            return None;
+        }
+        if self.depth == 0 {
+            self.call_span = span.clone();
+        }
+        if self.cancellation_token.is_cancelled() {
+            self.error_reporter
+                .report_evaluation_cancelled(self.call_span.clone());
+            return None;
+        }
+        let mut frame: Variables = captures.into_iter().collect();
+        frame.extend(cells(parameters.iter().cloned().zip(arguments)));
+
+        self.local_count += frame.len();
+        self.count_variables();
+        let locals = std::mem::replace(&mut self.locals, vec![frame]);
+        self.depth += 1;
+        let value = self.evaluate_expression(body);
+        self.depth -= 1;
+        let frame = std::mem::replace(&mut self.locals, locals);
+        self.local_count -= frame.iter().map(HashMap::len).sum::<usize>();
         value
     }
 
+    fn evaluate_lambda_expression(
+        &self,
+        ty: SilverType,
+        parameters: &[VariableSymbol],
+        captures: &[VariableSymbol],
+        body: &Rc<BoundExpression>,
+    ) -> SilverValue {
+        let captures = captures
+            .iter()
+            .map(|variable| (variable.clone(), Rc::clone(self.cell(variable))))
+            .collect();
+        SilverValue::Function(Rc::new(Closure {
+            ty,
+            parameters: parameters.to_vec(),
+            body: Rc::clone(body),
+            captures,
+        }))
+    }
+
+    fn evaluate_array_expression(
+        &mut self,
+        element_type: &SilverType,
+        elements: &[BoundExpression],
+    ) -> Option<SilverValue> {
+        let elements = elements
+            .iter()
+            .map(|element| self.evaluate_expression(element))
+            .collect::<Option<Vec<_>>>()?;
+        Some(SilverValue::Array {
+            element_type: element_type.clone(),
+            elements: Rc::new(RefCell::new(elements)),
+        })
+    }
+
+    fn evaluate_index_expression(
+        &mut self,
+        array: &BoundExpression,
+        index: &BoundExpression,
+        index_span: &TextSpan,
+    ) -> Option<SilverValue> {
+        let (elements, index) = self.evaluate_element(array, index, index_span)?;
+        let element = elements.borrow()[index].clone();
+        Some(element)
+    }
+
+    fn evaluate_element_assignment_expression(
+        &mut self,
+        array: &BoundExpression,
+        index: &BoundExpression,
+        index_span: &TextSpan,
+        expression: &BoundExpression,
+    ) -> Option<SilverValue> {
+        let (elements, index) = self.evaluate_element(array, index, index_span)?;
+        let value = self.evaluate_expression(expression)?;
+        elements.borrow_mut()[index] = value.clone();
+        Some(value)
+    }
+
+    /// Evaluate an array and an index into it, reporting an error unless the
+    /// index is in bounds.
+    fn evaluate_element(
+        &mut self,
+        array: &BoundExpression,
+        index: &BoundExpression,
+        index_span: &TextSpan,
+    ) -> Option<(Rc<RefCell<Vec<SilverValue>>>, usize)> {
+        let elements = match self.evaluate_expression(array)? {
+            SilverValue::Array { elements, .. } => elements,
+            _ => unreachable!("the binder only indexes arrays"),
+        };
+        let index = self.evaluate_expression(index)?;
+        let length = elements.borrow().len();
+        match index.as_integer().and_then(|i| usize::try_from(i).ok()) {
+            Some(i) if i < length => Some((elements, i)),
+            _ => {
+                let span = if self.depth > 0 {
+                    self.call_span.clone()
+                } else {
+                    index_span.clone()
+                };
+                self.error_reporter
+                    .report_index_out_of_bounds(span, &index.to_string(), length);
+                None
+            }
+        }
+    }
+
+    fn evaluate_array_length_expression(&mut self, array: &BoundExpression) -> Option<SilverValue> {
+        match self.evaluate_expression(array)? {
+            SilverValue::Array { elements, .. } => Some(SilverValue::Integer(
+                silver_integer::from_i128(elements.borrow().len() as i128),
+            )),
+            _ => unreachable!("the binder only takes the length of arrays"),
+        }
+    }
+
+    fn evaluate_struct_construction_expression(
+        &mut self,
+        ty: &Rc<TypeSymbol>,
+        fields: &[(usize, BoundExpression)],
+    ) -> Option<SilverValue> {
+        let mut values = vec![None; ty.fields().len()];
+        for (index, field) in fields {
+            values[*index] = Some(self.evaluate_expression(field)?);
+        }
+        Some(SilverValue::Struct {
+            ty: ty.clone(),
+            fields: Rc::new(RefCell::new(
+                values.into_iter().map(|value| value.unwrap()).collect(),
+            )),
+        })
+    }
+
+    fn evaluate_field_access_expression(
+        &mut self,
+        expression: &BoundExpression,
+        index: usize,
+    ) -> Option<SilverValue> {
+        let fields = self.evaluate_fields(expression)?;
+        let value = fields.borrow()[index].clone();
+        Some(value)
+    }
+
+    fn evaluate_conditional_field_access_expression(
+        &mut self,
+        expression: &BoundExpression,
+        index: usize,
+    ) -> Option<SilverValue> {
+        match self.evaluate_expression(expression)? {
+            SilverValue::Struct { fields, .. } => Some(fields.borrow()[index].clone()),
+            SilverValue::Null => Some(SilverValue::Null),
+            _ => unreachable!("the binder only accesses fields of structs"),
+        }
+    }
+
+    fn evaluate_coalesce_expression(
+        &mut self,
+        left: &BoundExpression,
+        right: &BoundExpression,
+    ) -> Option<SilverValue> {
+        match self.evaluate_expression(left)? {
+            SilverValue::Null => self.evaluate_expression(right),
+            value => Some(value),
+        }
+    }
+
+    fn evaluate_field_assignment_expression(
+        &mut self,
+        target: &BoundExpression,
+        index: usize,
+        expression: &BoundExpression,
+    ) -> Option<SilverValue> {
+        let fields = self.evaluate_fields(target)?;
+        let value = self.evaluate_expression(expression)?;
+        fields.borrow_mut()[index] = value.clone();
+        Some(value)
+    }
+
+    fn evaluate_variant_construction_expression(
+        &mut self,
+        ty: &Rc<EnumSymbol>,
+        variant: usize,
+        payload: &[BoundExpression],
+    ) -> Option<SilverValue> {
+        let payload = payload
+            .iter()
+            .map(|expression| self.evaluate_expression(expression))
+            .collect::<Option<Vec<_>>>()?;
+        Some(SilverValue::Enum {
+            ty: ty.clone(),
+            variant,
+            payload,
+        })
+    }
+
+    fn evaluate_match_expression(
+        &mut self,
+        expression: &BoundExpression,
+        arms: &[BoundMatchArm],
+    ) -> Option<SilverValue> {
+        let value = self.evaluate_expression(expression)?;
+        for arm in arms {
+            let mut bindings = HashMap::new();
+            if match_pattern(&arm.pattern, &value, &mut bindings) {
+                self.local_count += bindings.len();
+                self.count_variables();
+                self.locals.push(cells(bindings));
+                let result = self.evaluate_expression(&arm.expression);
+                self.local_count -= self.locals.pop().unwrap().len();
+                return result;
+            }
+        }
+        unreachable!("the binder only accepts exhaustive matches")
+    }
+
+    fn evaluate_fields(
+        &mut self,
+        expression: &BoundExpression,
+    ) -> Option<Rc<RefCell<Vec<SilverValue>>>> {
+        match self.evaluate_expression(expression)? {
+            SilverValue::Struct { fields, .. } => Some(fields),
+            _ => unreachable!("the binder only accesses fields of structs"),
+        }
+    }
+
     fn evaluate_binary_expression(
         &mut self,
         left: &BoundExpression,
         operator: &BoundBinaryOperator,
         right: &BoundExpression,
-    ) -> SilverValue {
-        let left = self.evaluate_expression(left);
-        let right = self.evaluate_expression(right);
+        operator_span: &TextSpan,
+    ) -> Option<SilverValue> {
+        let left = self.evaluate_expression(left)?;
+        // `&&` and `||` skip the right operand once the left decides the
+        // result, so the right may rely on what the left has checked.
+        match (operator.kind(), left.as_boolean()) {
+            (BoundBinaryOperatorKind::LogicalAnd, Some(false))
+            | (BoundBinaryOperatorKind::LogicalOr, Some(true)) => return Some(left),
+            _ => {}
+        }
+        let right = self.evaluate_expression(right)?;
+        if matches!(
+            operator.kind(),
+            BoundBinaryOperatorKind::Division | BoundBinaryOperatorKind::Remainder
+        ) && right.ty().is_integer()
+            && right.as_integer() == Some(0)
+        {
+            let span = if self.depth > 0 {
+                self.call_span.clone()
+            } else {
+                operator_span.clone()
+            };
+            self.error_reporter.report_division_by_zero(span);
+            return None;
+        }
 
-        match operator.kind() {
-            BoundBinaryOperatorKind::Addition => {
-                SilverValue::Integer(left.as_integer().unwrap() + right.as_integer().unwrap())
-            }
-            BoundBinaryOperatorKind::Subtraction => {
-                SilverValue::Integer(left.as_integer().unwrap() - right.as_integer().unwrap())
-            }
-            BoundBinaryOperatorKind::Multiplication => {
-                SilverValue::Integer(left.as_integer().unwrap() * right.as_integer().unwrap())
-            }
-            BoundBinaryOperatorKind::Division => {
-                SilverValue::Integer(left.as_integer().unwrap() / right.as_integer().unwrap())
-            }
+        let value = match operator.kind() {
+            BoundBinaryOperatorKind::Addition => arithmetic(
+                left,
+                right,
+                i128::wrapping_add,
+                silver_integer::add,
+                |l, r| l + r,
+            ),
+            BoundBinaryOperatorKind::Subtraction => arithmetic(
+                left,
+                right,
+                i128::wrapping_sub,
+                silver_integer::sub,
+                |l, r| l - r,
+            ),
+            BoundBinaryOperatorKind::Multiplication => arithmetic(
+                left,
+                right,
+                i128::wrapping_mul,
+                silver_integer::mul,
+                |l, r| l * r,
+            ),
+            BoundBinaryOperatorKind::Division => arithmetic(
+                left,
+                right,
+                i128::wrapping_div,
+                silver_integer::div,
+                |l, r| l / r,
+            ),
+            BoundBinaryOperatorKind::Remainder => arithmetic(
+                left,
+                right,
+                i128::wrapping_rem,
+                silver_integer::rem,
+                |l, r| l % r,
+            ),
+            BoundBinaryOperatorKind::Concatenation => match (left, right) {
+                (SilverValue::String(l), SilverValue::String(r)) => {
+                    SilverValue::String(format!("{}{}", l, r).into())
+                }
+                _ => unreachable!("the binder only concatenates strings"),
+            },
             BoundBinaryOperatorKind::LogicalAnd => {
                 SilverValue::Boolean(left.as_boolean().unwrap() && right.as_boolean().unwrap())
             }
@@ -87,24 +639,111 @@ impl<'variables> Evaluator<'variables> {
             }
             BoundBinaryOperatorKind::Equality => SilverValue::Boolean(left == right),
             BoundBinaryOperatorKind::Inequality => SilverValue::Boolean(left != right),
-        }
+            BoundBinaryOperatorKind::Less => comparison(left, right, Ordering::is_lt),
+            BoundBinaryOperatorKind::LessOrEquals => comparison(left, right, Ordering::is_le),
+            BoundBinaryOperatorKind::Greater => comparison(left, right, Ordering::is_gt),
+            BoundBinaryOperatorKind::GreaterOrEquals => comparison(left, right, Ordering::is_ge),
+        };
+        Some(value)
     }
 
     fn evaluate_unary_expression(
         &mut self,
         operator: &BoundUnaryOperator,
         operand: &BoundExpression,
-    ) -> SilverValue {
-        let operand = self.evaluate_expression(operand);
-        match operator.kind() {
+    ) -> Option<SilverValue> {
+        let operand = self.evaluate_expression(operand)?;
+        let value = match operator.kind() {
             BoundUnaryOperatorKind::Identity => operand,
-            BoundUnaryOperatorKind::Negation => {
-                SilverValue::Integer(-operand.as_integer().unwrap())
-            }
+            BoundUnaryOperatorKind::Negation => match operand {
+                SilverValue::Float(f) => SilverValue::Float(-f),
+                SilverValue::Integer(i) => SilverValue::Integer(silver_integer::neg(&i)),
+                operand => SilverValue::integer_wrapping(
+                    operand.as_integer().unwrap().wrapping_neg(),
+                    &operand.ty(),
+                ),
+            },
             BoundUnaryOperatorKind::LogicalNegation => {
                 SilverValue::Boolean(!operand.as_boolean().unwrap())
             }
+        };
+        Some(value)
+    }
+}
+
+/// Apply an arithmetic operator to two numbers of the same type. Sized
+/// integers are computed at 128 bits and wrap to the width of their type.
+fn arithmetic(
+    left: SilverValue,
+    right: SilverValue,
+    sized: fn(i128, i128) -> i128,
+    integer: fn(&SilverInteger, &SilverInteger) -> SilverInteger,
+    float: fn(f64, f64) -> f64,
+) -> SilverValue {
+    match (left, right) {
+        (SilverValue::Integer(l), SilverValue::Integer(r)) => SilverValue::Integer(integer(&l, &r)),
+        (SilverValue::Float(l), SilverValue::Float(r)) => SilverValue::Float(float(l, r)),
+        (left, right) => SilverValue::integer_wrapping(
+            sized(left.as_integer().unwrap(), right.as_integer().unwrap()),
+            &left.ty(),
+        ),
+    }
+}
+
+/// Apply an ordering operator to two numbers of the same type.
+fn comparison(left: SilverValue, right: SilverValue, test: fn(Ordering) -> bool) -> SilverValue {
+    let ordering = match (&left, &right) {
+        (SilverValue::Integer(l), SilverValue::Integer(r)) => l.partial_cmp(r),
+        (SilverValue::Float(l), SilverValue::Float(r)) => l.partial_cmp(r),
+        _ => left
+            .as_integer()
+            .unwrap()
+            .partial_cmp(&right.as_integer().unwrap()),
+    };
+    // Only NaN is unordered, and it compares false with everything.
+    SilverValue::Boolean(ordering.map(test).unwrap_or(false))
+}
+
+/// Variables holding `values`, each in a new cell.
+fn cells(values: impl IntoIterator<Item = (VariableSymbol, SilverValue)>) -> Variables {
+    values
+        .into_iter()
+        .map(|(variable, value)| (variable, Rc::new(RefCell::new(value))))
+        .collect()
+}
+
+/// Whether `value` matches `pattern`, collecting the values of the pattern's
+/// variables into `bindings`.
+fn match_pattern(
+    pattern: &BoundPattern,
+    value: &SilverValue,
+    bindings: &mut HashMap<VariableSymbol, SilverValue>,
+) -> bool {
+    match (pattern, value) {
+        (BoundPattern::Literal(literal), value) => literal == value,
+        (BoundPattern::Binding(None), _) => true,
+        (BoundPattern::Binding(Some(variable)), value) => {
+            bindings.insert(variable.clone(), value.clone());
+            true
+        }
+        (
+            BoundPattern::Variant {
+                variant, payload, ..
+            },
+            SilverValue::Enum {
+                variant: value_variant,
+                payload: values,
+                ..
+            },
+        ) => {
+            variant == value_variant
+                && payload
+                    .iter()
+                    .zip(values)
+                    .all(|(pattern, value)| match_pattern(pattern, value, bindings))
         }
+        // A variant pattern also sees null when it matches a nullable enum.
+        (BoundPattern::Variant { .. }, _) => false,
     }
 }
 
@@ -112,7 +751,11 @@ impl<'variables> Evaluator<'variables> {
 mod tests {
     use crate::analysis::{
         compilation::Compilation,
+        diagnostic_kind::DiagnosticKind,
+        enum_symbol::VariantSymbol,
         errors::{error_reporter::ErrorReporter, string_error_reporter::StringErrorReporter},
+        global_scope::GlobalScope,
+        syntax::syntax_kind::SyntaxKind,
         syntax::syntax_tree::SyntaxTree,
     };
 
@@ -122,23 +765,28 @@ mod tests {
         let mut error_reporter = StringErrorReporter::new();
         let syntax_tree = SyntaxTree::parse_str(text, &mut error_reporter);
         let mut compilation = Compilation::new(&syntax_tree, &mut error_reporter);
-        let mut variables = HashMap::<VariableSymbol, SilverValue>::new();
-        let result = compilation.evaluate(&mut variables);
-        assert_eq!(value, &result.unwrap());
-        assert!(!error_reporter.had_error());
+        let mut scope = GlobalScope::new();
+        let result = compilation.evaluate(&mut scope);
+        let errors: Vec<_> = error_reporter
+            .errors()
+            .iter()
+            .map(|e| e.message())
+            .collect();
+        assert!(!error_reporter.had_error(), "{}: {:?}", text, errors);
+        assert_eq!(value, &result.unwrap(), "{}", text);
     }
 
     #[test]
     fn evaluates_correctly() {
         for (text, value) in [
-            ("1", SilverValue::Integer(1)),
-            ("+1", SilverValue::Integer(1)),
-            ("-1", SilverValue::Integer(-1)),
-            ("1 + 2", SilverValue::Integer(3)),
-            ("1 - 2", SilverValue::Integer(-1)),
-            ("1 * 2", SilverValue::Integer(2)),
-            ("1 / 2", SilverValue::Integer(0)),
-            ("(10)", SilverValue::Integer(10)),
+            ("1", SilverValue::Integer(1.into())),
+            ("+1", SilverValue::Integer(1.into())),
+            ("-1", SilverValue::Integer((-1).into())),
+            ("1 + 2", SilverValue::Integer(3.into())),
+            ("1 - 2", SilverValue::Integer((-1).into())),
+            ("1 * 2", SilverValue::Integer(2.into())),
+            ("1 / 2", SilverValue::Integer(0.into())),
+            ("(10)", SilverValue::Integer(10.into())),
             ("12 == 3", SilverValue::Boolean(false)),
             ("3 == 3", SilverValue::Boolean(true)),
             ("12 != 3", SilverValue::Boolean(true)),
@@ -149,16 +797,1596 @@ mod tests {
             ("false", SilverValue::Boolean(false)),
             ("!true", SilverValue::Boolean(false)),
             ("!false", SilverValue::Boolean(true)),
-            ("a = 10", SilverValue::Integer(10)),
+            ("a = 10", SilverValue::Integer(10.into())),
             ("a = true", SilverValue::Boolean(true)),
             ("true && false", SilverValue::Boolean(false)),
             ("true && true", SilverValue::Boolean(true)),
             ("false || false", SilverValue::Boolean(false)),
             ("false || true", SilverValue::Boolean(true)),
+            ("1.5", SilverValue::Float(1.5)),
+            ("2.5e2", SilverValue::Float(250.0)),
+            ("1e-1", SilverValue::Float(0.1)),
+            ("3f64", SilverValue::Float(3.0)),
+            ("-1.5", SilverValue::Float(-1.5)),
+            ("1.5 + 2.25", SilverValue::Float(3.75)),
+            ("1.5 - 2.0", SilverValue::Float(-0.5)),
+            ("1.5 * 2.0", SilverValue::Float(3.0)),
+            ("1.0 / 4.0", SilverValue::Float(0.25)),
+            ("1.0 / 0.0", SilverValue::Float(f64::INFINITY)),
+            ("1.5 == 1.5", SilverValue::Boolean(true)),
+            ("0.0 == -0.0", SilverValue::Boolean(true)),
+            ("0.0 / 0.0 == 0.0 / 0.0", SilverValue::Boolean(false)),
+            ("0.0 / 0.0 != 0.0 / 0.0", SilverValue::Boolean(true)),
+            ("0.0 / 0.0 < 1.0", SilverValue::Boolean(false)),
+            ("1 < 2", SilverValue::Boolean(true)),
+            ("2 <= 2", SilverValue::Boolean(true)),
+            ("1 > 2", SilverValue::Boolean(false)),
+            ("2 >= 3", SilverValue::Boolean(false)),
+            ("1.5 < 2.5", SilverValue::Boolean(true)),
+            ("float(3)", SilverValue::Float(3.0)),
+            ("int(3.9)", SilverValue::Integer(3.into())),
+            ("int(-3.9)", SilverValue::Integer((-3).into())),
+            ("int(0.0 / 0.0)", SilverValue::Integer(0.into())),
+            ("float(1) / float(2)", SilverValue::Float(0.5)),
+            ("int(7)", SilverValue::Integer(7.into())),
+            ("10i32", SilverValue::Int32(10)),
+            ("255u8", SilverValue::UInt8(255)),
+            ("100i8 + 100i8", SilverValue::Int8(-56)),
+            ("200u8 + 100u8", SilverValue::UInt8(44)),
+            ("(-127i8 - 1i8) / -1i8", SilverValue::Int8(-128)),
+            ("-(-127i8 - 1i8)", SilverValue::Int8(-128)),
+            ("1i8 + 1i32", SilverValue::Int32(2)),
+            ("1u8 + 1i16", SilverValue::Int16(2)),
+            ("1u64 + 1", SilverValue::UInt64(2)),
+            ("1u64 + -1", SilverValue::Integer(0.into())),
+            ("1i8 + 300", SilverValue::Integer(301.into())),
+            ("1i32 == 1i64", SilverValue::Boolean(true)),
+            ("1u32 < 2u32", SilverValue::Boolean(true)),
+            ("i8(300)", SilverValue::Int8(44)),
+            ("u8(-1)", SilverValue::UInt8(255)),
+            ("i64(7i8)", SilverValue::Int64(7)),
+            ("u16(1e9)", SilverValue::UInt16(u16::MAX)),
+            ("f64(3u8)", SilverValue::Float(3.0)),
         ]
         .iter()
         {
             check(text, value);
         }
     }
+
+    fn check_diagnostics(text: &str, expected: &[DiagnosticKind]) {
+        let mut error_reporter = StringErrorReporter::new();
+        let syntax_tree = SyntaxTree::parse_str(text, &mut error_reporter);
+        let mut compilation = Compilation::new(&syntax_tree, &mut error_reporter);
+        let mut scope = GlobalScope::new();
+        let result = compilation.evaluate(&mut scope);
+        assert!(result.is_none(), "{}", text);
+        let actual = error_reporter
+            .errors()
+            .iter()
+            .map(|e| e.kind())
+            .collect::<Vec<_>>();
+        assert_eq!(expected.iter().collect::<Vec<_>>(), actual, "{}", text);
+    }
+
+    #[test]
+    fn evaluates_large_integers() {
+        const MAX: &str = "170141183460469231731687303715884105727";
+        for (text, value) in [
+            (
+                format!("{} + 1", MAX),
+                if cfg!(feature = "bigint") {
+                    "170141183460469231731687303715884105728"
+                } else {
+                    "-170141183460469231731687303715884105728"
+                },
+            ),
+            (format!("{} / {}", MAX, MAX), "1"),
+            (format!("-{} - 1 < {}", MAX, MAX), "true"),
+            (format!("u8({} * 2)", MAX), "254"),
+            (format!("int(i64({}))", MAX), "-1"),
+        ] {
+            let mut error_reporter = StringErrorReporter::new();
+            let syntax_tree = SyntaxTree::parse_str(&text, &mut error_reporter);
+            let mut compilation = Compilation::new(&syntax_tree, &mut error_reporter);
+            let result = compilation.evaluate(&mut GlobalScope::new());
+            assert_eq!(value, result.unwrap().to_string(), "{}", text);
+        }
+    }
+
+    #[test]
+    fn reports_bad_conversions() {
+        check_diagnostics(
+            "int(true)",
+            &[DiagnosticKind::CannotConvert {
+                from: SilverType::Boolean,
+                to: SilverType::Integer,
+            }],
+        );
+        check_diagnostics(
+            "float(1, 2)",
+            &[DiagnosticKind::WrongArgumentCount {
+                name: "float".to_string(),
+                expected: 1,
+                actual: 2,
+            }],
+        );
+        check_diagnostics(
+            "frobnicate(1)",
+            &[DiagnosticKind::UndefinedFunction {
+                name: "frobnicate".to_string(),
+            }],
+        );
+        check_diagnostics(
+            "1 + 1.5",
+            &[DiagnosticKind::CannotConvertImplicitly {
+                from: SilverType::Float,
+                to: SilverType::Integer,
+            }],
+        );
+        check_diagnostics(
+            "1 + true",
+            &[DiagnosticKind::UndefinedBinaryOperator {
+                operator_kind: SyntaxKind::PlusToken,
+                left_type: SilverType::Integer,
+                right_type: SilverType::Boolean,
+            }],
+        );
+        check_diagnostics(
+            "1u64 + 1i64",
+            &[DiagnosticKind::CannotConvertImplicitly {
+                from: SilverType::Int64,
+                to: SilverType::UInt64,
+            }],
+        );
+        check_diagnostics(
+            "i8(true)",
+            &[DiagnosticKind::CannotConvert {
+                from: SilverType::Boolean,
+                to: SilverType::Int8,
+            }],
+        );
+        check_diagnostics(
+            "-1u8",
+            &[DiagnosticKind::UndefinedUnaryOperator {
+                operator_kind: SyntaxKind::MinusToken,
+                operand_type: SilverType::UInt8,
+            }],
+        );
+    }
+
+    fn check_session(submissions: &[(&str, SilverValue)]) {
+        let mut scope = GlobalScope::new();
+        for (text, value) in submissions {
+            let mut error_reporter = StringErrorReporter::new();
+            let syntax_tree = SyntaxTree::parse_str(text, &mut error_reporter);
+            let mut compilation = Compilation::new(&syntax_tree, &mut error_reporter);
+            let result = compilation.evaluate(&mut scope);
+            assert_eq!(value, &result.unwrap(), "{}", text);
+        }
+    }
+
+    #[test]
+    fn canonically_equivalent_names_share_a_variable() {
+        check_session(&[
+            ("cafe\u{301} = 1", SilverValue::Integer(1.into())),
+            ("caf\u{e9} + 1", SilverValue::Integer(2.into())),
+        ]);
+    }
+
+    #[test]
+    fn assignment_widens_into_existing_variable() {
+        check_session(&[
+            ("a = 1i64", SilverValue::Int64(1)),
+            ("a = 2i8", SilverValue::Int64(2)),
+            ("a", SilverValue::Int64(2)),
+            ("a = true", SilverValue::Boolean(true)),
+            ("a", SilverValue::Boolean(true)),
+        ]);
+    }
+
+    fn check_program(text: &str, value: &str) {
+        let mut error_reporter = StringErrorReporter::new();
+        let syntax_tree = SyntaxTree::parse_str(text, &mut error_reporter);
+        let mut compilation = Compilation::new(&syntax_tree, &mut error_reporter);
+        let result = compilation.evaluate(&mut GlobalScope::new());
+        let errors: Vec<_> = error_reporter
+            .errors()
+            .iter()
+            .map(|e| e.message())
+            .collect();
+        assert!(!error_reporter.had_error(), "{}: {:?}", text, errors);
+        assert_eq!(value, result.unwrap().to_string(), "{}", text);
+    }
+
+    #[test]
+    fn evaluates_structs() {
+        const POINT: &str = "struct Point { x: int, y: int }";
+        check_program(
+            &format!("{} Point {{ y: 2, x: 1 }}", POINT),
+            "Point { x: 1, y: 2 }",
+        );
+        check_program(&format!("{} Point {{ x: 1i8, y: 2 }}.x", POINT), "1");
+        check_program(
+            &format!("{} p = Point {{ x: 1, y: 2 }} p.y = 5u8 p", POINT),
+            "Point { x: 1, y: 5 }",
+        );
+        check_program(
+            "struct A { xs: [f64] } struct B { a: A } b = B { a: A { xs: [1.5] } } b.a.xs[0]",
+            "1.5",
+        );
+    }
+
+    #[test]
+    fn struct_declarations_persist_between_submissions() {
+        let mut scope = GlobalScope::new();
+        for (text, value) in [
+            ("struct P { v: bool }", None),
+            ("p = P { v: true }", Some("P { v: true }")),
+            ("q = p q.v = false p.v", Some("false")),
+        ] {
+            let mut error_reporter = StringErrorReporter::new();
+            let syntax_tree = SyntaxTree::parse_str(text, &mut error_reporter);
+            let mut compilation = Compilation::new(&syntax_tree, &mut error_reporter);
+            let result = compilation.evaluate(&mut scope);
+            assert!(!error_reporter.had_error(), "{}", text);
+            assert_eq!(value, result.map(|v| v.to_string()).as_deref(), "{}", text);
+        }
+    }
+
+    #[test]
+    fn reports_bad_structs() {
+        check_diagnostics(
+            "struct P { x: int, x: bool }",
+            &[DiagnosticKind::DuplicateField {
+                name: "x".to_string(),
+            }],
+        );
+        check_diagnostics(
+            "struct P { x: Q }",
+            &[DiagnosticKind::UndefinedType {
+                name: "Q".to_string(),
+            }],
+        );
+        check_diagnostics(
+            "Q { x: 1 }",
+            &[DiagnosticKind::UndefinedType {
+                name: "Q".to_string(),
+            }],
+        );
+        check_diagnostics(
+            "int { }",
+            &[DiagnosticKind::NotAStruct(SilverType::Integer)],
+        );
+        check_diagnostics(
+            "1.x",
+            &[DiagnosticKind::UnknownField {
+                ty: SilverType::Integer,
+                name: "x".to_string(),
+            }],
+        );
+    }
+
+    #[test]
+    fn reports_bad_struct_fields() {
+        let point = Rc::new(TypeSymbol::new(
+            "P".to_string(),
+            vec![],
+            vec![
+                VariableSymbol::new("x".to_string(), SilverType::Integer),
+                VariableSymbol::new("y".to_string(), SilverType::Integer),
+            ],
+        ));
+        let ty = SilverType::Struct(point);
+        const P: &str = "struct P { x: int, y: int }";
+        check_diagnostics(
+            &format!("{} P {{ x: 1 }}", P),
+            &[DiagnosticKind::MissingFields {
+                ty: ty.clone(),
+                names: vec!["y".to_string()],
+            }],
+        );
+        check_diagnostics(
+            &format!("{} P {{ x: 1, y: 2, x: 3 }}", P),
+            &[DiagnosticKind::DuplicateField {
+                name: "x".to_string(),
+            }],
+        );
+        check_diagnostics(
+            &format!("{} P {{ x: 1, y: 2, z: 3 }}", P),
+            &[DiagnosticKind::UnknownField {
+                ty: ty.clone(),
+                name: "z".to_string(),
+            }],
+        );
+        check_diagnostics(
+            &format!("{} P {{ x: 1, y: 2 }}.z = 1", P),
+            &[DiagnosticKind::UnknownField {
+                ty: ty.clone(),
+                name: "z".to_string(),
+            }],
+        );
+        check_diagnostics(
+            &format!("{} P {{ x: 1, y: true }}", P),
+            &[DiagnosticKind::CannotConvert {
+                from: SilverType::Boolean,
+                to: SilverType::Integer,
+            }],
+        );
+    }
+
+    const SHAPE: &str = "enum Shape { Circle(f64), Rect(f64, f64), Empty }";
+
+    fn shape_type() -> SilverType {
+        SilverType::Enum(Rc::new(EnumSymbol::new(
+            "Shape".to_string(),
+            vec![],
+            vec![
+                VariantSymbol::new("Circle".to_string(), vec![SilverType::Float]),
+                VariantSymbol::new(
+                    "Rect".to_string(),
+                    vec![SilverType::Float, SilverType::Float],
+                ),
+                VariantSymbol::new("Empty".to_string(), vec![]),
+            ],
+        )))
+    }
+
+    #[test]
+    fn evaluates_enums() {
+        check_program(
+            &format!("{} Shape.Rect(1.0, 2.5)", SHAPE),
+            "Shape.Rect(1.0, 2.5)",
+        );
+        check_program(&format!("{} Shape.Empty", SHAPE), "Shape.Empty");
+        check_program(
+            &format!(
+                "{} enum Option {{ Some(Shape), None }} Option.Some(Shape.Empty)",
+                SHAPE
+            ),
+            "Option.Some(Shape.Empty)",
+        );
+    }
+
+    #[test]
+    fn evaluates_match_expressions() {
+        const AREA: &str =
+            "match s { Shape.Circle(r) => 3.0 * r * r, Shape.Rect(w, h) => w * h, Shape.Empty => 0.0 }";
+        for (shape, area) in [
+            ("Shape.Circle(2.0)", "12.0"),
+            ("Shape.Rect(2.0, 3.5)", "7.0"),
+            ("Shape.Empty", "0.0"),
+        ] {
+            check_program(&format!("{} s = {} {}", SHAPE, shape, AREA), area);
+        }
+        check_program("match 2 { 1 => false, 2 => true, _ => false }", "true");
+        check_program("match 300u16 { 300 => 1, n => n }", "1");
+        check_program("match true { true => 1i8, false => 2 }", "1");
+        check_program("match 1.5 { 1.5 => 0.0, x => x }", "0.0");
+        check_program(
+            &format!(
+                "{} match Shape.Rect(1.0, 2.0) {{ Shape.Rect(1.0, h) => h, Shape.Rect(_, h) => -h, _ => 0.0 }}",
+                SHAPE
+            ),
+            "2.0",
+        );
+    }
+
+    #[test]
+    fn pattern_variables_are_local_to_their_arm() {
+        check_program("x = 1 match 2 { x => x } + x", "3");
+        check_program("match 2 { x => x = true } x = 5 x", "5");
+        check_program("match 2 { n => y = n } y", "2");
+    }
+
+    #[test]
+    fn match_scrutinee_is_not_a_struct_construction() {
+        check_program(
+            "struct P { x: int } p = P { x: 1 } match p { q => q.x }",
+            "1",
+        );
+        check_program(
+            "struct P { x: int } match (P { x: 2 }) { q => [P { x: 3 }][0].x + q.x }",
+            "5",
+        );
+    }
+
+    #[test]
+    fn reports_bad_enums() {
+        check_diagnostics(
+            "enum E { A, A(int) }",
+            &[DiagnosticKind::DuplicateVariant {
+                name: "A".to_string(),
+            }],
+        );
+        check_diagnostics(
+            "enum E { A(Q) }",
+            &[DiagnosticKind::UndefinedType {
+                name: "Q".to_string(),
+            }],
+        );
+        let shape = shape_type();
+        check_diagnostics(
+            &format!("{} Shape.Square(1)", SHAPE),
+            &[DiagnosticKind::UnknownVariant {
+                ty: shape.clone(),
+                name: "Square".to_string(),
+            }],
+        );
+        check_diagnostics(
+            &format!("{} Shape.Circle", SHAPE),
+            &[DiagnosticKind::WrongArgumentCount {
+                name: "Shape.Circle".to_string(),
+                expected: 1,
+                actual: 0,
+            }],
+        );
+        check_diagnostics(
+            &format!("{} Shape.Circle(true)", SHAPE),
+            &[DiagnosticKind::CannotConvert {
+                from: SilverType::Boolean,
+                to: SilverType::Float,
+            }],
+        );
+        check_diagnostics("i8.Max", &[DiagnosticKind::NotAnEnum(SilverType::Int8)]);
+        check_diagnostics(
+            "x = 1 x.foo()",
+            &[DiagnosticKind::UndefinedFunction {
+                name: "foo".to_string(),
+            }],
+        );
+    }
+
+    #[test]
+    fn reports_bad_matches() {
+        check_diagnostics(
+            &format!("{} match Shape.Empty {{ Shape.Circle(_) => 1 }}", SHAPE),
+            &[DiagnosticKind::NonExhaustiveMatch {
+                missing: vec!["Shape.Rect(_, _)".to_string(), "Shape.Empty".to_string()],
+            }],
+        );
+        check_diagnostics(
+            "enum O { S(bool), N } match O.N { O.S(true) => 1, O.N => 2 }",
+            &[DiagnosticKind::NonExhaustiveMatch {
+                missing: vec!["O.S(false)".to_string()],
+            }],
+        );
+        check_diagnostics(
+            "match 1 { 1 => 1, 2 => 2 }",
+            &[DiagnosticKind::NonExhaustiveMatch {
+                missing: vec!["_".to_string()],
+            }],
+        );
+        check_diagnostics(
+            "match true { x => 1, false => 2 }",
+            &[DiagnosticKind::UnreachableArm],
+        );
+        check_diagnostics(
+            "match true { true => 1, false => 2, _ => 3 }",
+            &[DiagnosticKind::UnreachableArm],
+        );
+        check_diagnostics(
+            "match 1 { 1 => 1, 1 => 2, _ => 3 }",
+            &[DiagnosticKind::UnreachableArm],
+        );
+        check_diagnostics(
+            "match 1 { true => 1, _ => 2 }",
+            &[DiagnosticKind::PatternTypeMismatch {
+                expected: SilverType::Integer,
+                actual: SilverType::Boolean,
+            }],
+        );
+        check_diagnostics(
+            "match 1u8 { 256 => 1, _ => 2 }",
+            &[DiagnosticKind::NumberOutOfRange(SilverType::UInt8)],
+        );
+        check_diagnostics(
+            "match 1 { 1 => 1, _ => true }",
+            &[DiagnosticKind::CannotConvert {
+                from: SilverType::Boolean,
+                to: SilverType::Integer,
+            }],
+        );
+        check_diagnostics(
+            &format!("{} match 1 {{ Shape.Empty => 1, _ => 2 }}", SHAPE),
+            &[DiagnosticKind::PatternTypeMismatch {
+                expected: SilverType::Integer,
+                actual: shape_type(),
+            }],
+        );
+        check_diagnostics(
+            &format!(
+                "{} match Shape.Empty {{ Shape.Rect(w) => w, _ => 0.0 }}",
+                SHAPE
+            ),
+            &[DiagnosticKind::WrongArgumentCount {
+                name: "Shape.Rect".to_string(),
+                expected: 2,
+                actual: 1,
+            }],
+        );
+    }
+
+    const NESTED: &str = "struct P { x: int } struct Q { p: P? }";
+
+    #[test]
+    fn evaluates_nullable_values() {
+        check_program("null", "null");
+        check_program("a = 1 null", "null");
+        check_program("[1, null]", "[1, null]");
+        check_program("a = [1, null] a[1] ?? 5", "5");
+        check_program("a = [1, null] a[0] ?? 5", "1");
+        check_program("[null, 2i8][0] ?? 300", "300");
+        check_program("[1, null][1] ?? [2, null][1]", "null");
+        check_program("null == null", "true");
+        check_program("[1, null][1] == null", "true");
+        check_program("[1i8, null][0] == 1", "true");
+        check_program("[1, null][0] != 1", "false");
+        check_program(&format!("{} q = Q {{ p: null }} q.p?.x", NESTED), "null");
+        check_program(
+            &format!("{} q = Q {{ p: P {{ x: 1 }} }} q.p?.x ?? 0", NESTED),
+            "1",
+        );
+        check_program(&format!("{} Q {{ p: null }}.p == null", NESTED), "true");
+    }
+
+    #[test]
+    fn null_checks_narrow_nullable_variables() {
+        check_program("x = [3, null][0] x != null && x > 2", "true");
+        check_program("x = [3, null][1] x != null && x > 2", "false");
+        check_program("x = [3, null][1] x == null || x > 2", "true");
+        check_program("x = [3, null][0] !(x == null) && x + 1 == 4", "true");
+        check_program("x = [3, null][0] (null != x && true) && -x < 0", "true");
+        check_program(
+            &format!(
+                "{} q = Q {{ p: P {{ x: 1 }} }} p = q.p p != null && p.x == 1",
+                NESTED
+            ),
+            "true",
+        );
+        check_program("x = [1, null][1] match x { null => 0, v => v + 1 }", "0");
+        check_program("x = [1, null][0] match x { null => 0, v => v + 1 }", "2");
+        check_program(
+            "match [true, null][1] { true => 1, false => 2, null => 3 }",
+            "3",
+        );
+        check_program(
+            &format!(
+                "{} match [Shape.Empty, null][1] {{ Shape.Empty => 1, null => 2, _ => 3 }}",
+                SHAPE
+            ),
+            "2",
+        );
+    }
+
+    #[test]
+    fn matches_on_null_checks_narrow_their_arms() {
+        let text = "x = [3, null][N] match x == null { true => 0, false => x + 1 }";
+        check_program(&text.replace('N', "0"), "4");
+        check_program(&text.replace('N', "1"), "0");
+        check_program(
+            "x = [3, null][0] match x != null && x > 1 { true => x * 2, _ => 0 }",
+            "6",
+        );
+        check_program(
+            "x = [3, null][0] match x == null { true => 0, _ => x }",
+            "3",
+        );
+        check_program(
+            "x = [3, null][0] match !(x == null) { false => 0, y => x }",
+            "3",
+        );
+        // Only the arms of the match are narrowed.
+        check_diagnostics(
+            "x = [3, null][0] match x == null { true => 0, false => x } + x",
+            &[DiagnosticKind::PossiblyNull(SilverType::nullable(
+                SilverType::Integer,
+            ))],
+        );
+        check_diagnostics(
+            "x = [3, null][0] match x == null { false => 1, _ => x + 1 }",
+            &[DiagnosticKind::PossiblyNull(SilverType::nullable(
+                SilverType::Integer,
+            ))],
+        );
+        check_diagnostics(
+            "x = [3, null][0] match x == null { true => x + 1, _ => 1 }",
+            &[DiagnosticKind::PossiblyNull(SilverType::nullable(
+                SilverType::Integer,
+            ))],
+        );
+    }
+
+    #[test]
+    fn reports_possibly_null_values() {
+        let int = SilverType::nullable(SilverType::Integer);
+        check_diagnostics(
+            "x = [1, null][0] x + 1",
+            &[DiagnosticKind::PossiblyNull(int.clone())],
+        );
+        check_diagnostics(
+            "x = [1, null][0] x != null || x > 0",
+            &[DiagnosticKind::PossiblyNull(int.clone())],
+        );
+        check_diagnostics(
+            "x = [1, null][0] x != null && (x = [null, 2][0]) == null && x > 0",
+            &[DiagnosticKind::PossiblyNull(int.clone())],
+        );
+        check_diagnostics(
+            &format!("{} Q {{ p: null }}.p.x", NESTED),
+            &[DiagnosticKind::PossiblyNull(SilverType::nullable(
+                SilverType::Struct(Rc::new(TypeSymbol::new(
+                    "P".to_string(),
+                    vec![],
+                    vec![VariableSymbol::new("x".to_string(), SilverType::Integer)],
+                ))),
+            ))],
+        );
+        check_diagnostics(
+            "struct P { x: int } P { x: [1, null][0] }",
+            &[DiagnosticKind::PossiblyNull(int.clone())],
+        );
+        check_diagnostics("[1][[0, null][0]]", &[DiagnosticKind::PossiblyNull(int)]);
+        check_diagnostics(
+            "match [true, null][0] { true => 1, false => 2 }",
+            &[DiagnosticKind::NonExhaustiveMatch {
+                missing: vec!["null".to_string()],
+            }],
+        );
+        check_diagnostics(
+            "match 1 { null => 1, _ => 2 }",
+            &[DiagnosticKind::PatternTypeMismatch {
+                expected: SilverType::Integer,
+                actual: SilverType::Null,
+            }],
+        );
+        check_diagnostics(
+            "null + 1",
+            &[DiagnosticKind::UndefinedBinaryOperator {
+                operator_kind: SyntaxKind::PlusToken,
+                left_type: SilverType::Null,
+                right_type: SilverType::Integer,
+            }],
+        );
+    }
+
+    #[test]
+    fn evaluates_type_annotations_and_casts() {
+        check_program("x: i16 = 5i8 x + 1i16", "6");
+        check_program("x: int = 5i8 x + 1", "6");
+        check_program("x: int? = null x ?? 2", "2");
+        check_program("x: int? = 3 x ?? 2", "3");
+        check_program("x = true x: float = 1.0 x / 2.0", "0.5");
+        check_program("x: [int] = [1, 2] x[1]", "2");
+        check_program("300 as u8", "44");
+        check_program("1.5 as int", "1");
+        check_program("-1 as u8", "255");
+        check_program("2.5 * 2 as float", "5.0");
+        check_program("x = 1 x as int? ?? 2", "1");
+        check("x: i32 = 5 x", &SilverValue::Int32(5));
+        check("x: u8 = 5 x * 2", &SilverValue::UInt8(10));
+    }
+
+    #[test]
+    fn annotated_variables_keep_their_type() {
+        check("x: i8 = 1 x = 5 x", &SilverValue::Int8(5));
+        check("x: i16 = 1 x = 2i8 x", &SilverValue::Int16(2));
+        check("x: i8 = 1 x = -x x", &SilverValue::Int8(-1));
+        check_program("x: int? = null x = 3 x ?? 0", "3");
+        check_program("x: i8 = 1 x = 2 x: bool = true x", "true");
+        check_program("x = 1 x = true x", "true");
+        check_diagnostics(
+            "x: i8 = 1 x = true",
+            &[DiagnosticKind::CannotConvert {
+                from: SilverType::Boolean,
+                to: SilverType::Int8,
+            }],
+        );
+        check_diagnostics(
+            "x: i8 = 1 x = x + 200",
+            &[DiagnosticKind::CannotConvertImplicitly {
+                from: SilverType::Integer,
+                to: SilverType::Int8,
+            }],
+        );
+        check_session(&[
+            ("x: i8 = 1", SilverValue::Int8(1)),
+            ("x = 2", SilverValue::Int8(2)),
+        ]);
+    }
+
+    #[test]
+    fn evaluates_functions() {
+        check_program("fn inc(x: int) => x + 1 inc(1)", "2");
+        check_program("fn add(a: int, b: int): int => a + b add(1i8, 2u8)", "3");
+        check_program(
+            "fn fact(n: int): int => match n { 0 => 1, _ => n * fact(n - 1) } fact(10)",
+            "3628800",
+        );
+        check_program("fn one() => 1 fn two() => one() + one() two()", "2");
+        check_program("x = 5 fn f(x: int) => (y = x * 2) + y f(1) + x", "9");
+        check_program("fn f(x: int?) => x ?? 0 f(null) + f(3)", "3");
+        check("fn f(x: i32) => x * 2 f(5)", &SilverValue::Int32(10));
+        check(
+            "fn f(a: u8, b: i16): i16 => a + b f(1, -2)",
+            &SilverValue::Int16(-1),
+        );
+    }
+
+    #[test]
+    fn infers_parameter_types() {
+        check_program("fn inc(x) => x + 1 inc(2)", "3");
+        check_program("fn add(a, b) => a + b + 1i8 add(1i8, 2i8)", "4");
+        check_program("fn both(a, b) => a && b both(true, false)", "false");
+        check_program("fn not(a) => !a not(false)", "true");
+        check_program("fn first(xs) => xs[0] + 0.5 first([1.0, 2.0])", "1.5");
+        check_program("fn size(xs: [bool], n) => len(xs) + n size([true], 1)", "2");
+        check_program("fn or_zero(x) => x ?? 0 or_zero(null) + or_zero(2)", "2");
+        check_program(
+            "fn positive(x) => x != null && x > 0 positive(null) || positive(3)",
+            "true",
+        );
+        check_program(
+            "fn fact(n): int => match n { 0 => 1, _ => n * fact(n - 1) } fact(5)",
+            "120",
+        );
+        check_program(
+            &format!(
+                "{} fn area(s) => match s {{ Shape.Circle(r) => r * r, _ => 0.0 }} \
+                 area(Shape.Circle(2.0))",
+                SHAPE
+            ),
+            "4.0",
+        );
+        check_program("fn g(x: i16) => x fn f(x) => g(x) f(3i8)", "3");
+        check_program("fn f(x) => (x = 5) + x f(1)", "10");
+    }
+
+    #[test]
+    fn reports_uninferable_and_conflicting_parameter_types() {
+        check_diagnostics(
+            "fn f(x) => x",
+            &[DiagnosticKind::CannotInferParameterType {
+                name: "x".to_string(),
+            }],
+        );
+        check_diagnostics(
+            "fn f(x, y) => x == y",
+            &[
+                DiagnosticKind::CannotInferParameterType {
+                    name: "x".to_string(),
+                },
+                DiagnosticKind::CannotInferParameterType {
+                    name: "y".to_string(),
+                },
+            ],
+        );
+        check_diagnostics(
+            "fn f(xs) => xs[0]",
+            &[DiagnosticKind::CannotInferParameterType {
+                name: "xs".to_string(),
+            }],
+        );
+        check_diagnostics(
+            "fn f(x, y) => x + y + (y && true) > 0",
+            &[DiagnosticKind::ConflictingTypes {
+                expected: SilverType::Integer,
+                actual: SilverType::Boolean,
+            }],
+        );
+        check_diagnostics(
+            "fn f(x) => x > 0 && x < 1.5",
+            &[DiagnosticKind::ConflictingTypes {
+                expected: SilverType::Float,
+                actual: SilverType::Integer,
+            }],
+        );
+        check_diagnostics(
+            "fn f(x) => x[0] && x",
+            &[DiagnosticKind::ConflictingTypes {
+                expected: SilverType::Boolean,
+                actual: SilverType::Array(Box::new(SilverType::Boolean)),
+            }],
+        );
+        check_diagnostics(
+            "fn f(x) => x + 1 f(true)",
+            &[DiagnosticKind::CannotConvert {
+                from: SilverType::Boolean,
+                to: SilverType::Integer,
+            }],
+        );
+    }
+
+    #[test]
+    fn functions_persist_between_submissions() {
+        check_session(&[
+            ("fn f(x: int) => x * 2 0", SilverValue::Integer(0.into())),
+            ("f(3)", SilverValue::Integer(6.into())),
+            ("g = f(4)", SilverValue::Integer(8.into())),
+            ("fn f(x: bool) => !x f(true)", SilverValue::Boolean(false)),
+        ]);
+    }
+
+    #[test]
+    fn reports_bad_annotations_and_casts() {
+        check_diagnostics(
+            "x: number = 1",
+            &[DiagnosticKind::UndefinedType {
+                name: "number".to_string(),
+            }],
+        );
+        check_diagnostics(
+            "x: i8 = 1.5",
+            &[DiagnosticKind::CannotConvertImplicitly {
+                from: SilverType::Float,
+                to: SilverType::Int8,
+            }],
+        );
+        check_diagnostics(
+            "true as int",
+            &[DiagnosticKind::CannotConvert {
+                from: SilverType::Boolean,
+                to: SilverType::Integer,
+            }],
+        );
+        check_diagnostics(
+            "(1 as number) + 1",
+            &[DiagnosticKind::UndefinedType {
+                name: "number".to_string(),
+            }],
+        );
+    }
+
+    #[test]
+    fn reports_bad_functions() {
+        check_diagnostics(
+            "fn f(x: int, x: int) => x",
+            &[DiagnosticKind::DuplicateParameter {
+                name: "x".to_string(),
+            }],
+        );
+        check_diagnostics(
+            "fn f(n: int) => f(n) f(1)",
+            &[DiagnosticKind::MissingReturnType {
+                name: "f".to_string(),
+            }],
+        );
+        check_diagnostics(
+            "fn f(n: int) => n f(1, 2)",
+            &[DiagnosticKind::WrongArgumentCount {
+                name: "f".to_string(),
+                expected: 1,
+                actual: 2,
+            }],
+        );
+        check_diagnostics(
+            "fn f(n: int) => n f(true)",
+            &[DiagnosticKind::CannotConvert {
+                from: SilverType::Boolean,
+                to: SilverType::Integer,
+            }],
+        );
+        check_diagnostics(
+            "x = 1 fn f() => x",
+            &[DiagnosticKind::UndefinedName {
+                name: "x".to_string(),
+            }],
+        );
+        check_diagnostics(
+            "fn f(): bool => 1",
+            &[DiagnosticKind::CannotConvert {
+                from: SilverType::Integer,
+                to: SilverType::Boolean,
+            }],
+        );
+        check_diagnostics(
+            "fn f(n: int): int => f(n + 1) f(0)",
+            &[DiagnosticKind::CallDepthExceeded { depth: 200 }],
+        );
+    }
+
+    #[test]
+    fn nests_calls_as_deeply_as_the_compilation_allows() {
+        // Deep recursion needs a larger stack than a test thread has.
+        let thread = std::thread::Builder::new().stack_size(256 << 20);
+        let results = thread
+            .spawn(|| {
+                let text = "fn sum(n: int): int => match n { 0 => 0, _ => n + sum(n - 1) }";
+                let mut scope = GlobalScope::new();
+                [text, "sum(2000)", "sum(3000)"].map(|text| {
+                    let mut error_reporter = StringErrorReporter::new();
+                    let syntax_tree = SyntaxTree::parse_str(text, &mut error_reporter);
+                    let mut compilation = Compilation::new(&syntax_tree, &mut error_reporter);
+                    compilation.set_max_call_depth(2500);
+                    let result = compilation.evaluate(&mut scope).map(|v| v.to_string());
+                    let errors: Vec<_> = error_reporter
+                        .errors()
+                        .iter()
+                        .map(|e| e.message().to_string())
+                        .collect();
+                    (result, errors)
+                })
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+        assert_eq!((Some("2001000".to_string()), vec![]), results[1]);
+        let error = "The call nests more than 2500 calls deep.".to_string();
+        assert_eq!((None, vec![error]), results[2]);
+    }
+
+    fn evaluate_cancellable(
+        text: &str,
+        scope: &mut GlobalScope,
+        cancellation_token: &CancellationToken,
+    ) -> (Option<SilverValue>, StringErrorReporter) {
+        let mut error_reporter = StringErrorReporter::new();
+        let syntax_tree = SyntaxTree::parse_str(text, &mut error_reporter);
+        let result = Compilation::new(&syntax_tree, &mut error_reporter)
+            .evaluate_cancellable(scope, cancellation_token);
+        (result, error_reporter)
+    }
+
+    #[test]
+    fn stops_at_calls_when_cancelled() {
+        let mut scope = GlobalScope::new();
+        let cancellation_token = CancellationToken::new();
+        let text = "fn f(n: int): int => match n { 0 => 0, m => f(m - 1) + 1 } x = 1";
+        evaluate_cancellable(text, &mut scope, &cancellation_token);
+
+        cancellation_token.cancel();
+        // Nothing checks for cancellation outside a call.
+        let (result, errors) = evaluate_cancellable("x + 1", &mut scope, &cancellation_token);
+        assert_eq!(Some(SilverValue::Integer(2.into())), result);
+        assert!(!errors.had_error());
+        let (result, errors) =
+            evaluate_cancellable("x = 2 y = f(10) z = 3", &mut scope, &cancellation_token);
+        assert_eq!(None, result);
+        let kinds: Vec<_> = errors.errors().iter().map(|e| e.kind()).collect();
+        assert_eq!(vec![&DiagnosticKind::EvaluationCancelled], kinds);
+        // The assignment before the call was made, those after it were not.
+        let names: Vec<_> = scope
+            .variables()
+            .map(|(variable, value)| (variable.name().to_string(), value))
+            .collect();
+        assert_eq!(
+            vec![("x".to_string(), SilverValue::Integer(2.into()))],
+            names
+        );
+
+        cancellation_token.reset();
+        let (result, errors) = evaluate_cancellable("f(x)", &mut scope, &cancellation_token);
+        assert_eq!(Some(SilverValue::Integer(2.into())), result);
+        assert!(!errors.had_error());
+    }
+
+    #[test]
+    fn evaluates_recursive_types() {
+        let list = "enum List { Cons(int, List), Nil }
+            fn sum(l: List): int => match l { List.Cons(x, rest) => x + sum(rest), List.Nil => 0 }";
+        check_program(
+            &format!("{} sum(List.Cons(1, List.Cons(2, List.Nil)))", list),
+            "3",
+        );
+        check_program(
+            &format!("{} List.Cons(1, List.Cons(2, List.Nil))", list),
+            "List.Cons(1, List.Cons(2, List.Nil))",
+        );
+        let node = "struct Node { next: Node?, value: int }";
+        check_program(
+            &format!(
+                "{} n = Node {{ next: Node {{ next: null, value: 2 }}, value: 1 }} n.next?.value",
+                node
+            ),
+            "2",
+        );
+        check_program(
+            &format!(
+                "{} n = Node {{ next: null, value: 1 }} n.next = n n.next?.next?.value",
+                node
+            ),
+            "1",
+        );
+        check_program(
+            "enum Tree { Node([Tree]), Leaf(int) }
+            fn total(t: Tree): int => match t { Tree.Leaf(n) => n, Tree.Node(ts) => total(ts[0]) + 1 }
+            total(Tree.Node([Tree.Node([Tree.Leaf(5)])]))",
+            "7",
+        );
+        check_program(
+            "enum List<T> { Cons(T, List<T>), Nil }
+            fn len<T>(l: List<T>): int => match l { List.Cons(_, r) => 1 + len(r), List.Nil => 0 }
+            len(List<string>.Cons(\"a\", List<string>.Cons(\"b\", List<string>.Nil)))",
+            "2",
+        );
+        check_program(
+            "struct Pair<A, B> { a: A, b: B } struct Chain { link: Pair<int, Chain?> }
+            p = Pair<int, Chain?> { a: 2, b: null }
+            Chain { link: Pair<int, Chain?> { a: 1, b: Chain { link: p } } }.link.b?.link?.a",
+            "2",
+        );
+        check_session(&[
+            (&format!("{} 0", list), SilverValue::Integer(0.into())),
+            (
+                "sum(List.Cons(4, List.Nil))",
+                SilverValue::Integer(4.into()),
+            ),
+        ]);
+    }
+
+    #[test]
+    fn reports_recursive_type_errors() {
+        check_diagnostics(
+            "enum List<T> { Cons(T, List<int>), Nil }",
+            &[DiagnosticKind::IrregularRecursiveType {
+                name: "List".to_string(),
+            }],
+        );
+        check_diagnostics(
+            "enum List<T> { Cons(T, List), Nil }",
+            &[DiagnosticKind::WrongTypeArgumentCount {
+                name: "List".to_string(),
+                expected: 1,
+                actual: 0,
+            }],
+        );
+        check_diagnostics(
+            "struct Node { next: Node?, value: int } Node { next: 1, value: 1 }",
+            &[DiagnosticKind::CannotConvert {
+                from: SilverType::Integer,
+                to: SilverType::nullable(SilverType::Struct(Rc::new(TypeSymbol::new(
+                    "Node".to_string(),
+                    vec![],
+                    vec![
+                        VariableSymbol::new(
+                            "next".to_string(),
+                            SilverType::nullable(SilverType::Recursive("Node".to_string(), vec![])),
+                        ),
+                        VariableSymbol::new("value".to_string(), SilverType::Integer),
+                    ],
+                )))),
+            }],
+        );
+        check_diagnostics(
+            "enum List { Cons(int, List), Nil } match List.Nil { List.Cons(1, _) => 1, List.Nil => 0 }",
+            &[DiagnosticKind::NonExhaustiveMatch {
+                missing: vec!["List.Cons(_, _)".to_string()],
+            }],
+        );
+    }
+
+    const PAIR: &str = "struct Pair<A, B> { a: A, b: B }";
+    const OPTION: &str = "enum Option<T> { Some(T), None }";
+
+    #[test]
+    fn evaluates_generics() {
+        check_program("fn id<T>(x: T): T => x id(5) + 1", "6");
+        check_program("fn id<T>(x: T) => x id(true)", "true");
+        check_program("fn id<T>(x: T): T => x id<i16>(5i8) + 1i16", "6");
+        check_program("fn first<T>(a: T, b: T): T => a first(1i8, 2) + 300", "301");
+        check_program("fn first<T>(xs: [T]): T => xs[0] first([2.5, 1.0])", "2.5");
+        check_program("fn or<T>(x: T?, y: T): T => x ?? y or(null, 3)", "3");
+        check_program(
+            "fn count<T>(xs: [T], i: int): int => \
+             match i == len(xs) { true => 0, false => 1 + count(xs, i + 1) } \
+             count([true, false], 0)",
+            "2",
+        );
+        check_program(&format!("{} Pair {{ a: 1, b: true }}.b", PAIR), "true");
+        check_program(
+            &format!("{} p: Pair<int, bool> = Pair {{ a: 1, b: true }} p.a", PAIR),
+            "1",
+        );
+        check_program(
+            &format!(
+                "{} fn swap<A, B>(p: Pair<A, B>): Pair<B, A> => Pair {{ a: p.b, b: p.a }} \
+                 swap(Pair<int, bool> {{ a: 1, b: false }})",
+                PAIR
+            ),
+            "Pair { a: false, b: 1 }",
+        );
+        check_program(
+            &format!(
+                "{} fn unwrap_or<T>(o: Option<T>, d: T): T => \
+                 match o {{ Option.Some(x) => x, Option.None => d }} \
+                 unwrap_or(Option.Some(3), 0) + unwrap_or(Option<int>.None, 4)",
+                OPTION
+            ),
+            "7",
+        );
+    }
+
+    #[test]
+    fn reports_bad_generics() {
+        check_diagnostics(
+            "fn id<T>(x: T): T => x id<int, bool>(1)",
+            &[DiagnosticKind::WrongTypeArgumentCount {
+                name: "id".to_string(),
+                expected: 1,
+                actual: 2,
+            }],
+        );
+        check_diagnostics(
+            &format!("{} p: Pair<int> = 1", PAIR),
+            &[DiagnosticKind::WrongTypeArgumentCount {
+                name: "Pair".to_string(),
+                expected: 2,
+                actual: 1,
+            }],
+        );
+        check_diagnostics(
+            &format!("{} o: Option = 1", OPTION),
+            &[DiagnosticKind::WrongTypeArgumentCount {
+                name: "Option".to_string(),
+                expected: 1,
+                actual: 0,
+            }],
+        );
+        check_diagnostics(
+            "x: int<bool> = 1",
+            &[DiagnosticKind::WrongTypeArgumentCount {
+                name: "i128".to_string(),
+                expected: 0,
+                actual: 1,
+            }],
+        );
+        check_diagnostics(
+            &format!("{} Option.None", OPTION),
+            &[DiagnosticKind::CannotInferTypeArgument {
+                name: "Option".to_string(),
+                parameter: "T".to_string(),
+            }],
+        );
+        check_diagnostics(
+            "fn none<T>(): T? => null none()",
+            &[DiagnosticKind::CannotInferTypeArgument {
+                name: "none".to_string(),
+                parameter: "T".to_string(),
+            }],
+        );
+        check_diagnostics(
+            "fn first<T>(a: T, b: T): T => a first(1, true)",
+            &[DiagnosticKind::ConflictingTypeArguments {
+                parameter: "T".to_string(),
+                first: SilverType::Integer,
+                second: SilverType::Boolean,
+            }],
+        );
+        check_diagnostics(
+            "fn f<T>(x: T): int => x",
+            &[DiagnosticKind::CannotConvert {
+                from: SilverType::Parameter("T".to_string()),
+                to: SilverType::Integer,
+            }],
+        );
+        check_diagnostics(
+            "fn f<T, T>(x: T) => x",
+            &[DiagnosticKind::DuplicateParameter {
+                name: "T".to_string(),
+            }],
+        );
+    }
+
+    #[test]
+    fn evaluates_closures() {
+        check_program("inc = (x) => x + 1 inc(41)", "42");
+        check_program("((x: int, y: bool) => y)(1, true)", "true");
+        check_program("add = (a: int) => (b: int) => a + b add(2)(3)", "5");
+        check_program("(x: i8) => x", "<fn(i8): i8>");
+        check_program(
+            "fn apply(f: fn(int): int, x: int) => f(x) apply((x) => x * 3, 5)",
+            "15",
+        );
+        check_program(
+            "fn twice<T>(f: fn(T): T, x: T): T => f(f(x)) twice((b: bool) => !b, true)",
+            "true",
+        );
+        check_program("fn inc(x: int) => x + 1 f = inc f(1)", "2");
+        check_program("fn call(f) => f(1) + 1 call((x: int) => x)", "2");
+        check_program(
+            "struct S { f: fn(int): int } s = S { f: (x) => x * 2 } s.f(4)",
+            "8",
+        );
+        // Variables are captured by reference, both ways.
+        check_program("x = 1 f = () => x x = 2 f()", "2");
+        check_program("x = 1 f = () => (x = x + 1) f() f() x", "3");
+        check_program(
+            "fn counter(start: int) => () => (start = start + 1) c = counter(10) c() c()",
+            "12",
+        );
+        // A lambda that declares a variable afresh leaves the captured one.
+        check_program("x = 1 f = () => x = true f() x", "1");
+        check_program(
+            "fn f(n: int): int => match n { 0 => 0, _ => f(n - 1) } g = f g(3)",
+            "0",
+        );
+    }
+
+    #[test]
+    fn closures_see_later_submissions() {
+        check_session(&[
+            ("x = 1", SilverValue::Integer(1.into())),
+            ("f = () => x * 10 0", SilverValue::Integer(0.into())),
+            ("x = 2", SilverValue::Integer(2.into())),
+            ("f()", SilverValue::Integer(20.into())),
+        ]);
+    }
+
+    #[test]
+    fn calls_forget_checks_of_variables_that_closures_assign() {
+        let int = SilverType::nullable(SilverType::Integer);
+        for text in [
+            "x: int? = 3  f = () => x = null  x != null && f() == null && x + 1 > 1",
+            "x: int? = 3  f = () => x = null  x != null && (f() == null || true) && x > 1",
+            "x: int? = 3  f = () => x = null  x != null && (f() ?? 0) + x > 1",
+            "x: int? = 3  f = () => x = null  fn run(g: fn(): int?) => g()
+                x != null && run(f) == null && x > 1",
+            "x: int? = 3  x != null && (true && (x = null) == null) == (x > 0)",
+        ] {
+            check_diagnostics(text, &[DiagnosticKind::PossiblyNull(int.clone())]);
+        }
+        // Calls keep the checks of variables that no closure assigns.
+        check(
+            "x: int? = 3  f = () => y = null  x != null && f() == null && x + 1 > 1",
+            &SilverValue::Boolean(true),
+        );
+
+        // The closure may come from an earlier submission.
+        let mut scope = GlobalScope::new();
+        for (text, had_error) in [
+            ("x: int? = 3  f = () => x = null", false),
+            ("x != null && f() == null && x + 1 > 1", true),
+        ] {
+            let mut error_reporter = StringErrorReporter::new();
+            let syntax_tree = SyntaxTree::parse_str(text, &mut error_reporter);
+            Compilation::new(&syntax_tree, &mut error_reporter).evaluate(&mut scope);
+            assert_eq!(had_error, error_reporter.had_error(), "{}", text);
+        }
+    }
+
+    #[test]
+    fn reports_bad_closures() {
+        check_diagnostics("5(1)", &[DiagnosticKind::NotAFunction(SilverType::Integer)]);
+        check_diagnostics(
+            "f = (x: int) => x f(1, 2)",
+            &[DiagnosticKind::WrongArgumentCount {
+                name: "f".to_string(),
+                expected: 1,
+                actual: 2,
+            }],
+        );
+        check_diagnostics(
+            "(x) => x",
+            &[DiagnosticKind::CannotInferParameterType {
+                name: "x".to_string(),
+            }],
+        );
+        check_diagnostics(
+            "f = (x: int) => x f(true)",
+            &[DiagnosticKind::CannotConvert {
+                from: SilverType::Boolean,
+                to: SilverType::Integer,
+            }],
+        );
+        check_diagnostics(
+            "fn id<T>(x: T) => x f = id",
+            &[DiagnosticKind::CannotInferTypeArgument {
+                name: "id".to_string(),
+                parameter: "T".to_string(),
+            }],
+        );
+        // A narrowed variable may be null again by the time the lambda runs.
+        let int = SilverType::nullable(SilverType::Integer);
+        check_diagnostics(
+            "x = [1, null][0] x != null && (() => x + 1)() == 2",
+            &[DiagnosticKind::PossiblyNull(int)],
+        );
+    }
+
+    #[test]
+    fn evaluates_arrays() {
+        for (text, value) in [
+            ("[1, 2, 3]", "[1, 2, 3]"),
+            ("[1i8, 300]", "[1, 300]"),
+            ("[1u8, 2i16][0]", "1"),
+            ("[1, 2, 3][1]", "2"),
+            ("[[1], [2, 3]][1][0]", "2"),
+            ("[true][0u64]", "true"),
+            ("len([1, 2, 3])", "3"),
+            ("len([[1], [2, 3]][1])", "2"),
+        ] {
+            let mut error_reporter = StringErrorReporter::new();
+            let syntax_tree = SyntaxTree::parse_str(text, &mut error_reporter);
+            let mut compilation = Compilation::new(&syntax_tree, &mut error_reporter);
+            let result = compilation.evaluate(&mut GlobalScope::new());
+            assert_eq!(value, result.unwrap().to_string(), "{}", text);
+        }
+    }
+
+    #[test]
+    fn element_assignment_is_shared_between_variables() {
+        let mut scope = GlobalScope::new();
+        for (text, value) in [
+            ("a = [1, 2, 3]", "[1, 2, 3]"),
+            ("a[0] = 10", "10"),
+            ("b = a", "[10, 2, 3]"),
+            ("b[1] = 20i8", "20"),
+            ("a", "[10, 20, 3]"),
+        ] {
+            let mut error_reporter = StringErrorReporter::new();
+            let syntax_tree = SyntaxTree::parse_str(text, &mut error_reporter);
+            let mut compilation = Compilation::new(&syntax_tree, &mut error_reporter);
+            let result = compilation.evaluate(&mut scope);
+            assert_eq!(value, result.unwrap().to_string(), "{}", text);
+        }
+    }
+
+    #[test]
+    fn reports_bad_arrays() {
+        check_diagnostics("[]", &[DiagnosticKind::CannotInferEmptyArrayType]);
+        check_diagnostics(
+            "[1, true]",
+            &[DiagnosticKind::CannotConvert {
+                from: SilverType::Boolean,
+                to: SilverType::Integer,
+            }],
+        );
+        check_diagnostics(
+            "[1, 1.5]",
+            &[DiagnosticKind::CannotConvertImplicitly {
+                from: SilverType::Float,
+                to: SilverType::Integer,
+            }],
+        );
+        check_diagnostics("1[0]", &[DiagnosticKind::NotAnArray(SilverType::Integer)]);
+        check_diagnostics(
+            "len(true)",
+            &[DiagnosticKind::NotAnArray(SilverType::Boolean)],
+        );
+        check_diagnostics(
+            "[1][true]",
+            &[DiagnosticKind::CannotConvert {
+                from: SilverType::Boolean,
+                to: SilverType::Integer,
+            }],
+        );
+        check_diagnostics(
+            "[1i8][0] = 1.5",
+            &[DiagnosticKind::CannotConvertImplicitly {
+                from: SilverType::Float,
+                to: SilverType::Int8,
+            }],
+        );
+    }
+
+    #[test]
+    fn evaluates_strings() {
+        check_program(r#""a" + "b" == "ab""#, "true");
+        check_program(r#""tab\tquote\" brace\{""#, "tab\tquote\" brace{");
+        check_program(
+            "x = 5 y = true z = false $\"x = {x}, ok = {y && z}\"",
+            "x = 5, ok = false",
+        );
+        check_program(
+            r#"$"{ $"in {1 + 1}" } and {"q}"} {[1.5]}""#,
+            "in 2 and q} [1.5]",
+        );
+        check_program(r#"$"{null} \{ {string(3)}""#, "null { 3");
+        check_program(r#"$"" == """#, "true");
+        check_program(
+            r#"struct P { x: int } p = P { x: 1 } $"{p} {P { x: 2 }.x}""#,
+            "P { x: 1 } 2",
+        );
+        check_program(r#"fn show(a: int) => $"<{a}>" show(3) + show(4)"#, "<3><4>");
+        check_program("1 as string", "1");
+    }
+
+    #[test]
+    fn reports_errors_inside_interpolation_holes() {
+        for (text, span, kind) in [
+            (
+                r#"$"a {b} c""#,
+                5..6,
+                DiagnosticKind::UndefinedName {
+                    name: "b".to_string(),
+                },
+            ),
+            (
+                r#"$"{1} {true + 1}""#,
+                12..13,
+                DiagnosticKind::UndefinedBinaryOperator {
+                    operator_kind: SyntaxKind::PlusToken,
+                    left_type: SilverType::Boolean,
+                    right_type: SilverType::Integer,
+                },
+            ),
+            (
+                r#"$"{ $"{ "x" + 1 }" }""#,
+                14..15,
+                DiagnosticKind::CannotConvertImplicitly {
+                    from: SilverType::Integer,
+                    to: SilverType::String,
+                },
+            ),
+            (r#"$"abc {1}"#, 0..2, DiagnosticKind::UnterminatedString),
+        ] {
+            let mut error_reporter = StringErrorReporter::new();
+            let syntax_tree = SyntaxTree::parse_str(text, &mut error_reporter);
+            let mut compilation = Compilation::new(&syntax_tree, &mut error_reporter);
+            compilation.evaluate(&mut GlobalScope::new());
+            assert_eq!(1, error_reporter.errors().len(), "{}", text);
+            let error = &error_reporter.errors()[0];
+            assert_eq!(&kind, error.kind(), "{}", text);
+            assert_eq!(span, error.span(), "{}", text);
+        }
+    }
+
+    #[test]
+    fn evaluates_compound_assignments() {
+        check_program("x = 5 x += 2 x *= 3 x -= 1 x /= 4 x %= 3 x", "2");
+        check_program("x = 1.5 x *= 2.0 x", "3.0");
+        check_program(r#"s = "a" s += "b" s"#, "ab");
+        check_program(
+            "b = true b &&= false c = false c ||= true $\"{b} {c}\"",
+            "false true",
+        );
+        check_program("x = 1 y = x += 1 $\"{x} {y}\"", "2 2");
+        check_program("x = 1 y = 2 x += y += 3 $\"{x} {y}\"", "6 5");
+        check_program("a = [1, 2] a[1] += 10 a", "[1, 12]");
+        check_program("struct P { x: int } p = P { x: 2 } p.x *= 7 p.x", "14");
+        check_program("x: i8 = 1 as i8 x += 1 as i8 x", "2");
+        // The array and the index are evaluated only once.
+        check_program(
+            "n = 0 next = () => n++ a = [1, 2, 3] a[next()] += 5 a[next()]++ $\"{a} {n}\"",
+            "[6, 3, 3] 2",
+        );
+    }
+
+    #[test]
+    fn evaluates_increments_and_decrements() {
+        check_program("x = 1 y = x++ $\"{x} {y}\"", "2 1");
+        check_program("x = 1 y = ++x $\"{x} {y}\"", "2 2");
+        check_program("x = 1 y = x-- $\"{x} {y}\"", "0 1");
+        check_program("x = 1 y = --x $\"{x} {y}\"", "0 0");
+        check_program("x = 1 ++x * 2", "4");
+        check_program("x = 1.5 x++ x", "2.5");
+        // The increment keeps the type of the variable.
+        check_program("x = 255u8 x-- x", "254");
+        check_program("a = [1] a[0]++ + a[0]", "3");
+        check_program("struct P { x: int } p = P { x: 2 } --p.x p", "P { x: 1 }");
+        check_program("inc = (x) => ++x inc(1)", "2");
+    }
+
+    #[test]
+    fn converts_integer_constants_to_types_they_fit() {
+        for (text, value) in [
+            ("x: i32 = 5 x", SilverValue::Int32(5)),
+            ("x: u8 = 1 x", SilverValue::UInt8(1)),
+            ("x: i8 = -128 x", SilverValue::Int8(-128)),
+            ("x: u8? = 255 x", SilverValue::UInt8(255)),
+            ("fn f(x: i32) => x f(5)", SilverValue::Int32(5)),
+            ("fn f(): u16 => 7 f()", SilverValue::UInt16(7)),
+            ("xs: [i32] = [1, 2] xs[1]", SilverValue::Int32(2)),
+            ("xs: [i64] = [1i8, -2] xs[1]", SilverValue::Int64(-2)),
+            ("xs: [u8?] = [1, null] xs[0]", SilverValue::UInt8(1)),
+            ("x = 1i8 x += 1 x", SilverValue::Int8(2)),
+            ("x = 1i32 x = x + 1 x", SilverValue::Int32(2)),
+            ("x = 1i32 x = 7 x", SilverValue::Int32(7)),
+            ("2 * 3u8", SilverValue::UInt8(6)),
+            ("-128i8", SilverValue::Int8(-128)),
+            ("-0x8000i16", SilverValue::Int16(i16::MIN)),
+            ("-9223372036854775808i64", SilverValue::Int64(i64::MIN)),
+        ] {
+            check(text, &value);
+        }
+        // Constants that do not fit still widen the other side, or fail to
+        // convert.
+        check("x = 1i8 x = x + 128 x", &SilverValue::Integer(129.into()));
+        check_diagnostics(
+            "x: u8 = 256",
+            &[DiagnosticKind::CannotConvertImplicitly {
+                from: SilverType::Integer,
+                to: SilverType::UInt8,
+            }],
+        );
+        check_diagnostics(
+            "x: u8 = -1",
+            &[DiagnosticKind::CannotConvertImplicitly {
+                from: SilverType::Integer,
+                to: SilverType::UInt8,
+            }],
+        );
+        for text in [
+            "128i8",
+            "-(128i8)",
+            "match 1i8 { 128i8 => 1, _ => 2 }",
+            "300i8",
+            "x = 1 + 300i8 * 2",
+            "match 1i8 { 300i8 => 1, _ => 2 }",
+        ] {
+            check_diagnostics(text, &[DiagnosticKind::NumberOutOfRange(SilverType::Int8)]);
+        }
+    }
+
+    #[test]
+    fn reports_invalid_compound_assignments() {
+        check_diagnostics("1 += 2", &[DiagnosticKind::NotAssignable]);
+        check_diagnostics("x = 1 (x + 1)++", &[DiagnosticKind::NotAssignable]);
+        check_diagnostics("fn f() => 1 f += 1", &[DiagnosticKind::NotAssignable]);
+        check_diagnostics(
+            "y += 1",
+            &[DiagnosticKind::UndefinedName {
+                name: "y".to_string(),
+            }],
+        );
+        check_diagnostics(
+            "b = true b += 1",
+            &[DiagnosticKind::UndefinedBinaryOperator {
+                operator_kind: SyntaxKind::PlusEqualsToken,
+                left_type: SilverType::Boolean,
+                right_type: SilverType::Integer,
+            }],
+        );
+        check_diagnostics(
+            "b = true b++",
+            &[DiagnosticKind::UndefinedUnaryOperator {
+                operator_kind: SyntaxKind::PlusPlusToken,
+                operand_type: SilverType::Boolean,
+            }],
+        );
+        check_diagnostics(
+            "x = 1 as i8 x += 1000",
+            &[DiagnosticKind::CannotConvertImplicitly {
+                from: SilverType::Integer,
+                to: SilverType::Int8,
+            }],
+        );
+        check_diagnostics(
+            "x: int? = 1 x++",
+            &[DiagnosticKind::PossiblyNull(SilverType::nullable(
+                SilverType::Integer,
+            ))],
+        );
+    }
+
+    #[test]
+    fn reports_index_out_of_bounds_at_the_index() {
+        for (text, span) in [("[1, 2][2]", 7..8), ("[1, 2][-1] = 3", 7..9)] {
+            let mut error_reporter = StringErrorReporter::new();
+            let syntax_tree = SyntaxTree::parse_str(text, &mut error_reporter);
+            let mut compilation = Compilation::new(&syntax_tree, &mut error_reporter);
+            let result = compilation.evaluate(&mut GlobalScope::new());
+            assert!(result.is_none(), "{}", text);
+            assert_eq!(1, error_reporter.errors().len(), "{}", text);
+            let error = &error_reporter.errors()[0];
+            assert_eq!(
+                &DiagnosticKind::IndexOutOfBounds { length: 2 },
+                error.kind()
+            );
+            assert_eq!(span, error.span(), "{}", text);
+        }
+    }
+
+    #[test]
+    fn reports_division_by_zero_at_the_operator() {
+        for (text, span) in [
+            ("10 / 0", 3..4),
+            ("10 % 0", 3..4),
+            ("1i8 / 0i8", 4..5),
+            ("x = 5 x %= 0", 8..10),
+            ("x = 5 x /= 0", 8..10),
+            ("fn f(x: int) => 1 % x f(0)", 22..26),
+        ] {
+            let mut error_reporter = StringErrorReporter::new();
+            let syntax_tree = SyntaxTree::parse_str(text, &mut error_reporter);
+            let mut compilation = Compilation::new(&syntax_tree, &mut error_reporter);
+            let result = compilation.evaluate(&mut GlobalScope::new());
+            assert!(result.is_none(), "{}", text);
+            assert_eq!(1, error_reporter.errors().len(), "{}", text);
+            let error = &error_reporter.errors()[0];
+            assert_eq!(&DiagnosticKind::DivisionByZero, error.kind(), "{}", text);
+            assert_eq!(span, error.span(), "{}", text);
+        }
+        check("1.0 / 0.0 > 1e300", &SilverValue::Boolean(true));
+    }
 }
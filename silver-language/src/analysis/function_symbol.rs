@@ -0,0 +1,48 @@
+use super::{silver_type::SilverType, variable_symbol::VariableSymbol};
+
+/// A function declared by a program. Like a variable, a function is told
+/// apart by its signature as well as its name, so that redeclaring it with
+/// other types leaves calls bound to the old declaration intact.
+///
+/// The parameter and return types of a generic function may use its type
+/// parameters, which each call gives types for.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct FunctionSymbol {
+    name: String,
+    type_parameters: Vec<String>,
+    parameters: Vec<VariableSymbol>,
+    ty: SilverType,
+}
+
+impl FunctionSymbol {
+    pub(crate) fn new(
+        name: String,
+        type_parameters: Vec<String>,
+        parameters: Vec<VariableSymbol>,
+        ty: SilverType,
+    ) -> Self {
+        Self {
+            name,
+            type_parameters,
+            parameters,
+            ty,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn type_parameters(&self) -> &[String] {
+        &self.type_parameters
+    }
+
+    pub fn parameters(&self) -> &[VariableSymbol] {
+        &self.parameters
+    }
+
+    /// The return type.
+    pub fn ty(&self) -> &SilverType {
+        &self.ty
+    }
+}
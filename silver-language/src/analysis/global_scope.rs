@@ -0,0 +1,47 @@
+use std::{
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
+
+use super::{
+    binding::bound_expression::BoundExpression, evaluator::Variables,
+    function_symbol::FunctionSymbol, silver_type::SilverType, silver_value::SilverValue,
+    variable_symbol::VariableSymbol,
+};
+
+/// The variables, types and functions that outlive a single submission.
+#[derive(Default)]
+pub struct GlobalScope {
+    pub(crate) variables: Variables,
+    pub(crate) types: HashMap<String, SilverType>,
+    pub(crate) functions: HashMap<String, FunctionSymbol>,
+    /// The body of every function declared so far, including those since
+    /// redeclared with another signature, which earlier callers still call.
+    pub(crate) function_bodies: HashMap<FunctionSymbol, Rc<BoundExpression>>,
+    /// The names of the variables that some lambda assigns, which any call
+    /// may have made null again.
+    pub(crate) assigned_by_lambdas: HashSet<String>,
+}
+
+impl GlobalScope {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The variables assigned so far, with their values.
+    pub fn variables(&self) -> impl Iterator<Item = (&VariableSymbol, SilverValue)> {
+        self.variables
+            .iter()
+            .map(|(variable, value)| (variable, value.borrow().clone()))
+    }
+
+    /// The struct and enum types declared so far.
+    pub fn types(&self) -> impl Iterator<Item = &SilverType> {
+        self.types.values()
+    }
+
+    /// The functions declared so far.
+    pub fn functions(&self) -> impl Iterator<Item = &FunctionSymbol> {
+        self.functions.values()
+    }
+}
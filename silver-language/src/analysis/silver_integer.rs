@@ -0,0 +1,126 @@
+//! The representation of Silver's `i128` type.
+//!
+//! By default this is a machine `i128` whose arithmetic wraps on overflow.
+//! With the `bigint` feature it is an arbitrary-precision integer instead, so
+//! literals may be any length and arithmetic never overflows.
+
+pub use imp::SilverInteger;
+pub(crate) use imp::*;
+
+#[cfg(not(feature = "bigint"))]
+mod imp {
+    pub type SilverInteger = i128;
+
+    pub(crate) fn parse(digits: &str, radix: u32) -> Option<SilverInteger> {
+        i128::from_str_radix(digits, radix).ok()
+    }
+
+    pub(crate) fn from_i128(value: i128) -> SilverInteger {
+        value
+    }
+
+    pub(crate) fn to_i128(value: &SilverInteger) -> Option<i128> {
+        Some(*value)
+    }
+
+    pub(crate) fn to_i128_wrapping(value: &SilverInteger) -> i128 {
+        *value
+    }
+
+    /// Rounds toward zero, saturating at the bounds and sending NaN to zero.
+    pub(crate) fn from_f64(value: f64) -> SilverInteger {
+        value as i128
+    }
+
+    pub(crate) fn to_f64(value: &SilverInteger) -> f64 {
+        *value as f64
+    }
+
+    pub(crate) fn add(left: &SilverInteger, right: &SilverInteger) -> SilverInteger {
+        left.wrapping_add(*right)
+    }
+
+    pub(crate) fn sub(left: &SilverInteger, right: &SilverInteger) -> SilverInteger {
+        left.wrapping_sub(*right)
+    }
+
+    pub(crate) fn mul(left: &SilverInteger, right: &SilverInteger) -> SilverInteger {
+        left.wrapping_mul(*right)
+    }
+
+    /// `right` must not be zero, which the evaluator reports instead.
+    pub(crate) fn div(left: &SilverInteger, right: &SilverInteger) -> SilverInteger {
+        left.wrapping_div(*right)
+    }
+
+    /// `right` must not be zero, which the evaluator reports instead.
+    pub(crate) fn rem(left: &SilverInteger, right: &SilverInteger) -> SilverInteger {
+        left.wrapping_rem(*right)
+    }
+
+    pub(crate) fn neg(value: &SilverInteger) -> SilverInteger {
+        value.wrapping_neg()
+    }
+}
+
+#[cfg(feature = "bigint")]
+mod imp {
+    use num_bigint::BigInt;
+    use num_traits::{FromPrimitive, ToPrimitive, Zero};
+
+    pub type SilverInteger = BigInt;
+
+    pub(crate) fn parse(digits: &str, radix: u32) -> Option<SilverInteger> {
+        BigInt::parse_bytes(digits.as_bytes(), radix)
+    }
+
+    pub(crate) fn from_i128(value: i128) -> SilverInteger {
+        BigInt::from(value)
+    }
+
+    pub(crate) fn to_i128(value: &SilverInteger) -> Option<i128> {
+        value.to_i128()
+    }
+
+    /// The low 128 bits, read as two's complement.
+    pub(crate) fn to_i128_wrapping(value: &SilverInteger) -> i128 {
+        let low_bits = value & BigInt::from(u128::MAX);
+        low_bits.to_u128().unwrap() as i128
+    }
+
+    /// Rounds toward zero, sending NaN and the infinities to zero.
+    pub(crate) fn from_f64(value: f64) -> SilverInteger {
+        BigInt::from_f64(value.trunc()).unwrap_or_else(BigInt::zero)
+    }
+
+    pub(crate) fn to_f64(value: &SilverInteger) -> f64 {
+        value.to_f64().unwrap()
+    }
+
+    pub(crate) fn add(left: &SilverInteger, right: &SilverInteger) -> SilverInteger {
+        left + right
+    }
+
+    pub(crate) fn sub(left: &SilverInteger, right: &SilverInteger) -> SilverInteger {
+        left - right
+    }
+
+    pub(crate) fn mul(left: &SilverInteger, right: &SilverInteger) -> SilverInteger {
+        left * right
+    }
+
+    /// `right` must not be zero, which the evaluator reports instead.
+    pub(crate) fn div(left: &SilverInteger, right: &SilverInteger) -> SilverInteger {
+        left / right
+    }
+
+    /// Takes the sign of `left`, as the remainder of `i128` does. `right`
+    /// must not be zero.
+    pub(crate) fn rem(left: &SilverInteger, right: &SilverInteger) -> SilverInteger {
+        left % right
+    }
+
+    pub(crate) fn neg(value: &SilverInteger) -> SilverInteger {
+        -value
+    }
+}
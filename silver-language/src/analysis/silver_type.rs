@@ -1,8 +1,298 @@
-use derive_more::Display;
+use std::{fmt::Display, rc::Rc};
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Display)]
+use super::{enum_symbol::EnumSymbol, type_symbol::TypeSymbol};
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub enum SilverType {
+    /// The type of an expression that failed to bind. Diagnostics about it
+    /// are suppressed, since the failure has already been reported.
+    Error,
+    /// The type of `null`, which converts implicitly to every nullable type.
     Null,
+    Int8,
+    Int16,
+    Int32,
+    Int64,
     Integer,
+    UInt8,
+    UInt16,
+    UInt32,
+    UInt64,
+    Float,
     Boolean,
+    /// Immutable text, written `string`. Every value converts explicitly to
+    /// it.
+    String,
+    /// A sequence of values of the element type, written `[T]`.
+    Array(Box<SilverType>),
+    Struct(Rc<TypeSymbol>),
+    Enum(Rc<EnumSymbol>),
+    /// The values of the inner type or null, written `T?`. The inner type is
+    /// never itself nullable.
+    Nullable(Box<SilverType>),
+    /// Functions taking values of the parameter types and returning a value
+    /// of the last type, written `fn(T, U): R`.
+    Function(Vec<SilverType>, Box<SilverType>),
+    /// A type the binder is still inferring for an unannotated parameter,
+    /// which never outlives the binding of the function.
+    Variable(usize),
+    /// A type parameter of a generic declaration, which stands for whatever
+    /// type each use supplies. Nothing but itself converts to it.
+    Parameter(String),
+    /// A struct or enum named inside its own declaration, with its type
+    /// arguments, as in `enum List { Cons(int, List), Nil }`. The symbol
+    /// holds it in place of itself, and gives itself for it wherever a
+    /// field or payload type is taken.
+    Recursive(String, Vec<SilverType>),
+}
+
+impl SilverType {
+    /// The built-in type a program refers to by `name`, such as `i32`, or
+    /// `int` and `float`, which are aliases for `i128` and `f64`.
+    pub fn lookup(name: &str) -> Option<Self> {
+        let ty = match name {
+            "i8" => SilverType::Int8,
+            "i16" => SilverType::Int16,
+            "i32" => SilverType::Int32,
+            "i64" => SilverType::Int64,
+            "i128" | "int" => SilverType::Integer,
+            "u8" => SilverType::UInt8,
+            "u16" => SilverType::UInt16,
+            "u32" => SilverType::UInt32,
+            "u64" => SilverType::UInt64,
+            "f64" | "float" => SilverType::Float,
+            "bool" => SilverType::Boolean,
+            "string" => SilverType::String,
+            _ => return None,
+        };
+        Some(ty)
+    }
+
+    /// `ty?`, which is `ty` itself if it is already nullable.
+    pub fn nullable(ty: SilverType) -> Self {
+        match ty {
+            SilverType::Nullable(_) | SilverType::Null | SilverType::Error => ty,
+            ty => SilverType::Nullable(Box::new(ty)),
+        }
+    }
+
+    /// The type without null: the inner type of a nullable type, or the type
+    /// itself.
+    pub fn non_null(&self) -> &SilverType {
+        match self {
+            SilverType::Nullable(inner) => inner,
+            ty => ty,
+        }
+    }
+
+    /// Whether this is the type of an expression or type that already
+    /// failed to bind. Its error has been reported, so anything that uses it
+    /// is not reported again.
+    pub fn is_error(&self) -> bool {
+        *self == SilverType::Error
+    }
+
+    /// Whether null is a value of this type.
+    pub fn is_nullable(&self) -> bool {
+        matches!(self, SilverType::Nullable(_) | SilverType::Null)
+    }
+
+    /// The name a program uses for a built-in scalar type.
+    pub fn name(&self) -> Option<&'static str> {
+        let name = match self {
+            SilverType::Error => "?",
+            SilverType::Null => "null",
+            SilverType::Int8 => "i8",
+            SilverType::Int16 => "i16",
+            SilverType::Int32 => "i32",
+            SilverType::Int64 => "i64",
+            SilverType::Integer => "i128",
+            SilverType::UInt8 => "u8",
+            SilverType::UInt16 => "u16",
+            SilverType::UInt32 => "u32",
+            SilverType::UInt64 => "u64",
+            SilverType::Float => "f64",
+            SilverType::Boolean => "bool",
+            SilverType::String => "string",
+            SilverType::Array(_)
+            | SilverType::Struct(_)
+            | SilverType::Enum(_)
+            | SilverType::Nullable(_)
+            | SilverType::Function(..)
+            | SilverType::Variable(_)
+            | SilverType::Parameter(_)
+            | SilverType::Recursive(..) => return None,
+        };
+        Some(name)
+    }
+
+    /// The name a struct or enum type was declared with, without its type
+    /// arguments.
+    pub fn declared_name(&self) -> Option<&str> {
+        match self {
+            SilverType::Struct(symbol) => Some(symbol.name()),
+            SilverType::Enum(symbol) => Some(symbol.name()),
+            _ => None,
+        }
+    }
+
+    /// This type with each type parameter named in `arguments` replaced by
+    /// the type given for it.
+    pub fn substitute(&self, arguments: &[(String, SilverType)]) -> SilverType {
+        match self {
+            SilverType::Parameter(name) => arguments
+                .iter()
+                .find(|(parameter, _)| parameter == name)
+                .map_or_else(|| self.clone(), |(_, ty)| ty.clone()),
+            ty => ty.map_inner(&|ty| ty.substitute(arguments)),
+        }
+    }
+
+    /// This type with each reference to the declaration `name` from inside
+    /// it replaced by the type that `ty` gives for its type arguments.
+    pub(crate) fn unfold(&self, name: &str, ty: &dyn Fn(&[SilverType]) -> SilverType) -> Self {
+        match self {
+            SilverType::Recursive(other, type_arguments) if other == name => ty(type_arguments),
+            other => other.map_inner(&|inner| inner.unfold(name, ty)),
+        }
+    }
+
+    /// This type with `map` applied to each type it is made of. Only generic
+    /// structs and enums are looked into, as the types of the others cannot
+    /// mention a type parameter or the declaration being bound.
+    fn map_inner(&self, map: &dyn Fn(&SilverType) -> SilverType) -> SilverType {
+        match self {
+            SilverType::Array(element_type) => SilverType::Array(Box::new(map(element_type))),
+            SilverType::Nullable(inner) => SilverType::nullable(map(inner)),
+            SilverType::Function(parameters, ty) => {
+                SilverType::Function(parameters.iter().map(map).collect(), Box::new(map(ty)))
+            }
+            SilverType::Struct(symbol) if !symbol.type_arguments().is_empty() => {
+                SilverType::Struct(Rc::new(symbol.map_types(map)))
+            }
+            SilverType::Enum(symbol) if !symbol.type_arguments().is_empty() => {
+                SilverType::Enum(Rc::new(symbol.map_types(map)))
+            }
+            SilverType::Recursive(name, type_arguments) => {
+                SilverType::Recursive(name.clone(), type_arguments.iter().map(map).collect())
+            }
+            ty => ty.clone(),
+        }
+    }
+
+    /// The element type of an array type.
+    pub fn element_type(&self) -> Option<&SilverType> {
+        match self {
+            SilverType::Array(element_type) => Some(element_type),
+            _ => None,
+        }
+    }
+
+    /// Every integer type, narrowest first.
+    pub fn integer_types() -> &'static [SilverType] {
+        &[
+            SilverType::Int8,
+            SilverType::UInt8,
+            SilverType::Int16,
+            SilverType::UInt16,
+            SilverType::Int32,
+            SilverType::UInt32,
+            SilverType::Int64,
+            SilverType::UInt64,
+            SilverType::Integer,
+        ]
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.integer_bits().is_some()
+    }
+
+    pub fn is_signed_integer(&self) -> bool {
+        matches!(
+            self,
+            SilverType::Int8
+                | SilverType::Int16
+                | SilverType::Int32
+                | SilverType::Int64
+                | SilverType::Integer
+        )
+    }
+
+    pub fn is_numeric(&self) -> bool {
+        self.is_integer() || *self == SilverType::Float
+    }
+
+    /// The width of an integer type, or `None` if this is not one.
+    pub fn integer_bits(&self) -> Option<u32> {
+        match self {
+            SilverType::Int8 | SilverType::UInt8 => Some(8),
+            SilverType::Int16 | SilverType::UInt16 => Some(16),
+            SilverType::Int32 | SilverType::UInt32 => Some(32),
+            SilverType::Int64 | SilverType::UInt64 => Some(64),
+            SilverType::Integer => Some(128),
+            _ => None,
+        }
+    }
+}
+
+impl Display for SilverType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SilverType::Array(element_type) => write!(f, "[{}]", element_type),
+            SilverType::Struct(symbol) => {
+                f.write_str(symbol.name())?;
+                write_type_arguments(f, symbol.type_parameters(), symbol.type_arguments())
+            }
+            SilverType::Enum(symbol) => {
+                f.write_str(symbol.name())?;
+                write_type_arguments(f, symbol.type_parameters(), symbol.type_arguments())
+            }
+            SilverType::Nullable(inner) => write!(f, "{}?", inner),
+            SilverType::Function(parameters, ty) => {
+                f.write_str("fn(")?;
+                for (i, parameter) in parameters.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{}", parameter)?;
+                }
+                write!(f, "): {}", ty)
+            }
+            SilverType::Variable(_) => f.write_str("_"),
+            SilverType::Parameter(name) => f.write_str(name),
+            SilverType::Recursive(name, type_arguments) if type_arguments.is_empty() => {
+                f.write_str(name)
+            }
+            SilverType::Recursive(name, type_arguments) => {
+                let type_arguments: Vec<_> =
+                    type_arguments.iter().map(|ty| ty.to_string()).collect();
+                write!(f, "{}<{}>", name, type_arguments.join(", "))
+            }
+            ty => f.write_str(ty.name().unwrap()),
+        }
+    }
+}
+
+/// Write `<A, B>` after the name of a generic type: its type arguments, or
+/// its type parameters if it is the declaration itself.
+fn write_type_arguments(
+    f: &mut std::fmt::Formatter<'_>,
+    type_parameters: &[String],
+    type_arguments: &[SilverType],
+) -> std::fmt::Result {
+    if type_parameters.is_empty() {
+        return Ok(());
+    }
+    f.write_str("<")?;
+    if type_arguments.is_empty() {
+        f.write_str(&type_parameters.join(", "))?;
+    } else {
+        for (i, ty) in type_arguments.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{}", ty)?;
+        }
+    }
+    f.write_str(">")
 }
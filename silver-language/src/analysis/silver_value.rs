@@ -1,17 +1,164 @@
-use std::fmt::Display;
+use std::{cell::RefCell, convert::TryFrom, fmt::Display, rc::Rc};
 
-use super::silver_type::SilverType;
+use super::{
+    binding::bound_expression::BoundExpression,
+    enum_symbol::EnumSymbol,
+    silver_integer::{self, SilverInteger},
+    silver_type::SilverType,
+    type_symbol::TypeSymbol,
+    variable_symbol::VariableSymbol,
+};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum SilverValue {
-    Integer(i128),
+    /// The absence of a value, held only by nullable types. A nullable value
+    /// that is not null is held as the value of the inner type.
+    Null,
+    Int8(i8),
+    Int16(i16),
+    Int32(i32),
+    Int64(i64),
+    Integer(SilverInteger),
+    UInt8(u8),
+    UInt16(u16),
+    UInt32(u32),
+    UInt64(u64),
+    /// An IEEE 754 double. Equality follows IEEE rules, so `NaN` is unequal
+    /// to everything including itself, and `0.0` equals `-0.0`.
+    Float(f64),
     Boolean(bool),
+    /// Strings are immutable, so they are shared by value.
+    String(Rc<str>),
+    /// Arrays are shared by reference, so assigning an element through one
+    /// variable is visible through every other that holds the same array.
+    Array {
+        element_type: SilverType,
+        elements: Rc<RefCell<Vec<SilverValue>>>,
+    },
+    /// Structs are shared by reference, like arrays. `fields` follows the
+    /// declaration order of the type's fields.
+    Struct {
+        ty: Rc<TypeSymbol>,
+        fields: Rc<RefCell<Vec<SilverValue>>>,
+    },
+    /// A variant of an enum, by its index in `ty`, with its payload. Enum
+    /// values are immutable, so they are shared by value.
+    Enum {
+        ty: Rc<EnumSymbol>,
+        variant: usize,
+        payload: Vec<SilverValue>,
+    },
+    /// A function value: a lambda with the variables it captured, or a
+    /// declared function.
+    Function(Rc<Closure>),
+}
+
+/// A function body with the variables it closes over. The variables are
+/// captured by reference, so the closure sees later assignments to them
+/// and its own assignments are seen outside.
+pub struct Closure {
+    pub(crate) ty: SilverType,
+    pub(crate) parameters: Vec<VariableSymbol>,
+    pub(crate) body: Rc<BoundExpression>,
+    pub(crate) captures: Vec<(VariableSymbol, Rc<RefCell<SilverValue>>)>,
+}
+
+/// Closures are equal only to themselves.
+impl PartialEq for Closure {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
+// A captured variable may hold the closure itself, so the captures are left
+// out.
+impl std::fmt::Debug for Closure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Closure")
+            .field("ty", &self.ty)
+            .field("parameters", &self.parameters)
+            .finish_non_exhaustive()
+    }
 }
 
 impl SilverValue {
+    /// `value` as the integer type `ty`, or `None` if it is out of range.
+    pub fn integer(value: i128, ty: &SilverType) -> Option<Self> {
+        let value = match ty {
+            SilverType::Int8 => SilverValue::Int8(i8::try_from(value).ok()?),
+            SilverType::Int16 => SilverValue::Int16(i16::try_from(value).ok()?),
+            SilverType::Int32 => SilverValue::Int32(i32::try_from(value).ok()?),
+            SilverType::Int64 => SilverValue::Int64(i64::try_from(value).ok()?),
+            SilverType::Integer => SilverValue::Integer(silver_integer::from_i128(value)),
+            SilverType::UInt8 => SilverValue::UInt8(u8::try_from(value).ok()?),
+            SilverType::UInt16 => SilverValue::UInt16(u16::try_from(value).ok()?),
+            SilverType::UInt32 => SilverValue::UInt32(u32::try_from(value).ok()?),
+            SilverType::UInt64 => SilverValue::UInt64(u64::try_from(value).ok()?),
+            _ => return None,
+        };
+        Some(value)
+    }
+
+    /// `value` truncated to the width of the integer type `ty`, as the
+    /// two's complement `as` cast does.
+    pub(crate) fn integer_wrapping(value: i128, ty: &SilverType) -> Self {
+        match ty {
+            SilverType::Int8 => SilverValue::Int8(value as i8),
+            SilverType::Int16 => SilverValue::Int16(value as i16),
+            SilverType::Int32 => SilverValue::Int32(value as i32),
+            SilverType::Int64 => SilverValue::Int64(value as i64),
+            SilverType::UInt8 => SilverValue::UInt8(value as u8),
+            SilverType::UInt16 => SilverValue::UInt16(value as u16),
+            SilverType::UInt32 => SilverValue::UInt32(value as u32),
+            SilverType::UInt64 => SilverValue::UInt64(value as u64),
+            _ => SilverValue::Integer(silver_integer::from_i128(value)),
+        }
+    }
+
+    /// `value` rounded toward zero into the integer type `ty`, saturating at
+    /// its bounds and sending NaN to zero.
+    pub(crate) fn integer_saturating(value: f64, ty: &SilverType) -> Self {
+        match ty {
+            SilverType::Int8 => SilverValue::Int8(value as i8),
+            SilverType::Int16 => SilverValue::Int16(value as i16),
+            SilverType::Int32 => SilverValue::Int32(value as i32),
+            SilverType::Int64 => SilverValue::Int64(value as i64),
+            SilverType::UInt8 => SilverValue::UInt8(value as u8),
+            SilverType::UInt16 => SilverValue::UInt16(value as u16),
+            SilverType::UInt32 => SilverValue::UInt32(value as u32),
+            SilverType::UInt64 => SilverValue::UInt64(value as u64),
+            _ => SilverValue::Integer(silver_integer::from_f64(value)),
+        }
+    }
+
+    /// The value of any integer type, widened to `i128`. This is `None` for
+    /// an arbitrary-precision integer that does not fit.
     pub fn as_integer(&self) -> Option<i128> {
         match self {
-            SilverValue::Integer(i) => Some(*i),
+            SilverValue::Integer(i) => silver_integer::to_i128(i),
+            value => value.as_integer_wrapping(),
+        }
+    }
+
+    /// The low 128 bits of the value of any integer type.
+    pub(crate) fn as_integer_wrapping(&self) -> Option<i128> {
+        match self {
+            SilverValue::Int8(i) => Some(i128::from(*i)),
+            SilverValue::Int16(i) => Some(i128::from(*i)),
+            SilverValue::Int32(i) => Some(i128::from(*i)),
+            SilverValue::Int64(i) => Some(i128::from(*i)),
+            SilverValue::Integer(i) => Some(silver_integer::to_i128_wrapping(i)),
+            SilverValue::UInt8(i) => Some(i128::from(*i)),
+            SilverValue::UInt16(i) => Some(i128::from(*i)),
+            SilverValue::UInt32(i) => Some(i128::from(*i)),
+            SilverValue::UInt64(i) => Some(i128::from(*i)),
+            _ => None,
+        }
+    }
+
+    pub fn as_float(&self) -> Option<f64> {
+        match self {
+            SilverValue::Float(f) => Some(*f),
             _ => None,
         }
     }
@@ -25,8 +172,25 @@ impl SilverValue {
 
     pub fn ty(&self) -> SilverType {
         match self {
+            SilverValue::Null => SilverType::Null,
+            SilverValue::Int8(_) => SilverType::Int8,
+            SilverValue::Int16(_) => SilverType::Int16,
+            SilverValue::Int32(_) => SilverType::Int32,
+            SilverValue::Int64(_) => SilverType::Int64,
             SilverValue::Integer(_) => SilverType::Integer,
+            SilverValue::UInt8(_) => SilverType::UInt8,
+            SilverValue::UInt16(_) => SilverType::UInt16,
+            SilverValue::UInt32(_) => SilverType::UInt32,
+            SilverValue::UInt64(_) => SilverType::UInt64,
+            SilverValue::Float(_) => SilverType::Float,
             SilverValue::Boolean(_) => SilverType::Boolean,
+            SilverValue::String(_) => SilverType::String,
+            SilverValue::Array { element_type, .. } => {
+                SilverType::Array(Box::new(element_type.clone()))
+            }
+            SilverValue::Struct { ty, .. } => SilverType::Struct(ty.clone()),
+            SilverValue::Enum { ty, .. } => SilverType::Enum(ty.clone()),
+            SilverValue::Function(closure) => closure.ty.clone(),
         }
     }
 }
@@ -34,8 +198,54 @@ impl SilverValue {
 impl Display for SilverValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            SilverValue::Integer(i) => write!(f, "{}", i),
+            // Debug formatting keeps the decimal point on whole numbers.
+            SilverValue::Float(x) => write!(f, "{:?}", x),
+            SilverValue::Null => f.write_str("null"),
             SilverValue::Boolean(b) => write!(f, "{}", b),
+            SilverValue::String(s) => f.write_str(s),
+            SilverValue::Integer(i) => write!(f, "{}", i),
+            SilverValue::Array { elements, .. } => {
+                write!(f, "[")?;
+                for (i, element) in elements.borrow().iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", element)?;
+                }
+                write!(f, "]")
+            }
+            SilverValue::Struct { ty, fields } => {
+                write!(f, "{} {{", ty.name())?;
+                for (i, (field, value)) in
+                    ty.fields().iter().zip(fields.borrow().iter()).enumerate()
+                {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, " {}: {}", field.name(), value)?;
+                }
+                write!(f, " }}")
+            }
+            SilverValue::Enum {
+                ty,
+                variant,
+                payload,
+            } => {
+                write!(f, "{}.{}", ty.name(), ty.variants()[*variant].name())?;
+                if !payload.is_empty() {
+                    write!(f, "(")?;
+                    for (i, value) in payload.iter().enumerate() {
+                        if i > 0 {
+                            write!(f, ", ")?;
+                        }
+                        write!(f, "{}", value)?;
+                    }
+                    write!(f, ")")?;
+                }
+                Ok(())
+            }
+            SilverValue::Function(closure) => write!(f, "<{}>", closure.ty),
+            integer => write!(f, "{}", integer.as_integer().unwrap()),
         }
     }
 }
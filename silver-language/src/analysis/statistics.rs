@@ -0,0 +1,45 @@
+use std::time::Duration;
+
+/// Measurements of compiling and running a submission, for performance work.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Statistics {
+    pub(crate) lexing_time: Duration,
+    pub(crate) parsing_time: Duration,
+    pub(crate) binding_time: Duration,
+    pub(crate) evaluation_time: Duration,
+    pub(crate) evaluated_nodes: u64,
+    pub(crate) peak_variables: usize,
+}
+
+impl Statistics {
+    pub fn lexing_time(&self) -> Duration {
+        self.lexing_time
+    }
+
+    /// The time spent parsing the tokens, apart from lexing them.
+    pub fn parsing_time(&self) -> Duration {
+        self.parsing_time
+    }
+
+    pub fn binding_time(&self) -> Duration {
+        self.binding_time
+    }
+
+    /// The time spent running the bound program, which is zero if binding
+    /// it reported errors.
+    pub fn evaluation_time(&self) -> Duration {
+        self.evaluation_time
+    }
+
+    /// How many bound expressions were evaluated, counting an expression
+    /// again each time it runs, as in every call of a function.
+    pub fn evaluated_nodes(&self) -> u64 {
+        self.evaluated_nodes
+    }
+
+    /// The most variables held at once: the global variables together with
+    /// the parameters and locals of the calls and match arms being evaluated.
+    pub fn peak_variables(&self) -> usize {
+        self.peak_variables
+    }
+}
@@ -1,8 +1,14 @@
+pub mod classification;
+pub(crate) mod compilation_unit_syntax;
 pub(crate) mod expression_syntax;
 pub mod lexer;
+pub(crate) mod member_syntax;
 mod parser;
-mod syntax_facts;
+pub(crate) mod pattern_syntax;
+pub(crate) mod separated_syntax_list;
+pub(crate) mod syntax_facts;
 pub mod syntax_kind;
 pub(crate) mod syntax_node;
 pub mod syntax_token;
 pub mod syntax_tree;
+pub(crate) mod type_syntax;
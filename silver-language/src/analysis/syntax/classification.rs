@@ -0,0 +1,206 @@
+use std::sync::Arc;
+
+use crate::analysis::{
+    errors::null_error_reporter::NullErrorReporter, text::source_text::SourceText,
+    text::text_span::TextSpan,
+};
+
+use super::{syntax_kind::SyntaxKind, syntax_token::SyntaxToken, syntax_tree::SyntaxTree};
+
+/// What a piece of source text is, for an editor to highlight it by.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Classification {
+    Number,
+    /// A string literal, or the text and quotes of an interpolated string.
+    String,
+    Keyword,
+    Identifier,
+    /// An operator or punctuation, including matched brackets.
+    Operator,
+    /// A parenthesis, bracket or brace with no partner to close or open it.
+    UnmatchedBracket,
+    /// Text the lexer could not read as any token.
+    BadToken,
+    /// Whitespace.
+    Trivia,
+}
+
+/// A span of source text and how it is classified.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ClassifiedSpan {
+    span: TextSpan,
+    classification: Classification,
+}
+
+impl ClassifiedSpan {
+    pub fn span(&self) -> TextSpan {
+        self.span.clone()
+    }
+
+    pub fn classification(&self) -> Classification {
+        self.classification
+    }
+}
+
+/// Classify all of `text` by lexing it, which is cheap enough to do on every
+/// keystroke and works on incomplete input. The spans are in order and cover
+/// the text.
+pub fn classify(text: &str) -> Vec<ClassifiedSpan> {
+    let tokens = SyntaxTree::parse_tokens(
+        Arc::new(SourceText::from(text.to_string())),
+        &mut NullErrorReporter::new(),
+    );
+    let mut spans = vec![];
+    // The brackets still open, with the index of their spans.
+    let mut open = vec![];
+    let mut position = 0;
+    for token in tokens.iter().filter(|token| !token.span().is_empty()) {
+        // A character the lexer could not read leaves a gap before the
+        // next token.
+        if token.span().start > position {
+            spans.push(ClassifiedSpan {
+                span: position..token.span().start,
+                classification: Classification::BadToken,
+            });
+        }
+        position = token.span().end;
+        let index = spans.len();
+        spans.push(ClassifiedSpan {
+            span: token.span(),
+            classification: classify_token(token),
+        });
+        match closing_bracket(token.kind()) {
+            Some(closing) => open.push((closing, index)),
+            None if is_closing_bracket(token.kind()) => match open.last() {
+                Some(&(closing, _)) if closing == token.kind() => {
+                    open.pop();
+                }
+                _ => spans[index].classification = Classification::UnmatchedBracket,
+            },
+            None => {}
+        }
+    }
+    for (_, index) in open {
+        spans[index].classification = Classification::UnmatchedBracket;
+    }
+    if text.len() > position {
+        spans.push(ClassifiedSpan {
+            span: position..text.len(),
+            classification: Classification::BadToken,
+        });
+    }
+    spans
+}
+
+fn classify_token(token: &SyntaxToken) -> Classification {
+    match token.kind() {
+        SyntaxKind::NumberToken => Classification::Number,
+        SyntaxKind::StringToken
+        | SyntaxKind::InterpolatedStringStartToken
+        | SyntaxKind::InterpolatedStringTextToken
+        | SyntaxKind::InterpolatedStringEndToken => Classification::String,
+        SyntaxKind::IdentifierToken => Classification::Identifier,
+        SyntaxKind::WhitespaceToken => Classification::Trivia,
+        SyntaxKind::BadToken => Classification::BadToken,
+        kind if kind.to_string().ends_with("Keyword") => Classification::Keyword,
+        _ => Classification::Operator,
+    }
+}
+
+/// The kind of bracket that closes `kind`, if it opens one.
+fn closing_bracket(kind: SyntaxKind) -> Option<SyntaxKind> {
+    match kind {
+        SyntaxKind::OpenParenthesisToken => Some(SyntaxKind::CloseParenthesisToken),
+        SyntaxKind::OpenBracketToken => Some(SyntaxKind::CloseBracketToken),
+        SyntaxKind::OpenBraceToken => Some(SyntaxKind::CloseBraceToken),
+        _ => None,
+    }
+}
+
+fn is_closing_bracket(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::CloseParenthesisToken
+            | SyntaxKind::CloseBracketToken
+            | SyntaxKind::CloseBraceToken
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn check(text: &str, expected: &[(&str, Classification)]) {
+        let spans = classify(text);
+        let actual: Vec<_> = spans
+            .iter()
+            .map(|span| (&text[span.span()], span.classification()))
+            .collect();
+        assert_eq!(expected, actual.as_slice(), "{}", text);
+    }
+
+    #[test]
+    fn classifies_tokens() {
+        check(
+            "x = 1i8 as int + f(true) #",
+            &[
+                ("x", Classification::Identifier),
+                (" ", Classification::Trivia),
+                ("=", Classification::Operator),
+                (" ", Classification::Trivia),
+                ("1i8", Classification::Number),
+                (" ", Classification::Trivia),
+                ("as", Classification::Keyword),
+                (" ", Classification::Trivia),
+                ("int", Classification::Identifier),
+                (" ", Classification::Trivia),
+                ("+", Classification::Operator),
+                (" ", Classification::Trivia),
+                ("f", Classification::Identifier),
+                ("(", Classification::Operator),
+                ("true", Classification::Keyword),
+                (")", Classification::Operator),
+                (" ", Classification::Trivia),
+                ("#", Classification::BadToken),
+            ],
+        );
+        check(
+            "1 ¤ 2",
+            &[
+                ("1", Classification::Number),
+                (" ", Classification::Trivia),
+                ("¤", Classification::BadToken),
+                (" ", Classification::Trivia),
+                ("2", Classification::Number),
+            ],
+        );
+        check(
+            r#"$"a{"b"}""#,
+            &[
+                ("$\"", Classification::String),
+                ("a", Classification::String),
+                ("{", Classification::Operator),
+                ("\"b\"", Classification::String),
+                ("}", Classification::Operator),
+                ("\"", Classification::String),
+            ],
+        );
+    }
+
+    #[test]
+    fn classifies_unmatched_brackets() {
+        let unmatched = |text: &str| -> Vec<String> {
+            classify(text)
+                .into_iter()
+                .filter(|span| span.classification() == Classification::UnmatchedBracket)
+                .map(|span| format!("{}@{}", &text[span.span()], span.span().start))
+                .collect()
+        };
+        assert!(unmatched("f([1], { x: (2) })").is_empty());
+        assert_eq!(["(@0"], unmatched("((1)").as_slice());
+        assert_eq!([")@3"], unmatched("(1))").as_slice());
+        assert_eq!(["(@0", "]@2"], unmatched("(1]").as_slice());
+        assert_eq!(["[@0", ")@2"], unmatched("[1)").as_slice());
+    }
+}
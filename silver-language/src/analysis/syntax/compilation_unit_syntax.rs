@@ -0,0 +1,44 @@
+use crate::analysis::{silver_value::SilverValue, text::text_span::TextSpan};
+
+use super::{
+    member_syntax::MemberSyntax, syntax_kind::SyntaxKind, syntax_node::SyntaxNodeExt,
+    syntax_token::SyntaxToken,
+};
+
+/// The root of a syntax tree: the declarations and expressions of one
+/// submission, in source order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompilationUnitSyntax {
+    pub(crate) members: Vec<MemberSyntax>,
+    pub(crate) end_of_file_token: SyntaxToken,
+}
+
+impl SyntaxNodeExt for CompilationUnitSyntax {
+    fn kind(&self) -> SyntaxKind {
+        SyntaxKind::CompilationUnit
+    }
+
+    fn children(&self) -> Vec<&dyn SyntaxNodeExt> {
+        self.members
+            .iter()
+            .map(|m| m as &dyn SyntaxNodeExt)
+            .collect()
+    }
+
+    fn value(&self) -> Option<&SilverValue> {
+        None
+    }
+
+    fn text(&self) -> Option<&str> {
+        None
+    }
+
+    fn span(&self) -> TextSpan {
+        let start = self
+            .members
+            .first()
+            .map(|m| m.span().start)
+            .unwrap_or_else(|| self.end_of_file_token.span().start);
+        start..self.end_of_file_token.span().end
+    }
+}
@@ -1,6 +1,14 @@
 use crate::analysis::{silver_value::SilverValue, text::text_span::TextSpan};
 
-use super::{syntax_kind::SyntaxKind, syntax_node::SyntaxNodeExt, syntax_token::SyntaxToken};
+use super::{
+    member_syntax::ParameterSyntax,
+    pattern_syntax::PatternSyntax,
+    separated_syntax_list::SeparatedSyntaxList,
+    syntax_kind::SyntaxKind,
+    syntax_node::SyntaxNodeExt,
+    syntax_token::SyntaxToken,
+    type_syntax::{TypeArgumentListSyntax, TypeClauseSyntax, TypeSyntax},
+};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum ExpressionSyntax {
@@ -25,11 +33,244 @@ pub enum ExpressionSyntax {
     Name {
         identifier_token: SyntaxToken,
     },
+    /// A generic type with its type arguments, as in `Option<int>.None`;
+    /// the parser only reads one before a `.`.
+    GenericName {
+        identifier_token: SyntaxToken,
+        type_argument_list: TypeArgumentListSyntax,
+    },
+    /// `name = value`, or `name: type = value` to declare the variable
+    /// with a type other than the value's.
     Assignment {
         identifier_token: SyntaxToken,
+        type_clause: Option<TypeClauseSyntax>,
+        equals_token: SyntaxToken,
+        expression: Box<ExpressionSyntax>,
+    },
+    /// `target op= value`, which assigns `target op value` to the target.
+    /// The binder checks that the target can be assigned to.
+    CompoundAssignment {
+        target: Box<ExpressionSyntax>,
+        operator_token: SyntaxToken,
+        expression: Box<ExpressionSyntax>,
+    },
+    /// `++target` or `--target`, whose value is the updated one.
+    PrefixUpdate {
+        operator_token: SyntaxToken,
+        operand: Box<ExpressionSyntax>,
+    },
+    /// `target++` or `target--`, whose value is the one from before the
+    /// update.
+    PostfixUpdate {
+        operand: Box<ExpressionSyntax>,
+        operator_token: SyntaxToken,
+    },
+    Call {
+        identifier_token: SyntaxToken,
+        type_argument_list: Option<TypeArgumentListSyntax>,
+        open_parenthesis_token: SyntaxToken,
+        arguments: SeparatedSyntaxList<ExpressionSyntax>,
+        close_parenthesis_token: SyntaxToken,
+    },
+    Array {
+        open_bracket_token: SyntaxToken,
+        elements: SeparatedSyntaxList<ExpressionSyntax>,
+        close_bracket_token: SyntaxToken,
+    },
+    Index {
+        expression: Box<ExpressionSyntax>,
+        open_bracket_token: SyntaxToken,
+        index: Box<ExpressionSyntax>,
+        close_bracket_token: SyntaxToken,
+    },
+    /// An assignment to an array element; `target` is always an `Index`.
+    ElementAssignment {
+        target: Box<ExpressionSyntax>,
+        equals_token: SyntaxToken,
+        expression: Box<ExpressionSyntax>,
+    },
+    StructConstruction {
+        identifier_token: SyntaxToken,
+        type_argument_list: Option<TypeArgumentListSyntax>,
+        open_brace_token: SyntaxToken,
+        fields: SeparatedSyntaxList<FieldInitializerSyntax>,
+        close_brace_token: SyntaxToken,
+    },
+    FieldAccess {
+        expression: Box<ExpressionSyntax>,
+        dot_token: SyntaxToken,
+        identifier_token: SyntaxToken,
+    },
+    /// An assignment to a struct field; `target` is always a `FieldAccess`.
+    FieldAssignment {
+        target: Box<ExpressionSyntax>,
         equals_token: SyntaxToken,
         expression: Box<ExpressionSyntax>,
     },
+    /// A call through a member, such as the variant construction
+    /// `Shape.Circle(1.0)`.
+    MemberCall {
+        expression: Box<ExpressionSyntax>,
+        dot_token: SyntaxToken,
+        identifier_token: SyntaxToken,
+        open_parenthesis_token: SyntaxToken,
+        arguments: SeparatedSyntaxList<ExpressionSyntax>,
+        close_parenthesis_token: SyntaxToken,
+    },
+    Match {
+        match_keyword: SyntaxToken,
+        expression: Box<ExpressionSyntax>,
+        open_brace_token: SyntaxToken,
+        arms: SeparatedSyntaxList<MatchArmSyntax>,
+        close_brace_token: SyntaxToken,
+    },
+    Cast {
+        expression: Box<ExpressionSyntax>,
+        as_keyword: SyntaxToken,
+        type_syntax: TypeSyntax,
+    },
+    /// `(parameters) => body`, a function value that closes over the
+    /// variables it uses.
+    Lambda {
+        open_parenthesis_token: SyntaxToken,
+        parameters: SeparatedSyntaxList<ParameterSyntax>,
+        close_parenthesis_token: SyntaxToken,
+        equals_greater_token: SyntaxToken,
+        body: Box<ExpressionSyntax>,
+    },
+    /// A call through a function value, as in `make()(1)`.
+    Invocation {
+        expression: Box<ExpressionSyntax>,
+        open_parenthesis_token: SyntaxToken,
+        arguments: SeparatedSyntaxList<ExpressionSyntax>,
+        close_parenthesis_token: SyntaxToken,
+    },
+    /// `$"text {expression} text"`, which joins the text with the string
+    /// form of each hole's value.
+    InterpolatedString {
+        start_token: SyntaxToken,
+        parts: Vec<InterpolatedStringPartSyntax>,
+        end_token: SyntaxToken,
+    },
+}
+
+/// A run of text or a `{expression}` hole of an interpolated string.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InterpolatedStringPartSyntax {
+    Text {
+        text_token: SyntaxToken,
+    },
+    Interpolation {
+        open_brace_token: SyntaxToken,
+        expression: Box<ExpressionSyntax>,
+        close_brace_token: SyntaxToken,
+    },
+}
+
+// A run of text stands in the tree as its token.
+impl SyntaxNodeExt for InterpolatedStringPartSyntax {
+    fn kind(&self) -> SyntaxKind {
+        match self {
+            InterpolatedStringPartSyntax::Text { text_token } => text_token.kind(),
+            InterpolatedStringPartSyntax::Interpolation { .. } => SyntaxKind::Interpolation,
+        }
+    }
+
+    fn children(&self) -> Vec<&dyn SyntaxNodeExt> {
+        match self {
+            InterpolatedStringPartSyntax::Text { text_token } => text_token.children(),
+            InterpolatedStringPartSyntax::Interpolation {
+                open_brace_token,
+                expression,
+                close_brace_token,
+            } => vec![open_brace_token, expression.as_ref(), close_brace_token],
+        }
+    }
+
+    fn value(&self) -> Option<&SilverValue> {
+        match self {
+            InterpolatedStringPartSyntax::Text { text_token } => text_token.value(),
+            InterpolatedStringPartSyntax::Interpolation { .. } => None,
+        }
+    }
+
+    fn text(&self) -> Option<&str> {
+        match self {
+            InterpolatedStringPartSyntax::Text { text_token } => Some(text_token.text()),
+            InterpolatedStringPartSyntax::Interpolation { .. } => None,
+        }
+    }
+
+    fn span(&self) -> TextSpan {
+        match self {
+            InterpolatedStringPartSyntax::Text { text_token } => text_token.span(),
+            InterpolatedStringPartSyntax::Interpolation {
+                open_brace_token,
+                close_brace_token,
+                ..
+            } => open_brace_token.span().start..close_brace_token.span().end,
+        }
+    }
+}
+
+/// A `name: value` field of a struct construction.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldInitializerSyntax {
+    pub(crate) identifier_token: SyntaxToken,
+    pub(crate) colon_token: SyntaxToken,
+    pub(crate) expression: ExpressionSyntax,
+}
+
+impl SyntaxNodeExt for FieldInitializerSyntax {
+    fn kind(&self) -> SyntaxKind {
+        SyntaxKind::FieldInitializer
+    }
+
+    fn children(&self) -> Vec<&dyn SyntaxNodeExt> {
+        vec![&self.identifier_token, &self.colon_token, &self.expression]
+    }
+
+    fn value(&self) -> Option<&SilverValue> {
+        None
+    }
+
+    fn text(&self) -> Option<&str> {
+        None
+    }
+
+    fn span(&self) -> TextSpan {
+        self.identifier_token.span().start..self.expression.span().end
+    }
+}
+
+/// A `pattern => expression` arm of a match.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchArmSyntax {
+    pub(crate) pattern: PatternSyntax,
+    pub(crate) equals_greater_token: SyntaxToken,
+    pub(crate) expression: ExpressionSyntax,
+}
+
+impl SyntaxNodeExt for MatchArmSyntax {
+    fn kind(&self) -> SyntaxKind {
+        SyntaxKind::MatchArm
+    }
+
+    fn children(&self) -> Vec<&dyn SyntaxNodeExt> {
+        vec![&self.pattern, &self.equals_greater_token, &self.expression]
+    }
+
+    fn value(&self) -> Option<&SilverValue> {
+        None
+    }
+
+    fn text(&self) -> Option<&str> {
+        None
+    }
+
+    fn span(&self) -> TextSpan {
+        self.pattern.span().start..self.expression.span().end
+    }
 }
 
 impl SyntaxNodeExt for ExpressionSyntax {
@@ -40,7 +281,24 @@ impl SyntaxNodeExt for ExpressionSyntax {
             ExpressionSyntax::Unary { .. } => SyntaxKind::UnaryExpression,
             ExpressionSyntax::Parenthesized { .. } => SyntaxKind::ParenthesizedExpression,
             ExpressionSyntax::Name { .. } => SyntaxKind::NameExpression,
+            ExpressionSyntax::GenericName { .. } => SyntaxKind::GenericNameExpression,
             ExpressionSyntax::Assignment { .. } => SyntaxKind::AssignmentExpression,
+            ExpressionSyntax::CompoundAssignment { .. } => SyntaxKind::CompoundAssignmentExpression,
+            ExpressionSyntax::PrefixUpdate { .. } => SyntaxKind::PrefixUpdateExpression,
+            ExpressionSyntax::PostfixUpdate { .. } => SyntaxKind::PostfixUpdateExpression,
+            ExpressionSyntax::Call { .. } => SyntaxKind::CallExpression,
+            ExpressionSyntax::Array { .. } => SyntaxKind::ArrayExpression,
+            ExpressionSyntax::Index { .. } => SyntaxKind::IndexExpression,
+            ExpressionSyntax::ElementAssignment { .. } => SyntaxKind::ElementAssignmentExpression,
+            ExpressionSyntax::StructConstruction { .. } => SyntaxKind::StructConstructionExpression,
+            ExpressionSyntax::FieldAccess { .. } => SyntaxKind::FieldAccessExpression,
+            ExpressionSyntax::FieldAssignment { .. } => SyntaxKind::FieldAssignmentExpression,
+            ExpressionSyntax::MemberCall { .. } => SyntaxKind::MemberCallExpression,
+            ExpressionSyntax::Match { .. } => SyntaxKind::MatchExpression,
+            ExpressionSyntax::Cast { .. } => SyntaxKind::CastExpression,
+            ExpressionSyntax::Lambda { .. } => SyntaxKind::LambdaExpression,
+            ExpressionSyntax::Invocation { .. } => SyntaxKind::InvocationExpression,
+            ExpressionSyntax::InterpolatedString { .. } => SyntaxKind::InterpolatedStringExpression,
         }
     }
     fn children(&self) -> Vec<&dyn SyntaxNodeExt> {
@@ -62,11 +320,177 @@ impl SyntaxNodeExt for ExpressionSyntax {
                 close_parenthesis_token,
             ],
             ExpressionSyntax::Name { identifier_token } => vec![identifier_token],
+            ExpressionSyntax::GenericName {
+                identifier_token,
+                type_argument_list,
+            } => vec![identifier_token, type_argument_list],
             ExpressionSyntax::Assignment {
                 identifier_token,
+                type_clause,
+                equals_token,
+                expression,
+            } => {
+                let mut children: Vec<&dyn SyntaxNodeExt> = vec![identifier_token];
+                if let Some(type_clause) = type_clause {
+                    children.push(type_clause);
+                }
+                children.push(equals_token);
+                children.push(expression.as_ref());
+                children
+            }
+            ExpressionSyntax::CompoundAssignment {
+                target,
+                operator_token,
+                expression,
+            } => vec![target.as_ref(), operator_token, expression.as_ref()],
+            ExpressionSyntax::PrefixUpdate {
+                operator_token,
+                operand,
+            } => vec![operator_token, operand.as_ref()],
+            ExpressionSyntax::PostfixUpdate {
+                operand,
+                operator_token,
+            } => vec![operand.as_ref(), operator_token],
+            ExpressionSyntax::Call {
+                identifier_token,
+                type_argument_list,
+                open_parenthesis_token,
+                arguments,
+                close_parenthesis_token,
+            } => {
+                let mut children: Vec<&dyn SyntaxNodeExt> = vec![identifier_token];
+                if let Some(type_argument_list) = type_argument_list {
+                    children.push(type_argument_list);
+                }
+                children.push(open_parenthesis_token);
+                children.extend(arguments.children());
+                children.push(close_parenthesis_token);
+                children
+            }
+            ExpressionSyntax::Array {
+                open_bracket_token,
+                elements,
+                close_bracket_token,
+            } => {
+                let mut children: Vec<&dyn SyntaxNodeExt> = vec![open_bracket_token];
+                children.extend(elements.children());
+                children.push(close_bracket_token);
+                children
+            }
+            ExpressionSyntax::Index {
+                expression,
+                open_bracket_token,
+                index,
+                close_bracket_token,
+            } => vec![
+                expression.as_ref(),
+                open_bracket_token,
+                index.as_ref(),
+                close_bracket_token,
+            ],
+            ExpressionSyntax::ElementAssignment {
+                target,
+                equals_token,
+                expression,
+            } => vec![target.as_ref(), equals_token, expression.as_ref()],
+            ExpressionSyntax::StructConstruction {
+                identifier_token,
+                type_argument_list,
+                open_brace_token,
+                fields,
+                close_brace_token,
+            } => {
+                let mut children: Vec<&dyn SyntaxNodeExt> = vec![identifier_token];
+                if let Some(type_argument_list) = type_argument_list {
+                    children.push(type_argument_list);
+                }
+                children.push(open_brace_token);
+                children.extend(fields.children());
+                children.push(close_brace_token);
+                children
+            }
+            ExpressionSyntax::FieldAccess {
+                expression,
+                dot_token,
+                identifier_token,
+            } => vec![expression.as_ref(), dot_token, identifier_token],
+            ExpressionSyntax::FieldAssignment {
+                target,
                 equals_token,
                 expression,
-            } => vec![identifier_token, equals_token, expression.as_ref()],
+            } => vec![target.as_ref(), equals_token, expression.as_ref()],
+            ExpressionSyntax::MemberCall {
+                expression,
+                dot_token,
+                identifier_token,
+                open_parenthesis_token,
+                arguments,
+                close_parenthesis_token,
+            } => {
+                let mut children: Vec<&dyn SyntaxNodeExt> = vec![
+                    expression.as_ref(),
+                    dot_token,
+                    identifier_token,
+                    open_parenthesis_token,
+                ];
+                children.extend(arguments.children());
+                children.push(close_parenthesis_token);
+                children
+            }
+            ExpressionSyntax::Match {
+                match_keyword,
+                expression,
+                open_brace_token,
+                arms,
+                close_brace_token,
+            } => {
+                let mut children: Vec<&dyn SyntaxNodeExt> =
+                    vec![match_keyword, expression.as_ref(), open_brace_token];
+                children.extend(arms.children());
+                children.push(close_brace_token);
+                children
+            }
+            ExpressionSyntax::Cast {
+                expression,
+                as_keyword,
+                type_syntax,
+            } => vec![expression.as_ref(), as_keyword, type_syntax],
+            ExpressionSyntax::Lambda {
+                open_parenthesis_token,
+                parameters,
+                close_parenthesis_token,
+                equals_greater_token,
+                body,
+            } => {
+                let mut children: Vec<&dyn SyntaxNodeExt> = vec![open_parenthesis_token];
+                children.extend(parameters.children());
+                children.push(close_parenthesis_token);
+                children.push(equals_greater_token);
+                children.push(body.as_ref());
+                children
+            }
+            ExpressionSyntax::Invocation {
+                expression,
+                open_parenthesis_token,
+                arguments,
+                close_parenthesis_token,
+            } => {
+                let mut children: Vec<&dyn SyntaxNodeExt> =
+                    vec![expression.as_ref(), open_parenthesis_token];
+                children.extend(arguments.children());
+                children.push(close_parenthesis_token);
+                children
+            }
+            ExpressionSyntax::InterpolatedString {
+                start_token,
+                parts,
+                end_token,
+            } => {
+                let mut children: Vec<&dyn SyntaxNodeExt> = vec![start_token];
+                children.extend(parts.iter().map(|part| part as &dyn SyntaxNodeExt));
+                children.push(end_token);
+                children
+            }
         }
     }
 
@@ -91,11 +515,87 @@ impl SyntaxNodeExt for ExpressionSyntax {
                 ..
             } => open_parenthesis_token.span().start..close_parenthesis_token.span().end,
             ExpressionSyntax::Name { identifier_token } => identifier_token.span(),
+            ExpressionSyntax::GenericName {
+                identifier_token,
+                type_argument_list,
+            } => identifier_token.span().start..type_argument_list.span().end,
             ExpressionSyntax::Assignment {
                 identifier_token,
                 expression,
                 ..
             } => identifier_token.span().start..expression.span().end,
+            ExpressionSyntax::CompoundAssignment {
+                target, expression, ..
+            } => target.span().start..expression.span().end,
+            ExpressionSyntax::PrefixUpdate {
+                operator_token,
+                operand,
+            } => operator_token.span().start..operand.span().end,
+            ExpressionSyntax::PostfixUpdate {
+                operand,
+                operator_token,
+            } => operand.span().start..operator_token.span().end,
+            ExpressionSyntax::Call {
+                identifier_token,
+                close_parenthesis_token,
+                ..
+            } => identifier_token.span().start..close_parenthesis_token.span().end,
+            ExpressionSyntax::Array {
+                open_bracket_token,
+                close_bracket_token,
+                ..
+            } => open_bracket_token.span().start..close_bracket_token.span().end,
+            ExpressionSyntax::Index {
+                expression,
+                close_bracket_token,
+                ..
+            } => expression.span().start..close_bracket_token.span().end,
+            ExpressionSyntax::ElementAssignment {
+                target, expression, ..
+            } => target.span().start..expression.span().end,
+            ExpressionSyntax::StructConstruction {
+                identifier_token,
+                close_brace_token,
+                ..
+            } => identifier_token.span().start..close_brace_token.span().end,
+            ExpressionSyntax::FieldAccess {
+                expression,
+                identifier_token,
+                ..
+            } => expression.span().start..identifier_token.span().end,
+            ExpressionSyntax::FieldAssignment {
+                target, expression, ..
+            } => target.span().start..expression.span().end,
+            ExpressionSyntax::MemberCall {
+                expression,
+                close_parenthesis_token,
+                ..
+            } => expression.span().start..close_parenthesis_token.span().end,
+            ExpressionSyntax::Match {
+                match_keyword,
+                close_brace_token,
+                ..
+            } => match_keyword.span().start..close_brace_token.span().end,
+            ExpressionSyntax::Cast {
+                expression,
+                type_syntax,
+                ..
+            } => expression.span().start..type_syntax.span().end,
+            ExpressionSyntax::Lambda {
+                open_parenthesis_token,
+                body,
+                ..
+            } => open_parenthesis_token.span().start..body.span().end,
+            ExpressionSyntax::Invocation {
+                expression,
+                close_parenthesis_token,
+                ..
+            } => expression.span().start..close_parenthesis_token.span().end,
+            ExpressionSyntax::InterpolatedString {
+                start_token,
+                end_token,
+                ..
+            } => start_token.span().start..end_token.span().end,
         }
     }
 }
@@ -1,13 +1,15 @@
 use std::{collections::VecDeque, iter::Peekable, sync::Arc};
 
-use crate::analysis::{errors::error_reporter::ErrorReporter, silver_value::SilverValue};
+use crate::analysis::{
+    errors::error_reporter::ErrorReporter, silver_integer, silver_value::SilverValue,
+};
 use crate::analysis::{silver_type::SilverType, text::source_text::SourceText};
 
 use super::{syntax_facts, syntax_kind::SyntaxKind, syntax_token::SyntaxToken};
 
 pub struct Lexer;
 
-impl<'source> Lexer {
+impl Lexer {
     pub fn get_tokens(
         text: Arc<SourceText>,
         error_reporter: &mut dyn ErrorReporter,
@@ -15,7 +17,15 @@ impl<'source> Lexer {
         let mut tokens = VecDeque::new();
         let mut iterator = text.char_indices().peekable();
 
-        while let Some(token) = Self::next_token(&text, &mut iterator, error_reporter) {
+        loop {
+            if Self::at_interpolated_string(&text, &mut iterator) {
+                Self::read_interpolated_string(&text, &mut iterator, error_reporter, &mut tokens);
+                continue;
+            }
+            let token = match Self::next_token(&text, &mut iterator, error_reporter) {
+                Some(token) => token,
+                None => break,
+            };
             let kind = token.kind();
             tokens.push_back(token);
             if kind == SyntaxKind::EndOfFileToken {
@@ -26,6 +36,184 @@ impl<'source> Lexer {
         tokens
     }
 
+    /// Whether the input continues with `$"`, the start of an interpolated
+    /// string.
+    fn at_interpolated_string(
+        text: &SourceText,
+        iterator: &mut Peekable<impl Iterator<Item = (usize, char)>>,
+    ) -> bool {
+        match iterator.peek() {
+            Some(&(pos, '$')) => text[pos..text.len()].starts_with("$\""),
+            _ => false,
+        }
+    }
+
+    /// Read an interpolated string as a start token, a text token for each
+    /// run of text, the tokens of each hole between its braces, and an end
+    /// token. Holes are lexed like any other code, so they may contain
+    /// braces, strings and interpolated strings of their own.
+    fn read_interpolated_string(
+        text: &SourceText,
+        iterator: &mut Peekable<impl Iterator<Item = (usize, char)>>,
+        error_reporter: &mut dyn ErrorReporter,
+        tokens: &mut VecDeque<SyntaxToken>,
+    ) {
+        let (start, _) = iterator.next().unwrap();
+        iterator.next();
+        tokens.push_back(SyntaxToken::new(
+            SyntaxKind::InterpolatedStringStartToken,
+            start,
+            "$\"".to_string(),
+            None,
+        ));
+        loop {
+            let text_start = Self::position(text, iterator);
+            let value = Self::read_string_contents(text, iterator, error_reporter, true);
+            let position = Self::position(text, iterator);
+            if position > text_start {
+                tokens.push_back(SyntaxToken::new(
+                    SyntaxKind::InterpolatedStringTextToken,
+                    text_start,
+                    text[text_start..position].to_string(),
+                    Some(SilverValue::String(value.into())),
+                ));
+            }
+            match iterator.peek() {
+                Some(&(pos, '"')) => {
+                    iterator.next();
+                    tokens.extend(Self::fixed_token(
+                        pos,
+                        SyntaxKind::InterpolatedStringEndToken,
+                        "\"",
+                    ));
+                    return;
+                }
+                Some(&(pos, '{')) => {
+                    iterator.next();
+                    tokens.extend(Self::fixed_token(pos, SyntaxKind::OpenBraceToken, "{"));
+                    Self::read_interpolation_hole(text, iterator, error_reporter, tokens);
+                }
+                _ => {
+                    // A hole may run over several lines, so only the start is
+                    // marked.
+                    error_reporter.report_unterminated_string(start..start + 2);
+                    // The end is supplied here, so that the parser does not
+                    // report it missing as well.
+                    tokens.extend(Self::fixed_token(
+                        position,
+                        SyntaxKind::InterpolatedStringEndToken,
+                        "",
+                    ));
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Read the tokens of a hole up to and including the `}` that closes it.
+    fn read_interpolation_hole(
+        text: &SourceText,
+        iterator: &mut Peekable<impl Iterator<Item = (usize, char)>>,
+        error_reporter: &mut dyn ErrorReporter,
+        tokens: &mut VecDeque<SyntaxToken>,
+    ) {
+        let mut depth = 0;
+        loop {
+            if Self::at_interpolated_string(text, iterator) {
+                Self::read_interpolated_string(text, iterator, error_reporter, tokens);
+                continue;
+            }
+            let token = match Self::next_token(text, iterator, error_reporter) {
+                Some(token) => token,
+                None => return,
+            };
+            match token.kind() {
+                // The string reports itself unterminated.
+                SyntaxKind::EndOfFileToken => return,
+                SyntaxKind::OpenBraceToken => depth += 1,
+                SyntaxKind::CloseBraceToken if depth == 0 => {
+                    tokens.push_back(token);
+                    return;
+                }
+                SyntaxKind::CloseBraceToken => depth -= 1,
+                _ => {}
+            }
+            tokens.push_back(token);
+        }
+    }
+
+    fn read_string_token(
+        text: &SourceText,
+        iterator: &mut Peekable<impl Iterator<Item = (usize, char)>>,
+        error_reporter: &mut dyn ErrorReporter,
+    ) -> SyntaxToken {
+        let (start, _) = iterator.next().unwrap();
+        let value = Self::read_string_contents(text, iterator, error_reporter, false);
+        if iterator.peek().map(|&(_, c)| c == '"').unwrap_or(false) {
+            iterator.next();
+        } else {
+            let position = Self::position(text, iterator);
+            error_reporter.report_unterminated_string(start..position);
+        }
+        let position = Self::position(text, iterator);
+        SyntaxToken::new(
+            SyntaxKind::StringToken,
+            start,
+            text[start..position].to_string(),
+            Some(SilverValue::String(value.into())),
+        )
+    }
+
+    /// Read the text of a string up to its closing quote, the end of the
+    /// line, or in an interpolated string the `{` of a hole, and return it
+    /// with its escape sequences replaced.
+    fn read_string_contents(
+        text: &SourceText,
+        iterator: &mut Peekable<impl Iterator<Item = (usize, char)>>,
+        error_reporter: &mut dyn ErrorReporter,
+        interpolated: bool,
+    ) -> String {
+        let mut value = String::new();
+        while let Some(&(pos, c)) = iterator.peek() {
+            match c {
+                '"' | '\r' | '\n' => break,
+                '{' if interpolated => break,
+                '\\' => {
+                    iterator.next();
+                    let escaped = match iterator.peek() {
+                        Some(&(_, c)) => c,
+                        None => break,
+                    };
+                    match syntax_facts::escaped_character(escaped) {
+                        Some(c) => value.push(c),
+                        None if escaped == '\r' || escaped == '\n' => continue,
+                        None => {
+                            let end = Self::position(text, iterator) + escaped.len_utf8();
+                            error_reporter.report_invalid_escape_sequence(pos..end, escaped);
+                        }
+                    }
+                    iterator.next();
+                }
+                c => {
+                    value.push(c);
+                    iterator.next();
+                }
+            }
+        }
+        value
+    }
+
+    /// The position of the next character, or the end of the text.
+    fn position(
+        text: &SourceText,
+        iterator: &mut Peekable<impl Iterator<Item = (usize, char)>>,
+    ) -> usize {
+        iterator
+            .peek()
+            .map(|&(i, _)| i)
+            .unwrap_or_else(|| text.len())
+    }
+
     fn next_token(
         text: &SourceText,
         iterator: &mut Peekable<impl Iterator<Item = (usize, char)>>,
@@ -39,24 +227,84 @@ impl<'source> Lexer {
             Some((_, c)) if c.is_whitespace() => {
                 return Self::read_whitespace_token(text, iterator);
             }
-            Some((_, c)) if c.is_alphabetic() => {
+            Some(&(_, c)) if syntax_facts::is_identifier_start(c) => {
                 return Self::read_identifier_or_keyword_token(text, iterator);
             }
+            Some((_, '"')) => {
+                return Some(Self::read_string_token(text, iterator, error_reporter));
+            }
             Some(&(pos, '+')) => {
                 iterator.next();
-                return Self::fixed_token(pos, SyntaxKind::PlusToken, "+");
+                if iterator.peek().map(|&(_, c)| c == '+').unwrap_or(false) {
+                    iterator.next();
+                    return Self::fixed_token(pos, SyntaxKind::PlusPlusToken, "++");
+                } else if iterator.peek().map(|&(_, c)| c == '=').unwrap_or(false) {
+                    iterator.next();
+                    return Self::fixed_token(pos, SyntaxKind::PlusEqualsToken, "+=");
+                } else {
+                    return Self::fixed_token(pos, SyntaxKind::PlusToken, "+");
+                }
             }
             Some(&(pos, '-')) => {
                 iterator.next();
-                return Self::fixed_token(pos, SyntaxKind::MinusToken, "-");
+                if iterator.peek().map(|&(_, c)| c == '-').unwrap_or(false) {
+                    iterator.next();
+                    return Self::fixed_token(pos, SyntaxKind::MinusMinusToken, "--");
+                } else if iterator.peek().map(|&(_, c)| c == '=').unwrap_or(false) {
+                    iterator.next();
+                    return Self::fixed_token(pos, SyntaxKind::MinusEqualsToken, "-=");
+                } else {
+                    return Self::fixed_token(pos, SyntaxKind::MinusToken, "-");
+                }
             }
             Some(&(pos, '*')) => {
                 iterator.next();
-                return Self::fixed_token(pos, SyntaxKind::StarToken, "*");
+                if iterator.peek().map(|&(_, c)| c == '=').unwrap_or(false) {
+                    iterator.next();
+                    return Self::fixed_token(pos, SyntaxKind::StarEqualsToken, "*=");
+                } else {
+                    return Self::fixed_token(pos, SyntaxKind::StarToken, "*");
+                }
             }
             Some(&(pos, '/')) => {
                 iterator.next();
-                return Self::fixed_token(pos, SyntaxKind::SlashToken, "/");
+                if iterator.peek().map(|&(_, c)| c == '=').unwrap_or(false) {
+                    iterator.next();
+                    return Self::fixed_token(pos, SyntaxKind::SlashEqualsToken, "/=");
+                } else {
+                    return Self::fixed_token(pos, SyntaxKind::SlashToken, "/");
+                }
+            }
+            Some(&(pos, '%')) => {
+                iterator.next();
+                if iterator.peek().map(|&(_, c)| c == '=').unwrap_or(false) {
+                    iterator.next();
+                    return Self::fixed_token(pos, SyntaxKind::PercentEqualsToken, "%=");
+                } else {
+                    return Self::fixed_token(pos, SyntaxKind::PercentToken, "%");
+                }
+            }
+            Some(&(pos, ',')) => {
+                iterator.next();
+                return Self::fixed_token(pos, SyntaxKind::CommaToken, ",");
+            }
+            Some(&(pos, '<')) => {
+                iterator.next();
+                if iterator.peek().map(|&(_, c)| c == '=').unwrap_or(false) {
+                    iterator.next();
+                    return Self::fixed_token(pos, SyntaxKind::LessEqualsToken, "<=");
+                } else {
+                    return Self::fixed_token(pos, SyntaxKind::LessToken, "<");
+                }
+            }
+            Some(&(pos, '>')) => {
+                iterator.next();
+                if iterator.peek().map(|&(_, c)| c == '=').unwrap_or(false) {
+                    iterator.next();
+                    return Self::fixed_token(pos, SyntaxKind::GreaterEqualsToken, ">=");
+                } else {
+                    return Self::fixed_token(pos, SyntaxKind::GreaterToken, ">");
+                }
             }
             Some(&(pos, '(')) => {
                 iterator.next();
@@ -66,6 +314,42 @@ impl<'source> Lexer {
                 iterator.next();
                 return Self::fixed_token(pos, SyntaxKind::CloseParenthesisToken, ")");
             }
+            Some(&(pos, '[')) => {
+                iterator.next();
+                return Self::fixed_token(pos, SyntaxKind::OpenBracketToken, "[");
+            }
+            Some(&(pos, ']')) => {
+                iterator.next();
+                return Self::fixed_token(pos, SyntaxKind::CloseBracketToken, "]");
+            }
+            Some(&(pos, '{')) => {
+                iterator.next();
+                return Self::fixed_token(pos, SyntaxKind::OpenBraceToken, "{");
+            }
+            Some(&(pos, '}')) => {
+                iterator.next();
+                return Self::fixed_token(pos, SyntaxKind::CloseBraceToken, "}");
+            }
+            Some(&(pos, ':')) => {
+                iterator.next();
+                return Self::fixed_token(pos, SyntaxKind::ColonToken, ":");
+            }
+            Some(&(pos, '.')) => {
+                iterator.next();
+                return Self::fixed_token(pos, SyntaxKind::DotToken, ".");
+            }
+            Some(&(pos, '?')) => {
+                iterator.next();
+                if iterator.peek().map(|&(_, c)| c == '?').unwrap_or(false) {
+                    iterator.next();
+                    return Self::fixed_token(pos, SyntaxKind::QuestionQuestionToken, "??");
+                } else if iterator.peek().map(|&(_, c)| c == '.').unwrap_or(false) {
+                    iterator.next();
+                    return Self::fixed_token(pos, SyntaxKind::QuestionDotToken, "?.");
+                } else {
+                    return Self::fixed_token(pos, SyntaxKind::QuestionToken, "?");
+                }
+            }
             Some(&(pos, '!')) => {
                 iterator.next();
                 if iterator.peek().map(|&(_, c)| c == '=').unwrap_or(false) {
@@ -79,6 +363,14 @@ impl<'source> Lexer {
                 iterator.next();
                 if iterator.peek().map(|&(_, c)| c == '&').unwrap_or(false) {
                     iterator.next();
+                    if iterator.peek().map(|&(_, c)| c == '=').unwrap_or(false) {
+                        iterator.next();
+                        return Self::fixed_token(
+                            pos,
+                            SyntaxKind::AmpersandAmpersandEqualsToken,
+                            "&&=",
+                        );
+                    }
                     return Self::fixed_token(pos, SyntaxKind::AmpersandAmpersandToken, "&&");
                 }
             }
@@ -86,6 +378,10 @@ impl<'source> Lexer {
                 iterator.next();
                 if iterator.peek().map(|&(_, c)| c == '|').unwrap_or(false) {
                     iterator.next();
+                    if iterator.peek().map(|&(_, c)| c == '=').unwrap_or(false) {
+                        iterator.next();
+                        return Self::fixed_token(pos, SyntaxKind::PipePipeEqualsToken, "||=");
+                    }
                     return Self::fixed_token(pos, SyntaxKind::PipePipeToken, "||");
                 }
             }
@@ -94,6 +390,9 @@ impl<'source> Lexer {
                 if iterator.peek().map(|&(_, c)| c == '=').unwrap_or(false) {
                     iterator.next();
                     return Self::fixed_token(pos, SyntaxKind::EqualsEqualsToken, "==");
+                } else if iterator.peek().map(|&(_, c)| c == '>').unwrap_or(false) {
+                    iterator.next();
+                    return Self::fixed_token(pos, SyntaxKind::EqualsGreaterToken, "=>");
                 } else {
                     return Self::fixed_token(pos, SyntaxKind::EqualsToken, "=");
                 }
@@ -126,27 +425,170 @@ impl<'source> Lexer {
         iterator: &mut Peekable<impl Iterator<Item = (usize, char)>>,
         error_reporter: &mut dyn ErrorReporter,
     ) -> SyntaxToken {
-        let (start, _) = iterator.next().unwrap();
-        while let Some((_, c)) = iterator.peek() {
-            if !c.is_ascii_digit() {
-                break;
-            }
+        let (start, _) = iterator.peek().cloned().unwrap();
+        let position = start + Self::number_length(&text[start..text.len()]);
+        while iterator.peek().map(|&(i, _)| i < position).unwrap_or(false) {
             iterator.next();
         }
-        let (position, _) = iterator
-            .peek()
-            .cloned()
-            .unwrap_or_else(|| (text.len(), '\0'));
         let text = &text[start..position];
-        let parsed = match text.parse() {
-            Ok(p) => p,
-            Err(_) => {
-                error_reporter.report_invalid_number(start..position, text, SilverType::Integer);
-                return SyntaxToken::new(SyntaxKind::BadToken, start, text.to_string(), None);
+        match Self::parse_number(start, text, error_reporter) {
+            Some(value) => SyntaxToken::new(
+                SyntaxKind::NumberToken,
+                start,
+                text.to_string(),
+                Some(value),
+            ),
+            None => SyntaxToken::new(SyntaxKind::BadToken, start, text.to_string(), None),
+        }
+    }
+
+    /// The length of the numeric literal at the start of `text`.
+    ///
+    /// This takes the whole word, so that bad digits and suffixes are
+    /// diagnosed as part of the literal rather than lexed as separate tokens.
+    /// Decimal literals may also contain a fraction and a signed exponent.
+    fn number_length(text: &str) -> usize {
+        let is_decimal =
+            !(text.starts_with("0x") || text.starts_with("0o") || text.starts_with("0b"));
+        let chars = text.char_indices().collect::<Vec<_>>();
+        let is_digit_at = |i: usize| chars.get(i).map(|&(_, c)| c.is_ascii_digit()) == Some(true);
+        let mut i = 0;
+        let mut only_digits = true;
+        while let Some(&(_, c)) = chars.get(i) {
+            if is_decimal && only_digits && c == '.' && is_digit_at(i + 1) {
+                only_digits = false;
+                i += 1;
+            } else if is_decimal
+                && (c == 'e' || c == 'E')
+                && matches!(chars.get(i + 1), Some((_, '+')) | Some((_, '-')))
+                && is_digit_at(i + 2)
+            {
+                only_digits = false;
+                i += 2;
+            } else if syntax_facts::is_identifier_continue(c) {
+                only_digits = only_digits && (c.is_ascii_digit() || c == '_');
+                i += 1;
+            } else {
+                break;
             }
+        }
+        chars.get(i).map(|&(p, _)| p).unwrap_or_else(|| text.len())
+    }
+
+    /// The length of the decimal number at the start of `text`, including
+    /// any fraction and exponent, and whether it had either of those.
+    fn decimal_length(text: &str) -> (usize, bool) {
+        let digits_from = |from: usize| {
+            text[from..]
+                .find(|c: char| !(c.is_ascii_digit() || c == '_'))
+                .map(|len| from + len)
+                .unwrap_or_else(|| text.len())
         };
-        let value = Some(SilverValue::Integer(parsed));
-        SyntaxToken::new(SyntaxKind::NumberToken, start, text.to_string(), value)
+        let mut length = digits_from(0);
+        let mut is_float = false;
+        if text[length..].starts_with('.') {
+            length = digits_from(length + 1);
+            is_float = true;
+        }
+        let exponent = text[length..]
+            .strip_prefix(|c| c == 'e' || c == 'E')
+            .map(|rest| rest.strip_prefix(|c| c == '+' || c == '-').unwrap_or(rest));
+        if let Some(rest) = exponent {
+            if rest.starts_with(|c: char| c.is_ascii_digit()) {
+                length = digits_from(text.len() - rest.len());
+                is_float = true;
+            }
+        }
+        (length, is_float)
+    }
+
+    /// Interpret a numeric literal: an optional `0x`, `0o` or `0b` prefix,
+    /// digits with optional `_` separators, a fraction and exponent if it is
+    /// decimal, and an optional type suffix.
+    fn parse_number(
+        start: usize,
+        text: &str,
+        error_reporter: &mut dyn ErrorReporter,
+    ) -> Option<SilverValue> {
+        let (radix, prefix_len) = match text.get(..2) {
+            Some("0x") => (16, 2),
+            Some("0o") => (8, 2),
+            Some("0b") => (2, 2),
+            _ => (10, 0),
+        };
+        let body = &text[prefix_len..];
+        let (digits_len, is_float) = if radix == 10 {
+            Self::decimal_length(body)
+        } else {
+            let digits_len = body
+                .find(|c: char| {
+                    !(c == '_' || radix == 16 && c.is_ascii_hexdigit() || c.is_ascii_digit())
+                })
+                .unwrap_or(body.len());
+            (digits_len, false)
+        };
+        let (digits, suffix) = body.split_at(digits_len);
+        let digits_start = start + prefix_len;
+
+        if let Some((i, digit)) = digits
+            .char_indices()
+            .find(|&(_, c)| radix != 10 && c != '_' && !c.is_digit(radix))
+        {
+            let position = digits_start + i;
+            error_reporter.report_invalid_digit(position..position + 1, digit, radix);
+            return None;
+        }
+        if digits.chars().all(|c| c == '_') {
+            error_reporter.report_missing_digits(start..start + text.len(), text);
+            return None;
+        }
+        if digits.ends_with('_') {
+            let position = digits_start + digits.len() - 1;
+            error_reporter.report_trailing_digit_separator(position..position + 1, text);
+            return None;
+        }
+        let ty = match syntax_facts::number_suffix_type(suffix) {
+            _ if suffix.is_empty() && is_float => SilverType::Float,
+            _ if suffix.is_empty() => SilverType::Integer,
+            Some(SilverType::Float) => SilverType::Float,
+            Some(ty) if !is_float => ty,
+            _ => {
+                let suffix_start = digits_start + digits.len();
+                error_reporter
+                    .report_invalid_number_suffix(suffix_start..start + text.len(), suffix);
+                return None;
+            }
+        };
+
+        let digits = digits.chars().filter(|&c| c != '_').collect::<String>();
+        let value = match ty {
+            SilverType::Float if radix == 10 => digits
+                .parse::<f64>()
+                .ok()
+                .filter(|f| f.is_finite())
+                .map(SilverValue::Float),
+            SilverType::Float => silver_integer::parse(&digits, radix)
+                .map(|i| SilverValue::Float(silver_integer::to_f64(&i))),
+            SilverType::Integer => silver_integer::parse(&digits, radix).map(SilverValue::Integer),
+            _ => silver_integer::parse(&digits, radix)
+                .and_then(|i| silver_integer::to_i128(&i))
+                .and_then(|i| {
+                    // Like `128i8`, which only fits its type once negated, as
+                    // the binder checks.
+                    let fits_negated = SilverValue::integer(i, &ty).is_none()
+                        && i > 0
+                        && SilverValue::integer(-i, &ty).is_some();
+                    if fits_negated {
+                        Some(SilverValue::Integer(silver_integer::from_i128(i)))
+                    } else {
+                        SilverValue::integer(i, &ty)
+                    }
+                }),
+        };
+        if value.is_none() {
+            error_reporter.report_number_out_of_range(start..start + text.len(), text, ty);
+        }
+        value
     }
 
     fn read_whitespace_token(
@@ -178,8 +620,8 @@ impl<'source> Lexer {
         iterator: &mut Peekable<impl Iterator<Item = (usize, char)>>,
     ) -> Option<SyntaxToken> {
         let (start, _) = iterator.next().unwrap();
-        while let Some((_, c)) = iterator.peek() {
-            if !c.is_alphabetic() {
+        while let Some(&(_, c)) = iterator.peek() {
+            if !syntax_facts::is_identifier_continue(c) {
                 break;
             }
             iterator.next();
@@ -197,14 +639,20 @@ impl<'source> Lexer {
 #[cfg(test)]
 mod tests {
     use pretty_assertions::assert_eq;
+    use proptest::prelude::*;
     use strum::IntoEnumIterator;
 
     use crate::analysis::{
-        diagnostic_kind::DiagnosticKind, errors::string_error_reporter::StringErrorReporter,
+        diagnostic_kind::DiagnosticKind,
+        errors::{
+            null_error_reporter::NullErrorReporter, string_error_reporter::StringErrorReporter,
+        },
     };
 
     use super::syntax_facts::SyntaxKindWithText;
     use super::*;
+    use crate::analysis::syntax::syntax_tree::SyntaxTree;
+    use crate::analysis::text::text_span::TextSpan;
     use std::collections::HashSet;
 
     #[test]
@@ -217,9 +665,19 @@ mod tests {
             .chain(get_all_separator_tokens().iter())
             .map(|&(_, k)| k)
             .collect::<HashSet<_>>();
+        // The parts of an interpolated string never stand alone.
         let untested_tokens = all_tokens
             .difference(&tested_tokens)
-            .filter(|&&k| k != SyntaxKind::BadToken && k != SyntaxKind::EndOfFileToken)
+            .filter(|&&k| {
+                !matches!(
+                    k,
+                    SyntaxKind::BadToken
+                        | SyntaxKind::EndOfFileToken
+                        | SyntaxKind::InterpolatedStringStartToken
+                        | SyntaxKind::InterpolatedStringTextToken
+                        | SyntaxKind::InterpolatedStringEndToken
+                )
+            })
             .cloned()
             .collect::<Vec<_>>();
 
@@ -231,20 +689,34 @@ mod tests {
 
     fn get_all_valid_tokens() -> Vec<(&'static str, SyntaxKind)> {
         let static_tokens = SyntaxKind::iter()
-            .filter_map(|k| {
-                if let Some(t) = k.get_text() {
-                    Some((t, k))
-                } else {
-                    None
-                }
-            })
+            .filter_map(|k| k.get_text().map(|t| (t, k)))
             .collect::<Vec<_>>();
-        let dynamic_tokens = vec![
+        let dynamic_tokens = [
             ("a", SyntaxKind::IdentifierToken),
             ("abc", SyntaxKind::IdentifierToken),
             ("abcABC", SyntaxKind::IdentifierToken),
+            ("_", SyntaxKind::IdentifierToken),
+            ("_tmp", SyntaxKind::IdentifierToken),
+            ("foo_bar", SyntaxKind::IdentifierToken),
+            ("x1", SyntaxKind::IdentifierToken),
+            ("\u{3b1}\u{3b2}", SyntaxKind::IdentifierToken),
+            ("cafe\u{301}", SyntaxKind::IdentifierToken),
             ("1", SyntaxKind::NumberToken),
             ("123", SyntaxKind::NumberToken),
+            ("1_000", SyntaxKind::NumberToken),
+            ("0x1f", SyntaxKind::NumberToken),
+            ("0b1010", SyntaxKind::NumberToken),
+            ("0o17", SyntaxKind::NumberToken),
+            ("10i128", SyntaxKind::NumberToken),
+            ("10i32", SyntaxKind::NumberToken),
+            ("255u8", SyntaxKind::NumberToken),
+            ("1.5", SyntaxKind::NumberToken),
+            ("1e10", SyntaxKind::NumberToken),
+            ("2.5e-3", SyntaxKind::NumberToken),
+            ("1f64", SyntaxKind::NumberToken),
+            ("\"\"", SyntaxKind::StringToken),
+            ("\"a b\"", SyntaxKind::StringToken),
+            ("\"\\\"\\n{}\"", SyntaxKind::StringToken),
         ];
         static_tokens
             .iter()
@@ -280,18 +752,65 @@ mod tests {
     }
 
     fn token_pair_requires_separator(t1kind: SyntaxKind, t2kind: SyntaxKind) -> bool {
-        let t1_is_keyword = t1kind.to_string().ends_with("Keyword");
-        let t2_is_keyword = t2kind.to_string().ends_with("Keyword");
+        let is_word = |kind: SyntaxKind| {
+            kind == SyntaxKind::IdentifierToken || kind.to_string().ends_with("Keyword")
+        };
 
-        t1_is_keyword && t2_is_keyword
-            || t1_is_keyword && t2kind == SyntaxKind::IdentifierToken
-            || t1kind == SyntaxKind::IdentifierToken && t2_is_keyword
-            || t1kind == SyntaxKind::IdentifierToken && t2kind == SyntaxKind::IdentifierToken
+        (is_word(t1kind) || t1kind == SyntaxKind::NumberToken)
+            && (is_word(t2kind) || t2kind == SyntaxKind::NumberToken)
             || t1kind == SyntaxKind::BangToken && t2kind == SyntaxKind::EqualsEqualsToken
             || t1kind == SyntaxKind::BangToken && t2kind == SyntaxKind::EqualsToken
             || t1kind == SyntaxKind::EqualsToken && t2kind == SyntaxKind::EqualsEqualsToken
             || t1kind == SyntaxKind::EqualsToken && t2kind == SyntaxKind::EqualsToken
-            || t1kind == SyntaxKind::NumberToken && t2kind == SyntaxKind::NumberToken
+            || t1kind == SyntaxKind::EqualsToken && t2kind == SyntaxKind::GreaterToken
+            || t1kind == SyntaxKind::EqualsToken && t2kind == SyntaxKind::GreaterEqualsToken
+            || t1kind == SyntaxKind::LessToken && t2kind == SyntaxKind::EqualsToken
+            || t1kind == SyntaxKind::LessToken && t2kind == SyntaxKind::EqualsEqualsToken
+            || t1kind == SyntaxKind::GreaterToken && t2kind == SyntaxKind::EqualsToken
+            || t1kind == SyntaxKind::GreaterToken && t2kind == SyntaxKind::EqualsEqualsToken
+            || t1kind == SyntaxKind::QuestionToken
+                && matches!(
+                    t2kind,
+                    SyntaxKind::QuestionToken
+                        | SyntaxKind::QuestionQuestionToken
+                        | SyntaxKind::QuestionDotToken
+                        | SyntaxKind::DotToken
+                )
+            || matches!(
+                t1kind,
+                SyntaxKind::PlusToken
+                    | SyntaxKind::MinusToken
+                    | SyntaxKind::StarToken
+                    | SyntaxKind::SlashToken
+                    | SyntaxKind::PercentToken
+                    | SyntaxKind::AmpersandAmpersandToken
+                    | SyntaxKind::PipePipeToken
+            ) && matches!(
+                t2kind,
+                SyntaxKind::EqualsToken
+                    | SyntaxKind::EqualsEqualsToken
+                    | SyntaxKind::EqualsGreaterToken
+            )
+            || t1kind == SyntaxKind::PlusToken
+                && matches!(
+                    t2kind,
+                    SyntaxKind::PlusToken | SyntaxKind::PlusPlusToken | SyntaxKind::PlusEqualsToken
+                )
+            || t1kind == SyntaxKind::MinusToken
+                && matches!(
+                    t2kind,
+                    SyntaxKind::MinusToken
+                        | SyntaxKind::MinusMinusToken
+                        | SyntaxKind::MinusEqualsToken
+                )
+            || t2kind == SyntaxKind::EqualsGreaterToken
+                && matches!(
+                    t1kind,
+                    SyntaxKind::BangToken
+                        | SyntaxKind::EqualsToken
+                        | SyntaxKind::LessToken
+                        | SyntaxKind::GreaterToken
+                )
     }
 
     fn lexer_lexes_token_pair(t1text: &str, t1kind: SyntaxKind, t2text: &str, t2kind: SyntaxKind) {
@@ -360,6 +879,89 @@ mod tests {
         }
     }
 
+    #[test]
+    fn lexes_string_values() {
+        for (input, value) in [
+            (r#""""#, ""),
+            (r#""a b""#, "a b"),
+            (r#""\n\t\r\0""#, "\n\t\r\0"),
+            (r#""\"\\\{\}""#, "\"\\{}"),
+            (r#""{x}""#, "{x}"),
+        ] {
+            let mut error_reporter = StringErrorReporter::new();
+            let tokens = Lexer::get_tokens(Arc::new(input.to_string().into()), &mut error_reporter);
+            assert!(!error_reporter.had_error(), "{}", input);
+            assert_eq!(2, tokens.len(), "{}", input);
+            assert_eq!(Some(&SilverValue::String(value.into())), tokens[0].value());
+        }
+    }
+
+    #[test]
+    fn lexes_interpolated_string() {
+        let input = r#"$"a{ {b} + "}" }\{c{$"{d}"}""#;
+        let mut error_reporter = StringErrorReporter::new();
+        let tokens = Lexer::get_tokens(Arc::new(input.to_string().into()), &mut error_reporter);
+        assert!(!error_reporter.had_error());
+        let actual = tokens
+            .iter()
+            .filter(|t| t.kind() != SyntaxKind::WhitespaceToken)
+            .map(|t| (t.kind(), t.text()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                (SyntaxKind::InterpolatedStringStartToken, "$\""),
+                (SyntaxKind::InterpolatedStringTextToken, "a"),
+                (SyntaxKind::OpenBraceToken, "{"),
+                (SyntaxKind::OpenBraceToken, "{"),
+                (SyntaxKind::IdentifierToken, "b"),
+                (SyntaxKind::CloseBraceToken, "}"),
+                (SyntaxKind::PlusToken, "+"),
+                (SyntaxKind::StringToken, "\"}\""),
+                (SyntaxKind::CloseBraceToken, "}"),
+                (SyntaxKind::InterpolatedStringTextToken, "\\{c"),
+                (SyntaxKind::OpenBraceToken, "{"),
+                (SyntaxKind::InterpolatedStringStartToken, "$\""),
+                (SyntaxKind::OpenBraceToken, "{"),
+                (SyntaxKind::IdentifierToken, "d"),
+                (SyntaxKind::CloseBraceToken, "}"),
+                (SyntaxKind::InterpolatedStringEndToken, "\""),
+                (SyntaxKind::CloseBraceToken, "}"),
+                (SyntaxKind::InterpolatedStringEndToken, "\""),
+                (SyntaxKind::EndOfFileToken, ""),
+            ],
+            actual
+        );
+        let text = tokens.iter().find(|t| t.text() == "\\{c").unwrap();
+        assert_eq!(Some(&SilverValue::String("{c".into())), text.value());
+        assert_eq!(input.len(), tokens[tokens.len() - 2].span().end);
+    }
+
+    #[test]
+    fn lex_bad_strings() {
+        for (input, span, kind) in [
+            (r#""abc"#, 0..4, DiagnosticKind::UnterminatedString),
+            ("\"abc\nx", 0..4, DiagnosticKind::UnterminatedString),
+            (r#"$"a {b"#, 0..2, DiagnosticKind::UnterminatedString),
+            (
+                r#""a\qb""#,
+                2..4,
+                DiagnosticKind::InvalidEscapeSequence { character: 'q' },
+            ),
+        ] {
+            let mut error_reporter = StringErrorReporter::new();
+            let tokens = Lexer::get_tokens(Arc::new(input.to_string().into()), &mut error_reporter);
+            assert_eq!(
+                SyntaxKind::EndOfFileToken,
+                tokens.back().unwrap().kind(),
+                "{}",
+                input
+            );
+            assert_eq!(1, error_reporter.errors().len(), "{}", input);
+            assert_eq!(&kind, error_reporter.errors()[0].kind(), "{}", input);
+            assert_eq!(span, error_reporter.errors()[0].span(), "{}", input);
+        }
+    }
+
     #[test]
     fn lex_bad_token() {
         let mut error_reporter = StringErrorReporter::new();
@@ -375,6 +977,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(not(feature = "bigint"))]
     fn lex_bad_number_literal() {
         let mut error_reporter = StringErrorReporter::new();
         let tokens = Lexer::get_tokens(
@@ -386,8 +989,216 @@ mod tests {
         assert_eq!("483295734987984573189492137827598724983", tokens[0].text());
         assert_eq!(1, error_reporter.errors().len());
         assert_eq!(
-            &DiagnosticKind::BadLiteral(SilverType::Integer),
+            &DiagnosticKind::NumberOutOfRange(SilverType::Integer),
             error_reporter.errors()[0].kind()
         );
     }
+
+    #[test]
+    #[cfg(feature = "bigint")]
+    fn lexes_arbitrarily_large_number_literal() {
+        let text = "483295734987984573189492137827598724983";
+        let tokens = Lexer::get_tokens(
+            Arc::new(text.to_string().into()),
+            &mut NullErrorReporter::new(),
+        );
+        assert_eq!(2, tokens.len());
+        assert_eq!(SyntaxKind::NumberToken, tokens[0].kind());
+        assert_eq!(
+            Some(&SilverValue::Integer(text.parse().unwrap())),
+            tokens[0].value()
+        );
+    }
+
+    #[test]
+    fn lexes_number_literal_values() {
+        for (input, value) in [
+            ("0", 0),
+            ("1_000_000", 1_000_000),
+            ("0xff", 255),
+            ("0xFF", 255),
+            ("0x_ff", 255),
+            ("0b1010", 10),
+            ("0o777", 511),
+            ("42i128", 42),
+            ("0x10i128", 16),
+        ]
+        .iter()
+        {
+            let mut error_reporter = StringErrorReporter::new();
+            let tokens = Lexer::get_tokens(Arc::new(input.to_string().into()), &mut error_reporter);
+            assert!(!error_reporter.had_error(), "{}", input);
+            assert_eq!(SyntaxKind::NumberToken, tokens[0].kind());
+            assert_eq!(
+                Some(&SilverValue::Integer((*value).into())),
+                tokens[0].value()
+            );
+        }
+    }
+
+    #[test]
+    fn lexes_float_literal_values() {
+        for (input, value) in [
+            ("1.5", 1.5),
+            ("0.25", 0.25),
+            ("1_000.000_1", 1000.0001),
+            ("1e3", 1e3),
+            ("1E3", 1e3),
+            ("1.5e+2", 150.0),
+            ("25e-1", 2.5),
+            ("2f64", 2.0),
+            ("2.5f64", 2.5),
+        ]
+        .iter()
+        {
+            let mut error_reporter = StringErrorReporter::new();
+            let tokens = Lexer::get_tokens(Arc::new(input.to_string().into()), &mut error_reporter);
+            assert!(!error_reporter.had_error(), "{}", input);
+            assert_eq!(2, tokens.len(), "{}", input);
+            assert_eq!(Some(&SilverValue::Float(*value)), tokens[0].value());
+        }
+    }
+
+    #[test]
+    fn number_does_not_take_following_operator() {
+        let tokens = Lexer::get_tokens(
+            Arc::new("0x1e-1".to_string().into()),
+            &mut NullErrorReporter::new(),
+        );
+        assert_eq!(4, tokens.len());
+        assert_eq!(Some(&SilverValue::Integer(0x1e.into())), tokens[0].value());
+        assert_eq!(SyntaxKind::MinusToken, tokens[1].kind());
+
+        let tokens = Lexer::get_tokens(
+            Arc::new("1e-x".to_string().into()),
+            &mut NullErrorReporter::new(),
+        );
+        assert_eq!("1e", tokens[0].text());
+    }
+
+    fn check_bad_number(input: &str, span: TextSpan, kind: DiagnosticKind) {
+        let mut error_reporter = StringErrorReporter::new();
+        let tokens = Lexer::get_tokens(Arc::new(input.to_string().into()), &mut error_reporter);
+        assert_eq!(2, tokens.len());
+        assert_eq!(SyntaxKind::BadToken, tokens[0].kind());
+        assert_eq!(input, tokens[0].text());
+        assert_eq!(1, error_reporter.errors().len());
+        assert_eq!(&kind, error_reporter.errors()[0].kind());
+        assert_eq!(span, error_reporter.errors()[0].span());
+
+        // The parser keeps the literal, and reports nothing more about it.
+        let mut error_reporter = StringErrorReporter::new();
+        SyntaxTree::parse_str(input, &mut error_reporter);
+        assert_eq!(1, error_reporter.errors().len(), "{}", input);
+        assert_eq!(&kind, error_reporter.errors()[0].kind());
+    }
+
+    #[test]
+    fn lex_malformed_number_literals() {
+        check_bad_number(
+            "0b102",
+            4..5,
+            DiagnosticKind::InvalidDigit {
+                digit: '2',
+                radix: 2,
+            },
+        );
+        check_bad_number(
+            "0o78",
+            3..4,
+            DiagnosticKind::InvalidDigit {
+                digit: '8',
+                radix: 8,
+            },
+        );
+        check_bad_number("0x", 0..2, DiagnosticKind::MissingDigits);
+        check_bad_number("0b_", 0..3, DiagnosticKind::MissingDigits);
+        check_bad_number("1_", 1..2, DiagnosticKind::TrailingDigitSeparator);
+        check_bad_number("1_i128", 1..2, DiagnosticKind::TrailingDigitSeparator);
+        check_bad_number(
+            "10abc",
+            2..5,
+            DiagnosticKind::InvalidNumberSuffix {
+                suffix: "abc".to_string(),
+            },
+        );
+        #[cfg(not(feature = "bigint"))]
+        check_bad_number(
+            "0x1_0000_0000_0000_0000_0000_0000_0000_0000i128",
+            0..47,
+            DiagnosticKind::NumberOutOfRange(SilverType::Integer),
+        );
+        check_bad_number(
+            "256u8",
+            0..5,
+            DiagnosticKind::NumberOutOfRange(SilverType::UInt8),
+        );
+        check_bad_number(
+            "10int",
+            2..5,
+            DiagnosticKind::InvalidNumberSuffix {
+                suffix: "int".to_string(),
+            },
+        );
+        check_bad_number(
+            "1.5i128",
+            3..7,
+            DiagnosticKind::InvalidNumberSuffix {
+                suffix: "i128".to_string(),
+            },
+        );
+        check_bad_number(
+            "1e999",
+            0..5,
+            DiagnosticKind::NumberOutOfRange(SilverType::Float),
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn lexes_arbitrary_input_without_panicking(s in "\\PC*") {
+            let tokens = Lexer::get_tokens(Arc::new(s.into()), &mut NullErrorReporter::new());
+            prop_assert_eq!(SyntaxKind::EndOfFileToken, tokens.back().unwrap().kind());
+        }
+
+        #[test]
+        fn lexes_identifier(s in "[_\\p{XID_Start}]\\p{XID_Continue}{0,8}") {
+            prop_assume!(syntax_facts::keyword_kind(&s) == SyntaxKind::IdentifierToken);
+            let mut error_reporter = StringErrorReporter::new();
+            let tokens = Lexer::get_tokens(Arc::new(s.clone().into()), &mut error_reporter);
+            prop_assert!(!error_reporter.had_error());
+            prop_assert_eq!(2, tokens.len());
+            prop_assert_eq!(SyntaxKind::IdentifierToken, tokens[0].kind());
+            prop_assert_eq!(s.as_str(), tokens[0].text());
+        }
+
+        #[test]
+        fn identifier_stops_before_non_continue_character(
+            s in "[_a-z][_a-z0-9]{0,8}",
+            c in "[-+*/()!=]",
+        ) {
+            prop_assume!(syntax_facts::keyword_kind(&s) == SyntaxKind::IdentifierToken);
+            let input = format!("{}{}", s, c);
+            let tokens = Lexer::get_tokens(Arc::new(input.into()), &mut NullErrorReporter::new());
+            prop_assert_eq!(SyntaxKind::IdentifierToken, tokens[0].kind());
+            prop_assert_eq!(s.as_str(), tokens[0].text());
+        }
+
+        #[test]
+        fn lexes_integer_in_every_base(n in any::<u64>()) {
+            for input in [
+                format!("{}", n),
+                format!("{:#x}", n),
+                format!("{:#o}", n),
+                format!("{:#b}", n),
+            ]
+            .iter()
+            {
+                let mut error_reporter = StringErrorReporter::new();
+                let tokens = Lexer::get_tokens(Arc::new(input.clone().into()), &mut error_reporter);
+                prop_assert!(!error_reporter.had_error());
+                prop_assert_eq!(Some(&SilverValue::Integer(n.into())), tokens[0].value());
+            }
+        }
+    }
 }
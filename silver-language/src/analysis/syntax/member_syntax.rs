@@ -0,0 +1,290 @@
+use crate::analysis::{silver_value::SilverValue, text::text_span::TextSpan};
+
+use super::{
+    expression_syntax::ExpressionSyntax,
+    separated_syntax_list::SeparatedSyntaxList,
+    syntax_kind::SyntaxKind,
+    syntax_node::SyntaxNodeExt,
+    syntax_token::SyntaxToken,
+    type_syntax::{TypeClauseSyntax, TypeParameterListSyntax, TypeSyntax},
+};
+
+/// A top-level item of a submission.
+// A submission holds few members, so their size matters less than keeping
+// them as plain as the other syntax nodes.
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, PartialEq)]
+pub enum MemberSyntax {
+    StructDeclaration {
+        struct_keyword: SyntaxToken,
+        identifier_token: SyntaxToken,
+        type_parameter_list: Option<TypeParameterListSyntax>,
+        open_brace_token: SyntaxToken,
+        fields: SeparatedSyntaxList<FieldDeclarationSyntax>,
+        close_brace_token: SyntaxToken,
+    },
+    EnumDeclaration {
+        enum_keyword: SyntaxToken,
+        identifier_token: SyntaxToken,
+        type_parameter_list: Option<TypeParameterListSyntax>,
+        open_brace_token: SyntaxToken,
+        variants: SeparatedSyntaxList<VariantDeclarationSyntax>,
+        close_brace_token: SyntaxToken,
+    },
+    /// `fn name<T>(parameters): type => body`, where the type parameters
+    /// and the return type are optional.
+    FunctionDeclaration {
+        fn_keyword: SyntaxToken,
+        identifier_token: SyntaxToken,
+        type_parameter_list: Option<TypeParameterListSyntax>,
+        open_parenthesis_token: SyntaxToken,
+        parameters: SeparatedSyntaxList<ParameterSyntax>,
+        close_parenthesis_token: SyntaxToken,
+        type_clause: Option<TypeClauseSyntax>,
+        equals_greater_token: SyntaxToken,
+        body: ExpressionSyntax,
+    },
+    GlobalExpression {
+        expression: ExpressionSyntax,
+    },
+}
+
+impl SyntaxNodeExt for MemberSyntax {
+    fn kind(&self) -> SyntaxKind {
+        match self {
+            MemberSyntax::StructDeclaration { .. } => SyntaxKind::StructDeclaration,
+            MemberSyntax::EnumDeclaration { .. } => SyntaxKind::EnumDeclaration,
+            MemberSyntax::FunctionDeclaration { .. } => SyntaxKind::FunctionDeclaration,
+            MemberSyntax::GlobalExpression { .. } => SyntaxKind::GlobalExpression,
+        }
+    }
+
+    fn children(&self) -> Vec<&dyn SyntaxNodeExt> {
+        match self {
+            MemberSyntax::StructDeclaration {
+                struct_keyword,
+                identifier_token,
+                type_parameter_list,
+                open_brace_token,
+                fields,
+                close_brace_token,
+            } => {
+                let mut children: Vec<&dyn SyntaxNodeExt> = vec![struct_keyword, identifier_token];
+                if let Some(type_parameter_list) = type_parameter_list {
+                    children.push(type_parameter_list);
+                }
+                children.push(open_brace_token);
+                children.extend(fields.children());
+                children.push(close_brace_token);
+                children
+            }
+            MemberSyntax::EnumDeclaration {
+                enum_keyword,
+                identifier_token,
+                type_parameter_list,
+                open_brace_token,
+                variants,
+                close_brace_token,
+            } => {
+                let mut children: Vec<&dyn SyntaxNodeExt> = vec![enum_keyword, identifier_token];
+                if let Some(type_parameter_list) = type_parameter_list {
+                    children.push(type_parameter_list);
+                }
+                children.push(open_brace_token);
+                children.extend(variants.children());
+                children.push(close_brace_token);
+                children
+            }
+            MemberSyntax::FunctionDeclaration {
+                fn_keyword,
+                identifier_token,
+                type_parameter_list,
+                open_parenthesis_token,
+                parameters,
+                close_parenthesis_token,
+                type_clause,
+                equals_greater_token,
+                body,
+            } => {
+                let mut children: Vec<&dyn SyntaxNodeExt> = vec![fn_keyword, identifier_token];
+                if let Some(type_parameter_list) = type_parameter_list {
+                    children.push(type_parameter_list);
+                }
+                children.push(open_parenthesis_token);
+                children.extend(parameters.children());
+                children.push(close_parenthesis_token);
+                if let Some(type_clause) = type_clause {
+                    children.push(type_clause);
+                }
+                children.push(equals_greater_token);
+                children.push(body);
+                children
+            }
+            MemberSyntax::GlobalExpression { expression } => vec![expression],
+        }
+    }
+
+    fn value(&self) -> Option<&SilverValue> {
+        None
+    }
+
+    fn text(&self) -> Option<&str> {
+        None
+    }
+
+    fn span(&self) -> TextSpan {
+        match self {
+            MemberSyntax::StructDeclaration {
+                struct_keyword,
+                close_brace_token,
+                ..
+            } => struct_keyword.span().start..close_brace_token.span().end,
+            MemberSyntax::EnumDeclaration {
+                enum_keyword,
+                close_brace_token,
+                ..
+            } => enum_keyword.span().start..close_brace_token.span().end,
+            MemberSyntax::FunctionDeclaration {
+                fn_keyword, body, ..
+            } => fn_keyword.span().start..body.span().end,
+            MemberSyntax::GlobalExpression { expression } => expression.span(),
+        }
+    }
+}
+
+/// A `name: type` field of a struct declaration.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldDeclarationSyntax {
+    pub(crate) identifier_token: SyntaxToken,
+    pub(crate) type_clause: TypeClauseSyntax,
+}
+
+impl SyntaxNodeExt for FieldDeclarationSyntax {
+    fn kind(&self) -> SyntaxKind {
+        SyntaxKind::FieldDeclaration
+    }
+
+    fn children(&self) -> Vec<&dyn SyntaxNodeExt> {
+        vec![&self.identifier_token, &self.type_clause]
+    }
+
+    fn value(&self) -> Option<&SilverValue> {
+        None
+    }
+
+    fn text(&self) -> Option<&str> {
+        None
+    }
+
+    fn span(&self) -> TextSpan {
+        self.identifier_token.span().start..self.type_clause.span().end
+    }
+}
+
+/// A parameter of a function declaration, with its type if it states one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParameterSyntax {
+    pub(crate) identifier_token: SyntaxToken,
+    pub(crate) type_clause: Option<TypeClauseSyntax>,
+}
+
+impl SyntaxNodeExt for ParameterSyntax {
+    fn kind(&self) -> SyntaxKind {
+        SyntaxKind::Parameter
+    }
+
+    fn children(&self) -> Vec<&dyn SyntaxNodeExt> {
+        let mut children: Vec<&dyn SyntaxNodeExt> = vec![&self.identifier_token];
+        if let Some(type_clause) = &self.type_clause {
+            children.push(type_clause);
+        }
+        children
+    }
+
+    fn value(&self) -> Option<&SilverValue> {
+        None
+    }
+
+    fn text(&self) -> Option<&str> {
+        None
+    }
+
+    fn span(&self) -> TextSpan {
+        let end = match &self.type_clause {
+            Some(type_clause) => type_clause.span().end,
+            None => self.identifier_token.span().end,
+        };
+        self.identifier_token.span().start..end
+    }
+}
+
+/// A variant of an enum declaration, with the types of its payload if it
+/// has one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VariantDeclarationSyntax {
+    pub(crate) identifier_token: SyntaxToken,
+    pub(crate) payload: Option<VariantPayloadSyntax>,
+}
+
+impl SyntaxNodeExt for VariantDeclarationSyntax {
+    fn kind(&self) -> SyntaxKind {
+        SyntaxKind::VariantDeclaration
+    }
+
+    fn children(&self) -> Vec<&dyn SyntaxNodeExt> {
+        let mut children: Vec<&dyn SyntaxNodeExt> = vec![&self.identifier_token];
+        if let Some(payload) = &self.payload {
+            children.push(payload);
+        }
+        children
+    }
+
+    fn value(&self) -> Option<&SilverValue> {
+        None
+    }
+
+    fn text(&self) -> Option<&str> {
+        None
+    }
+
+    fn span(&self) -> TextSpan {
+        let end = match &self.payload {
+            Some(payload) => payload.span().end,
+            None => self.identifier_token.span().end,
+        };
+        self.identifier_token.span().start..end
+    }
+}
+
+/// The parenthesized payload types of a variant declaration.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VariantPayloadSyntax {
+    pub(crate) open_parenthesis_token: SyntaxToken,
+    pub(crate) types: SeparatedSyntaxList<TypeSyntax>,
+    pub(crate) close_parenthesis_token: SyntaxToken,
+}
+
+impl SyntaxNodeExt for VariantPayloadSyntax {
+    fn kind(&self) -> SyntaxKind {
+        SyntaxKind::VariantPayload
+    }
+
+    fn children(&self) -> Vec<&dyn SyntaxNodeExt> {
+        let mut children: Vec<&dyn SyntaxNodeExt> = vec![&self.open_parenthesis_token];
+        children.extend(self.types.children());
+        children.push(&self.close_parenthesis_token);
+        children
+    }
+
+    fn value(&self) -> Option<&SilverValue> {
+        None
+    }
+
+    fn text(&self) -> Option<&str> {
+        None
+    }
+
+    fn span(&self) -> TextSpan {
+        self.open_parenthesis_token.span().start..self.close_parenthesis_token.span().end
+    }
+}
@@ -1,17 +1,35 @@
-use std::{collections::VecDeque, sync::Arc};
+use std::{collections::VecDeque, sync::Arc, time::Instant};
 
 use crate::analysis::{
     errors::error_reporter::ErrorReporter, silver_value::SilverValue, text::source_text::SourceText,
 };
 
 use super::{
-    expression_syntax::ExpressionSyntax, lexer::Lexer, syntax_facts::Operator,
-    syntax_kind::SyntaxKind, syntax_token::SyntaxToken, syntax_tree::SyntaxTree,
+    compilation_unit_syntax::CompilationUnitSyntax,
+    expression_syntax::{
+        ExpressionSyntax, FieldInitializerSyntax, InterpolatedStringPartSyntax, MatchArmSyntax,
+    },
+    lexer::Lexer,
+    member_syntax::{
+        FieldDeclarationSyntax, MemberSyntax, ParameterSyntax, VariantDeclarationSyntax,
+        VariantPayloadSyntax,
+    },
+    pattern_syntax::{PatternPayloadSyntax, PatternSyntax},
+    separated_syntax_list::SeparatedSyntaxList,
+    syntax_facts::{self, Operator},
+    syntax_kind::SyntaxKind,
+    syntax_node::SyntaxNodeExt,
+    syntax_token::SyntaxToken,
+    syntax_tree::SyntaxTree,
+    type_syntax::{TypeArgumentListSyntax, TypeClauseSyntax, TypeParameterListSyntax, TypeSyntax},
 };
 
 pub(crate) struct Parser<'reporter> {
     tokens: VecDeque<SyntaxToken>,
     error_reporter: &'reporter mut dyn ErrorReporter,
+    /// Whether `Name {` starts a struct construction. It does not in a match
+    /// scrutinee, where the brace opens the arms instead.
+    allow_struct_literals: bool,
 }
 
 impl<'reporter> Parser<'reporter> {
@@ -22,6 +40,7 @@ impl<'reporter> Parser<'reporter> {
         Self {
             tokens,
             error_reporter,
+            allow_struct_literals: true,
         }
     }
 
@@ -29,15 +48,347 @@ impl<'reporter> Parser<'reporter> {
         text: Arc<SourceText>,
         error_reporter: &'reporter mut dyn ErrorReporter,
     ) -> SyntaxTree {
-        let tokens = Lexer::get_tokens(text.clone(), error_reporter)
+        let start = Instant::now();
+        let tokens = Lexer::get_tokens(text.clone(), error_reporter);
+        // A bad character is dropped, but a malformed number keeps its place
+        // as a literal without a value, so that the lexer's error is the
+        // only one reported for it.
+        let tokens = tokens
             .iter()
-            .filter(|t| t.kind() != SyntaxKind::WhitespaceToken && t.kind() != SyntaxKind::BadToken)
+            .filter(|t| match t.kind() {
+                SyntaxKind::WhitespaceToken => false,
+                SyntaxKind::BadToken => !t.text().is_empty(),
+                _ => true,
+            })
             .cloned()
             .collect();
+        let lexing_time = start.elapsed();
+        let start = Instant::now();
         let mut parser = Self::new(tokens, error_reporter);
-        let expression = parser.parse_expression();
-        let end_of_file = parser.match_token(SyntaxKind::EndOfFileToken);
-        SyntaxTree::new(expression, end_of_file, text)
+        let root = parser.parse_compilation_unit();
+        SyntaxTree::new(root, text, lexing_time, start.elapsed())
+    }
+
+    fn parse_compilation_unit(&mut self) -> CompilationUnitSyntax {
+        let mut members = vec![self.parse_member()];
+        while self.at_member_start() {
+            members.push(self.parse_member());
+        }
+        let end_of_file_token = self.match_token(SyntaxKind::EndOfFileToken);
+        CompilationUnitSyntax {
+            members,
+            end_of_file_token,
+        }
+    }
+
+    /// Whether the current token can begin another member, so that stray
+    /// tokens are reported as expecting the end of the input.
+    fn at_member_start(&self) -> bool {
+        let kind = self.current().kind();
+        kind.unary_operator_precedence() != 0
+            || matches!(
+                kind,
+                SyntaxKind::StructKeyword
+                    | SyntaxKind::EnumKeyword
+                    | SyntaxKind::FnKeyword
+                    | SyntaxKind::MatchKeyword
+                    | SyntaxKind::PlusPlusToken
+                    | SyntaxKind::MinusMinusToken
+                    | SyntaxKind::OpenParenthesisToken
+                    | SyntaxKind::OpenBracketToken
+                    | SyntaxKind::NumberToken
+                    | SyntaxKind::BadToken
+                    | SyntaxKind::StringToken
+                    | SyntaxKind::InterpolatedStringStartToken
+                    | SyntaxKind::IdentifierToken
+                    | SyntaxKind::TrueKeyword
+                    | SyntaxKind::FalseKeyword
+                    | SyntaxKind::NullKeyword
+            )
+    }
+
+    fn parse_member(&mut self) -> MemberSyntax {
+        match self.current().kind() {
+            SyntaxKind::StructKeyword => self.parse_struct_declaration(),
+            SyntaxKind::EnumKeyword => self.parse_enum_declaration(),
+            SyntaxKind::FnKeyword => self.parse_function_declaration(),
+            _ => MemberSyntax::GlobalExpression {
+                expression: self.parse_expression(),
+            },
+        }
+    }
+
+    fn parse_struct_declaration(&mut self) -> MemberSyntax {
+        let struct_keyword = self.match_token(SyntaxKind::StructKeyword);
+        let identifier_token = self.match_token(SyntaxKind::IdentifierToken);
+        let type_parameter_list = self.parse_optional_type_parameter_list();
+        let open_brace_token = self.match_token(SyntaxKind::OpenBraceToken);
+        let fields =
+            self.parse_separated_list(SyntaxKind::CloseBraceToken, Self::parse_field_declaration);
+        let close_brace_token = self.match_token(SyntaxKind::CloseBraceToken);
+        MemberSyntax::StructDeclaration {
+            struct_keyword,
+            identifier_token,
+            type_parameter_list,
+            open_brace_token,
+            fields,
+            close_brace_token,
+        }
+    }
+
+    fn parse_enum_declaration(&mut self) -> MemberSyntax {
+        let enum_keyword = self.match_token(SyntaxKind::EnumKeyword);
+        let identifier_token = self.match_token(SyntaxKind::IdentifierToken);
+        let type_parameter_list = self.parse_optional_type_parameter_list();
+        let open_brace_token = self.match_token(SyntaxKind::OpenBraceToken);
+        let variants =
+            self.parse_separated_list(SyntaxKind::CloseBraceToken, Self::parse_variant_declaration);
+        let close_brace_token = self.match_token(SyntaxKind::CloseBraceToken);
+        MemberSyntax::EnumDeclaration {
+            enum_keyword,
+            identifier_token,
+            type_parameter_list,
+            open_brace_token,
+            variants,
+            close_brace_token,
+        }
+    }
+
+    fn parse_function_declaration(&mut self) -> MemberSyntax {
+        let fn_keyword = self.match_token(SyntaxKind::FnKeyword);
+        let identifier_token = self.match_token(SyntaxKind::IdentifierToken);
+        let type_parameter_list = self.parse_optional_type_parameter_list();
+        let open_parenthesis_token = self.match_token(SyntaxKind::OpenParenthesisToken);
+        let parameters =
+            self.parse_separated_list(SyntaxKind::CloseParenthesisToken, Self::parse_parameter);
+        let close_parenthesis_token = self.match_token(SyntaxKind::CloseParenthesisToken);
+        let type_clause = if self.current().kind() == SyntaxKind::ColonToken {
+            Some(self.parse_type_clause())
+        } else {
+            None
+        };
+        let equals_greater_token = self.match_token(SyntaxKind::EqualsGreaterToken);
+        let body = self.parse_expression();
+        MemberSyntax::FunctionDeclaration {
+            fn_keyword,
+            identifier_token,
+            type_parameter_list,
+            open_parenthesis_token,
+            parameters,
+            close_parenthesis_token,
+            type_clause,
+            equals_greater_token,
+            body,
+        }
+    }
+
+    fn parse_optional_type_parameter_list(&mut self) -> Option<TypeParameterListSyntax> {
+        if self.current().kind() != SyntaxKind::LessToken {
+            return None;
+        }
+        let less_token = self.next_token();
+        let parameters = self.parse_separated_list(SyntaxKind::GreaterToken, |parser| {
+            parser.match_token(SyntaxKind::IdentifierToken)
+        });
+        let greater_token = self.match_token(SyntaxKind::GreaterToken);
+        Some(TypeParameterListSyntax {
+            less_token,
+            parameters,
+            greater_token,
+        })
+    }
+
+    fn parse_parameter(&mut self) -> ParameterSyntax {
+        let identifier_token = self.match_token(SyntaxKind::IdentifierToken);
+        let type_clause = if self.current().kind() == SyntaxKind::ColonToken {
+            Some(self.parse_type_clause())
+        } else {
+            None
+        };
+        ParameterSyntax {
+            identifier_token,
+            type_clause,
+        }
+    }
+
+    fn parse_variant_declaration(&mut self) -> VariantDeclarationSyntax {
+        let identifier_token = self.match_token(SyntaxKind::IdentifierToken);
+        let payload = if self.current().kind() == SyntaxKind::OpenParenthesisToken {
+            let open_parenthesis_token = self.next_token();
+            let types =
+                self.parse_separated_list(SyntaxKind::CloseParenthesisToken, Self::parse_type);
+            let close_parenthesis_token = self.match_token(SyntaxKind::CloseParenthesisToken);
+            Some(VariantPayloadSyntax {
+                open_parenthesis_token,
+                types,
+                close_parenthesis_token,
+            })
+        } else {
+            None
+        };
+        VariantDeclarationSyntax {
+            identifier_token,
+            payload,
+        }
+    }
+
+    fn parse_field_declaration(&mut self) -> FieldDeclarationSyntax {
+        let identifier_token = self.match_token(SyntaxKind::IdentifierToken);
+        let type_clause = self.parse_type_clause();
+        FieldDeclarationSyntax {
+            identifier_token,
+            type_clause,
+        }
+    }
+
+    fn parse_type_clause(&mut self) -> TypeClauseSyntax {
+        let colon_token = self.match_token(SyntaxKind::ColonToken);
+        let type_syntax = self.parse_type();
+        TypeClauseSyntax {
+            colon_token,
+            type_syntax,
+        }
+    }
+
+    fn parse_type(&mut self) -> TypeSyntax {
+        let type_syntax = self.parse_non_nullable_type();
+        if self.current().kind() != SyntaxKind::QuestionToken {
+            return type_syntax;
+        }
+        let question_token = self.next_token();
+        TypeSyntax::Nullable {
+            element_type: Box::new(type_syntax),
+            question_token,
+        }
+    }
+
+    fn parse_non_nullable_type(&mut self) -> TypeSyntax {
+        if self.current().kind() == SyntaxKind::OpenBracketToken {
+            let open_bracket_token = self.next_token();
+            let element_type = self.parse_type();
+            let close_bracket_token = self.match_token(SyntaxKind::CloseBracketToken);
+            TypeSyntax::Array {
+                open_bracket_token,
+                element_type: Box::new(element_type),
+                close_bracket_token,
+            }
+        } else if self.current().kind() == SyntaxKind::FnKeyword {
+            let fn_keyword = self.next_token();
+            let open_parenthesis_token = self.match_token(SyntaxKind::OpenParenthesisToken);
+            let parameters =
+                self.parse_separated_list(SyntaxKind::CloseParenthesisToken, Self::parse_type);
+            let close_parenthesis_token = self.match_token(SyntaxKind::CloseParenthesisToken);
+            let type_clause = self.parse_type_clause();
+            TypeSyntax::Function {
+                fn_keyword,
+                open_parenthesis_token,
+                parameters,
+                close_parenthesis_token,
+                type_clause: Box::new(type_clause),
+            }
+        } else {
+            let identifier_token = self.match_token(SyntaxKind::IdentifierToken);
+            // `x as int < y` compares, so the `<` must open a whole list.
+            if self.current().kind() == SyntaxKind::LessToken
+                && self.type_argument_list_end(0).is_some()
+            {
+                let type_argument_list = self.parse_type_argument_list();
+                TypeSyntax::Generic {
+                    identifier_token,
+                    type_argument_list,
+                }
+            } else {
+                TypeSyntax::Name { identifier_token }
+            }
+        }
+    }
+
+    fn parse_type_argument_list(&mut self) -> TypeArgumentListSyntax {
+        let less_token = self.match_token(SyntaxKind::LessToken);
+        let arguments = self.parse_separated_list(SyntaxKind::GreaterToken, Self::parse_type);
+        let greater_token = self.match_token(SyntaxKind::GreaterToken);
+        TypeArgumentListSyntax {
+            less_token,
+            arguments,
+            greater_token,
+        }
+    }
+
+    fn parse_optional_type_argument_list(&mut self) -> Option<TypeArgumentListSyntax> {
+        if self.current().kind() == SyntaxKind::LessToken {
+            Some(self.parse_type_argument_list())
+        } else {
+            None
+        }
+    }
+
+    /// The offset of the token after the type argument list that starts at
+    /// `offset`, if the tokens from there can form one: type names, array
+    /// brackets, `?`, function types and commas between balanced angle
+    /// brackets.
+    fn type_argument_list_end(&self, offset: usize) -> Option<usize> {
+        let mut depth = 0;
+        let mut offset = offset;
+        loop {
+            match self.peek(offset)?.kind() {
+                SyntaxKind::LessToken => depth += 1,
+                SyntaxKind::GreaterToken => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(offset + 1);
+                    }
+                }
+                SyntaxKind::IdentifierToken
+                | SyntaxKind::CommaToken
+                | SyntaxKind::OpenBracketToken
+                | SyntaxKind::CloseBracketToken
+                | SyntaxKind::QuestionToken
+                | SyntaxKind::FnKeyword
+                | SyntaxKind::OpenParenthesisToken
+                | SyntaxKind::CloseParenthesisToken
+                | SyntaxKind::ColonToken => {}
+                _ => return None,
+            }
+            offset += 1;
+        }
+    }
+
+    /// The kind of the token after the current identifier and any type
+    /// argument list after it. As in C#, `f<T>(x)` is a generic call rather
+    /// than two comparisons because a `(`, `.` or `{` follows the `>`.
+    fn identifier_follower(&self) -> Option<SyntaxKind> {
+        let next = self.peek(1)?.kind();
+        if next != SyntaxKind::LessToken {
+            return Some(next);
+        }
+        let kind = self.peek(self.type_argument_list_end(1)?)?.kind();
+        matches!(
+            kind,
+            SyntaxKind::OpenParenthesisToken | SyntaxKind::DotToken | SyntaxKind::OpenBraceToken
+        )
+        .then_some(kind)
+    }
+
+    /// Whether the current `(` opens the parameters of a lambda, which is
+    /// only known from the `=>` after the matching `)`.
+    fn at_lambda_start(&self) -> bool {
+        let mut depth = 0;
+        let mut offset = 0;
+        loop {
+            match self.peek(offset).map(|token| token.kind()) {
+                Some(SyntaxKind::OpenParenthesisToken) => depth += 1,
+                Some(SyntaxKind::CloseParenthesisToken) => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return self.peek(offset + 1).map(|token| token.kind())
+                            == Some(SyntaxKind::EqualsGreaterToken);
+                    }
+                }
+                Some(SyntaxKind::EndOfFileToken) | None => return false,
+                Some(_) => {}
+            }
+            offset += 1;
+        }
     }
 
     fn parse_expression(&mut self) -> ExpressionSyntax {
@@ -46,36 +397,101 @@ impl<'reporter> Parser<'reporter> {
 
     fn parse_assignment_expression(&mut self) -> ExpressionSyntax {
         if self.peek(0).unwrap().kind() == SyntaxKind::IdentifierToken
-            && self.peek(1).unwrap().kind() == SyntaxKind::EqualsToken
+            && matches!(
+                self.peek(1).unwrap().kind(),
+                SyntaxKind::EqualsToken | SyntaxKind::ColonToken
+            )
         {
             let identifier_token = self.next_token();
-            let equals_token = self.next_token();
+            let type_clause = if self.current().kind() == SyntaxKind::ColonToken {
+                Some(self.parse_type_clause())
+            } else {
+                None
+            };
+            let equals_token = self.match_token(SyntaxKind::EqualsToken);
             let right = self.parse_assignment_expression();
             ExpressionSyntax::Assignment {
                 identifier_token,
+                type_clause,
                 equals_token,
                 expression: Box::new(right),
             }
         } else {
-            self.parse_binary_expression(0)
+            let left = self.parse_binary_expression(0);
+            if syntax_facts::update_operator_kind(self.current().kind()).is_some()
+                && !syntax_facts::is_update_operator(self.current().kind())
+            {
+                let operator_token = self.next_token();
+                let right = self.parse_assignment_expression();
+                return ExpressionSyntax::CompoundAssignment {
+                    target: Box::new(left),
+                    operator_token,
+                    expression: Box::new(right),
+                };
+            }
+            if self.current().kind() != SyntaxKind::EqualsToken {
+                return left;
+            }
+            match left {
+                ExpressionSyntax::Index { .. } => {
+                    let equals_token = self.next_token();
+                    let right = self.parse_assignment_expression();
+                    ExpressionSyntax::ElementAssignment {
+                        target: Box::new(left),
+                        equals_token,
+                        expression: Box::new(right),
+                    }
+                }
+                ExpressionSyntax::FieldAccess { ref dot_token, .. }
+                    if dot_token.kind() == SyntaxKind::DotToken =>
+                {
+                    let equals_token = self.next_token();
+                    let right = self.parse_assignment_expression();
+                    ExpressionSyntax::FieldAssignment {
+                        target: Box::new(left),
+                        equals_token,
+                        expression: Box::new(right),
+                    }
+                }
+                left => left,
+            }
         }
     }
 
     fn parse_binary_expression(&mut self, parent_precedence: usize) -> ExpressionSyntax {
         let unary_operator_precedence = self.current().kind().unary_operator_precedence();
-        let mut left =
-            if unary_operator_precedence != 0 && unary_operator_precedence >= parent_precedence {
-                let operator = self.next_token();
-                let operand = self.parse_binary_expression(unary_operator_precedence);
-                ExpressionSyntax::Unary {
-                    operator,
-                    operand: Box::new(operand),
-                }
-            } else {
-                self.parse_primary_expression()
-            };
+        let mut left = if syntax_facts::is_update_operator(self.current().kind()) {
+            let operator_token = self.next_token();
+            let operand = self.parse_binary_expression(syntax_facts::UPDATE_PRECEDENCE);
+            ExpressionSyntax::PrefixUpdate {
+                operator_token,
+                operand: Box::new(operand),
+            }
+        } else if unary_operator_precedence != 0 && unary_operator_precedence >= parent_precedence {
+            let operator = self.next_token();
+            let operand = self.parse_binary_expression(unary_operator_precedence);
+            ExpressionSyntax::Unary {
+                operator,
+                operand: Box::new(operand),
+            }
+        } else {
+            self.parse_postfix_expression()
+        };
 
         loop {
+            if self.current().kind() == SyntaxKind::AsKeyword
+                && syntax_facts::CAST_PRECEDENCE > parent_precedence
+            {
+                let as_keyword = self.next_token();
+                let type_syntax = self.parse_type();
+                left = ExpressionSyntax::Cast {
+                    expression: Box::new(left),
+                    as_keyword,
+                    type_syntax,
+                };
+                continue;
+            }
+
             let precedence = self.current().kind().binary_operator_precedence();
             if precedence == 0 || precedence <= parent_precedence {
                 break;
@@ -93,26 +509,359 @@ impl<'reporter> Parser<'reporter> {
         left
     }
 
+    fn parse_postfix_expression(&mut self) -> ExpressionSyntax {
+        let mut expression = self.parse_primary_expression();
+        loop {
+            expression = match self.current().kind() {
+                SyntaxKind::OpenBracketToken => {
+                    let open_bracket_token = self.next_token();
+                    let index = self.parse_expression();
+                    let close_bracket_token = self.match_token(SyntaxKind::CloseBracketToken);
+                    ExpressionSyntax::Index {
+                        expression: Box::new(expression),
+                        open_bracket_token,
+                        index: Box::new(index),
+                        close_bracket_token,
+                    }
+                }
+                // Members follow one another with only a space between, so
+                // `f (x)` is `f` and then `(x)`, while `f(x)` is a call.
+                SyntaxKind::OpenParenthesisToken
+                    if self.current().span().start == expression.span().end =>
+                {
+                    let open_parenthesis_token = self.next_token();
+                    let arguments = self.parse_separated_list(
+                        SyntaxKind::CloseParenthesisToken,
+                        Self::parse_expression,
+                    );
+                    let close_parenthesis_token =
+                        self.match_token(SyntaxKind::CloseParenthesisToken);
+                    ExpressionSyntax::Invocation {
+                        expression: Box::new(expression),
+                        open_parenthesis_token,
+                        arguments,
+                        close_parenthesis_token,
+                    }
+                }
+                // Likewise `x ++y` is `x` and then `++y`.
+                SyntaxKind::PlusPlusToken | SyntaxKind::MinusMinusToken
+                    if self.current().span().start == expression.span().end =>
+                {
+                    let operator_token = self.next_token();
+                    ExpressionSyntax::PostfixUpdate {
+                        operand: Box::new(expression),
+                        operator_token,
+                    }
+                }
+                SyntaxKind::QuestionDotToken => {
+                    let dot_token = self.next_token();
+                    let identifier_token = self.match_token(SyntaxKind::IdentifierToken);
+                    ExpressionSyntax::FieldAccess {
+                        expression: Box::new(expression),
+                        dot_token,
+                        identifier_token,
+                    }
+                }
+                SyntaxKind::DotToken => {
+                    let dot_token = self.next_token();
+                    let identifier_token = self.match_token(SyntaxKind::IdentifierToken);
+                    if self.current().kind() == SyntaxKind::OpenParenthesisToken {
+                        let open_parenthesis_token = self.next_token();
+                        let arguments = self.parse_separated_list(
+                            SyntaxKind::CloseParenthesisToken,
+                            Self::parse_expression,
+                        );
+                        let close_parenthesis_token =
+                            self.match_token(SyntaxKind::CloseParenthesisToken);
+                        ExpressionSyntax::MemberCall {
+                            expression: Box::new(expression),
+                            dot_token,
+                            identifier_token,
+                            open_parenthesis_token,
+                            arguments,
+                            close_parenthesis_token,
+                        }
+                    } else {
+                        ExpressionSyntax::FieldAccess {
+                            expression: Box::new(expression),
+                            dot_token,
+                            identifier_token,
+                        }
+                    }
+                }
+                _ => return expression,
+            };
+        }
+    }
+
     fn parse_primary_expression(&mut self) -> ExpressionSyntax {
         match self.current().kind() {
+            SyntaxKind::OpenParenthesisToken if self.at_lambda_start() => {
+                self.parse_lambda_expression()
+            }
             SyntaxKind::OpenParenthesisToken => self.parse_parenthesized_expression(),
+            SyntaxKind::OpenBracketToken => self.parse_array_expression(),
             SyntaxKind::TrueKeyword | SyntaxKind::FalseKeyword => self.parse_boolean_literal(),
-            SyntaxKind::NumberToken => self.parse_number_literal(),
+            SyntaxKind::NumberToken | SyntaxKind::BadToken => self.parse_number_literal(),
+            SyntaxKind::StringToken => self.parse_string_literal(),
+            SyntaxKind::InterpolatedStringStartToken => self.parse_interpolated_string(),
+            SyntaxKind::NullKeyword => self.parse_null_literal(),
+            SyntaxKind::MatchKeyword => self.parse_match_expression(),
+            SyntaxKind::IdentifierToken => match self.identifier_follower() {
+                Some(SyntaxKind::OpenParenthesisToken) => self.parse_call_expression(),
+                Some(SyntaxKind::OpenBraceToken) if self.allow_struct_literals => {
+                    self.parse_struct_construction_expression()
+                }
+                Some(SyntaxKind::DotToken)
+                    if self.peek(1).map(|t| t.kind()) == Some(SyntaxKind::LessToken) =>
+                {
+                    self.parse_generic_name_expression()
+                }
+                _ => self.parse_name_expression(),
+            },
             _ => self.parse_name_expression(),
         }
     }
 
+    fn parse_call_expression(&mut self) -> ExpressionSyntax {
+        let identifier_token = self.match_token(SyntaxKind::IdentifierToken);
+        let type_argument_list = self.parse_optional_type_argument_list();
+        let open_parenthesis_token = self.match_token(SyntaxKind::OpenParenthesisToken);
+        let arguments =
+            self.parse_separated_list(SyntaxKind::CloseParenthesisToken, Self::parse_expression);
+        let close_parenthesis_token = self.match_token(SyntaxKind::CloseParenthesisToken);
+        ExpressionSyntax::Call {
+            identifier_token,
+            type_argument_list,
+            open_parenthesis_token,
+            arguments,
+            close_parenthesis_token,
+        }
+    }
+
+    fn parse_array_expression(&mut self) -> ExpressionSyntax {
+        let open_bracket_token = self.match_token(SyntaxKind::OpenBracketToken);
+        let elements =
+            self.parse_separated_list(SyntaxKind::CloseBracketToken, Self::parse_expression);
+        let close_bracket_token = self.match_token(SyntaxKind::CloseBracketToken);
+        ExpressionSyntax::Array {
+            open_bracket_token,
+            elements,
+            close_bracket_token,
+        }
+    }
+
+    fn parse_struct_construction_expression(&mut self) -> ExpressionSyntax {
+        let identifier_token = self.match_token(SyntaxKind::IdentifierToken);
+        let type_argument_list = self.parse_optional_type_argument_list();
+        let open_brace_token = self.match_token(SyntaxKind::OpenBraceToken);
+        let fields =
+            self.parse_separated_list(SyntaxKind::CloseBraceToken, Self::parse_field_initializer);
+        let close_brace_token = self.match_token(SyntaxKind::CloseBraceToken);
+        ExpressionSyntax::StructConstruction {
+            identifier_token,
+            type_argument_list,
+            open_brace_token,
+            fields,
+            close_brace_token,
+        }
+    }
+
+    fn parse_match_expression(&mut self) -> ExpressionSyntax {
+        let match_keyword = self.match_token(SyntaxKind::MatchKeyword);
+        let allow_struct_literals = std::mem::replace(&mut self.allow_struct_literals, false);
+        let expression = self.parse_expression();
+        self.allow_struct_literals = allow_struct_literals;
+        let open_brace_token = self.match_token(SyntaxKind::OpenBraceToken);
+        let arms = self.parse_separated_list(SyntaxKind::CloseBraceToken, Self::parse_match_arm);
+        let close_brace_token = self.match_token(SyntaxKind::CloseBraceToken);
+        ExpressionSyntax::Match {
+            match_keyword,
+            expression: Box::new(expression),
+            open_brace_token,
+            arms,
+            close_brace_token,
+        }
+    }
+
+    fn parse_match_arm(&mut self) -> MatchArmSyntax {
+        let pattern = self.parse_pattern();
+        let equals_greater_token = self.match_token(SyntaxKind::EqualsGreaterToken);
+        let expression = self.parse_expression();
+        MatchArmSyntax {
+            pattern,
+            equals_greater_token,
+            expression,
+        }
+    }
+
+    fn parse_pattern(&mut self) -> PatternSyntax {
+        match self.current().kind() {
+            SyntaxKind::NumberToken | SyntaxKind::BadToken => {
+                let literal_token = self.next_token();
+                PatternSyntax::Literal {
+                    literal_token,
+                    value: None,
+                }
+            }
+            SyntaxKind::TrueKeyword | SyntaxKind::FalseKeyword | SyntaxKind::NullKeyword => {
+                let value = match self.current().kind() {
+                    SyntaxKind::NullKeyword => SilverValue::Null,
+                    kind => SilverValue::Boolean(kind == SyntaxKind::TrueKeyword),
+                };
+                let literal_token = self.next_token();
+                PatternSyntax::Literal {
+                    literal_token,
+                    value: Some(value),
+                }
+            }
+            _ if self.peek(1).map(|t| t.kind()) == Some(SyntaxKind::DotToken) => {
+                self.parse_variant_pattern()
+            }
+            _ => {
+                let identifier_token = self.match_token(SyntaxKind::IdentifierToken);
+                PatternSyntax::Binding { identifier_token }
+            }
+        }
+    }
+
+    fn parse_variant_pattern(&mut self) -> PatternSyntax {
+        let type_identifier_token = self.match_token(SyntaxKind::IdentifierToken);
+        let dot_token = self.match_token(SyntaxKind::DotToken);
+        let identifier_token = self.match_token(SyntaxKind::IdentifierToken);
+        let payload = if self.current().kind() == SyntaxKind::OpenParenthesisToken {
+            let open_parenthesis_token = self.next_token();
+            let patterns =
+                self.parse_separated_list(SyntaxKind::CloseParenthesisToken, Self::parse_pattern);
+            let close_parenthesis_token = self.match_token(SyntaxKind::CloseParenthesisToken);
+            Some(Box::new(PatternPayloadSyntax {
+                open_parenthesis_token,
+                patterns,
+                close_parenthesis_token,
+            }))
+        } else {
+            None
+        };
+        PatternSyntax::Variant {
+            type_identifier_token,
+            dot_token,
+            identifier_token,
+            payload,
+        }
+    }
+
+    fn parse_field_initializer(&mut self) -> FieldInitializerSyntax {
+        let identifier_token = self.match_token(SyntaxKind::IdentifierToken);
+        let colon_token = self.match_token(SyntaxKind::ColonToken);
+        let expression = self.parse_expression();
+        FieldInitializerSyntax {
+            identifier_token,
+            colon_token,
+            expression,
+        }
+    }
+
+    /// Comma-separated nodes up to, but not including, `close_kind`.
+    fn parse_separated_list<T>(
+        &mut self,
+        close_kind: SyntaxKind,
+        parse_node: fn(&mut Self) -> T,
+    ) -> SeparatedSyntaxList<T> {
+        // The list's delimiters keep a struct construction inside it from
+        // being mistaken for the start of the arms of an enclosing match.
+        let allow_struct_literals = std::mem::replace(&mut self.allow_struct_literals, true);
+        let mut nodes = vec![];
+        let mut separators = vec![];
+
+        while self.current().kind() != close_kind
+            && self.current().kind() != SyntaxKind::EndOfFileToken
+        {
+            nodes.push(parse_node(self));
+            if self.current().kind() != SyntaxKind::CommaToken {
+                break;
+            }
+            separators.push(self.match_token(SyntaxKind::CommaToken));
+        }
+        self.allow_struct_literals = allow_struct_literals;
+
+        SeparatedSyntaxList::new(nodes, separators)
+    }
+
+    /// Parse a number, or a malformed one as a literal without a value.
     fn parse_number_literal(&mut self) -> ExpressionSyntax {
-        let literal_token = self.match_token(SyntaxKind::NumberToken);
+        let literal_token = self.next_token();
+        ExpressionSyntax::Literal {
+            literal_token,
+            value: None,
+        }
+    }
+
+    fn parse_string_literal(&mut self) -> ExpressionSyntax {
+        let literal_token = self.match_token(SyntaxKind::StringToken);
         ExpressionSyntax::Literal {
             literal_token,
             value: None,
         }
     }
 
+    fn parse_interpolated_string(&mut self) -> ExpressionSyntax {
+        let start_token = self.match_token(SyntaxKind::InterpolatedStringStartToken);
+        let mut parts = vec![];
+        loop {
+            match self.current().kind() {
+                SyntaxKind::InterpolatedStringTextToken => {
+                    parts.push(InterpolatedStringPartSyntax::Text {
+                        text_token: self.next_token(),
+                    })
+                }
+                SyntaxKind::OpenBraceToken => parts.push(self.parse_interpolation()),
+                _ => break,
+            }
+        }
+        let end_token = self.match_token(SyntaxKind::InterpolatedStringEndToken);
+        ExpressionSyntax::InterpolatedString {
+            start_token,
+            parts,
+            end_token,
+        }
+    }
+
+    fn parse_interpolation(&mut self) -> InterpolatedStringPartSyntax {
+        let open_brace_token = self.match_token(SyntaxKind::OpenBraceToken);
+        let allow_struct_literals = std::mem::replace(&mut self.allow_struct_literals, true);
+        let expression = self.parse_expression();
+        self.allow_struct_literals = allow_struct_literals;
+        let mut close_brace_token = self.match_token(SyntaxKind::CloseBraceToken);
+        if close_brace_token.text().is_empty() {
+            // Whatever else the hole holds has been reported, and is skipped
+            // so that the rest of the string still parses.
+            let mut depth = 0;
+            loop {
+                match self.current().kind() {
+                    SyntaxKind::CloseBraceToken if depth == 0 => break,
+                    SyntaxKind::CloseBraceToken => depth -= 1,
+                    SyntaxKind::OpenBraceToken => depth += 1,
+                    SyntaxKind::InterpolatedStringEndToken | SyntaxKind::EndOfFileToken => break,
+                    _ => {}
+                }
+                self.next_token();
+            }
+            if self.current().kind() == SyntaxKind::CloseBraceToken {
+                close_brace_token = self.next_token();
+            }
+        }
+        InterpolatedStringPartSyntax::Interpolation {
+            open_brace_token,
+            expression: Box::new(expression),
+            close_brace_token,
+        }
+    }
+
     fn parse_parenthesized_expression(&mut self) -> ExpressionSyntax {
         let open_parenthesis_token = self.match_token(SyntaxKind::OpenParenthesisToken);
+        let allow_struct_literals = std::mem::replace(&mut self.allow_struct_literals, true);
         let expression = self.parse_expression();
+        self.allow_struct_literals = allow_struct_literals;
         let close_parenthesis_token = self.match_token(SyntaxKind::CloseParenthesisToken);
         ExpressionSyntax::Parenthesized {
             open_parenthesis_token,
@@ -121,6 +870,32 @@ impl<'reporter> Parser<'reporter> {
         }
     }
 
+    fn parse_lambda_expression(&mut self) -> ExpressionSyntax {
+        let open_parenthesis_token = self.match_token(SyntaxKind::OpenParenthesisToken);
+        let parameters =
+            self.parse_separated_list(SyntaxKind::CloseParenthesisToken, Self::parse_parameter);
+        let close_parenthesis_token = self.match_token(SyntaxKind::CloseParenthesisToken);
+        let equals_greater_token = self.match_token(SyntaxKind::EqualsGreaterToken);
+        let allow_struct_literals = std::mem::replace(&mut self.allow_struct_literals, true);
+        let body = self.parse_expression();
+        self.allow_struct_literals = allow_struct_literals;
+        ExpressionSyntax::Lambda {
+            open_parenthesis_token,
+            parameters,
+            close_parenthesis_token,
+            equals_greater_token,
+            body: Box::new(body),
+        }
+    }
+
+    fn parse_null_literal(&mut self) -> ExpressionSyntax {
+        let null_keyword = self.match_token(SyntaxKind::NullKeyword);
+        ExpressionSyntax::Literal {
+            literal_token: null_keyword,
+            value: Some(SilverValue::Null),
+        }
+    }
+
     fn parse_boolean_literal(&mut self) -> ExpressionSyntax {
         let is_true = self.current().kind() == SyntaxKind::TrueKeyword;
         let keyword_token = if is_true {
@@ -134,6 +909,15 @@ impl<'reporter> Parser<'reporter> {
         }
     }
 
+    fn parse_generic_name_expression(&mut self) -> ExpressionSyntax {
+        let identifier_token = self.match_token(SyntaxKind::IdentifierToken);
+        let type_argument_list = self.parse_type_argument_list();
+        ExpressionSyntax::GenericName {
+            identifier_token,
+            type_argument_list,
+        }
+    }
+
     fn parse_name_expression(&mut self) -> ExpressionSyntax {
         let identifier_token = self.match_token(SyntaxKind::IdentifierToken);
         ExpressionSyntax::Name { identifier_token }
@@ -186,6 +970,14 @@ mod tests {
     use pretty_assertions::assert_eq;
     use strum::IntoEnumIterator;
 
+    /// The expression a tree with a single expression member consists of.
+    fn root_expression(tree: &SyntaxTree) -> &ExpressionSyntax {
+        match tree.root().members.as_slice() {
+            [MemberSyntax::GlobalExpression { expression }] => expression,
+            members => panic!("expected a single expression, got {:?}", members),
+        }
+    }
+
     fn check(input: &str, expected_tree: ExpressionSyntax) {
         let mut error_reporter = StringErrorReporter::new();
         let actual_tree = Parser::parse(Arc::new(input.to_string().into()), &mut error_reporter);
@@ -193,7 +985,7 @@ mod tests {
             println!("{:?}", error.kind());
         }
         assert!(!error_reporter.had_error(),);
-        assert_eq!(&expected_tree, actual_tree.root());
+        assert_eq!(&expected_tree, root_expression(&actual_tree));
     }
 
     #[test]
@@ -205,7 +997,7 @@ mod tests {
                     SyntaxKind::NumberToken,
                     0,
                     String::from("123"),
-                    Some(SilverValue::Integer(123)),
+                    Some(SilverValue::Integer(123.into())),
                 ),
                 value: None,
             },
@@ -267,7 +1059,7 @@ mod tests {
                             SyntaxKind::NumberToken,
                             1,
                             String::from("1"),
-                            Some(SilverValue::Integer(1)),
+                            Some(SilverValue::Integer(1.into())),
                         ),
                     }),
                 },
@@ -286,7 +1078,7 @@ mod tests {
                             SyntaxKind::NumberToken,
                             0,
                             String::from("1"),
-                            Some(SilverValue::Integer(1)),
+                            Some(SilverValue::Integer(1.into())),
                         ),
                         value: None,
                     }),
@@ -296,7 +1088,7 @@ mod tests {
                             SyntaxKind::NumberToken,
                             1 + binary_op.len(),
                             String::from("2"),
-                            Some(SilverValue::Integer(2)),
+                            Some(SilverValue::Integer(2.into())),
                         ),
                         value: None,
                     }),
@@ -321,7 +1113,7 @@ mod tests {
                         SyntaxKind::NumberToken,
                         1,
                         String::from("1"),
-                        Some(SilverValue::Integer(1)),
+                        Some(SilverValue::Integer(1.into())),
                     ),
                     value: None,
                 }),
@@ -361,13 +1153,14 @@ mod tests {
                     String::from("a"),
                     None,
                 ),
+                type_clause: None,
                 equals_token: SyntaxToken::new(SyntaxKind::EqualsToken, 1, String::from("="), None),
                 expression: Box::new(ExpressionSyntax::Literal {
                     literal_token: SyntaxToken::new(
                         SyntaxKind::NumberToken,
                         2,
                         String::from("2"),
-                        Some(SilverValue::Integer(2)),
+                        Some(SilverValue::Integer(2.into())),
                     ),
                     value: None,
                 }),
@@ -375,6 +1168,611 @@ mod tests {
         )
     }
 
+    #[test]
+    fn parse_call_expression() {
+        let tree = SyntaxTree::parse_str("f(a, 1)", &mut NullErrorReporter::new());
+        let mut e = AssertingIterator::new(root_expression(&tree));
+        e.assert_node(SyntaxKind::CallExpression);
+        e.assert_token(SyntaxKind::IdentifierToken, "f");
+        e.assert_token(SyntaxKind::OpenParenthesisToken, "(");
+        e.assert_node(SyntaxKind::NameExpression);
+        e.assert_token(SyntaxKind::IdentifierToken, "a");
+        e.assert_token(SyntaxKind::CommaToken, ",");
+        e.assert_node(SyntaxKind::LiteralExpression);
+        e.assert_token(SyntaxKind::NumberToken, "1");
+        e.assert_token(SyntaxKind::CloseParenthesisToken, ")");
+        e.assert_at_end();
+    }
+
+    #[test]
+    fn parse_array_expression() {
+        let tree = SyntaxTree::parse_str("[1, a]", &mut NullErrorReporter::new());
+        let mut e = AssertingIterator::new(root_expression(&tree));
+        e.assert_node(SyntaxKind::ArrayExpression);
+        e.assert_token(SyntaxKind::OpenBracketToken, "[");
+        e.assert_node(SyntaxKind::LiteralExpression);
+        e.assert_token(SyntaxKind::NumberToken, "1");
+        e.assert_token(SyntaxKind::CommaToken, ",");
+        e.assert_node(SyntaxKind::NameExpression);
+        e.assert_token(SyntaxKind::IdentifierToken, "a");
+        e.assert_token(SyntaxKind::CloseBracketToken, "]");
+        e.assert_at_end();
+    }
+
+    #[test]
+    fn index_binds_tighter_than_unary_operators() {
+        let tree = SyntaxTree::parse_str("-a[0][1]", &mut NullErrorReporter::new());
+        let mut e = AssertingIterator::new(root_expression(&tree));
+        e.assert_node(SyntaxKind::UnaryExpression);
+        e.assert_token(SyntaxKind::MinusToken, "-");
+        e.assert_node(SyntaxKind::IndexExpression);
+        e.assert_node(SyntaxKind::IndexExpression);
+        e.assert_node(SyntaxKind::NameExpression);
+        e.assert_token(SyntaxKind::IdentifierToken, "a");
+        e.assert_token(SyntaxKind::OpenBracketToken, "[");
+        e.assert_node(SyntaxKind::LiteralExpression);
+        e.assert_token(SyntaxKind::NumberToken, "0");
+        e.assert_token(SyntaxKind::CloseBracketToken, "]");
+        e.assert_token(SyntaxKind::OpenBracketToken, "[");
+        e.assert_node(SyntaxKind::LiteralExpression);
+        e.assert_token(SyntaxKind::NumberToken, "1");
+        e.assert_token(SyntaxKind::CloseBracketToken, "]");
+        e.assert_at_end();
+    }
+
+    #[test]
+    fn parse_element_assignment_expression() {
+        let tree = SyntaxTree::parse_str("a[i] = b = 1", &mut NullErrorReporter::new());
+        let mut e = AssertingIterator::new(root_expression(&tree));
+        e.assert_node(SyntaxKind::ElementAssignmentExpression);
+        e.assert_node(SyntaxKind::IndexExpression);
+        e.assert_node(SyntaxKind::NameExpression);
+        e.assert_token(SyntaxKind::IdentifierToken, "a");
+        e.assert_token(SyntaxKind::OpenBracketToken, "[");
+        e.assert_node(SyntaxKind::NameExpression);
+        e.assert_token(SyntaxKind::IdentifierToken, "i");
+        e.assert_token(SyntaxKind::CloseBracketToken, "]");
+        e.assert_token(SyntaxKind::EqualsToken, "=");
+        e.assert_node(SyntaxKind::AssignmentExpression);
+        e.assert_token(SyntaxKind::IdentifierToken, "b");
+        e.assert_token(SyntaxKind::EqualsToken, "=");
+        e.assert_node(SyntaxKind::LiteralExpression);
+        e.assert_token(SyntaxKind::NumberToken, "1");
+        e.assert_at_end();
+    }
+
+    #[test]
+    fn parse_struct_declaration() {
+        let tree = SyntaxTree::parse_str(
+            "struct P { x: int, ys: [i8] }",
+            &mut NullErrorReporter::new(),
+        );
+        let mut e = AssertingIterator::new(tree.root());
+        e.assert_node(SyntaxKind::CompilationUnit);
+        e.assert_node(SyntaxKind::StructDeclaration);
+        e.assert_token(SyntaxKind::StructKeyword, "struct");
+        e.assert_token(SyntaxKind::IdentifierToken, "P");
+        e.assert_token(SyntaxKind::OpenBraceToken, "{");
+        e.assert_node(SyntaxKind::FieldDeclaration);
+        e.assert_token(SyntaxKind::IdentifierToken, "x");
+        e.assert_node(SyntaxKind::TypeClause);
+        e.assert_token(SyntaxKind::ColonToken, ":");
+        e.assert_node(SyntaxKind::NameType);
+        e.assert_token(SyntaxKind::IdentifierToken, "int");
+        e.assert_token(SyntaxKind::CommaToken, ",");
+        e.assert_node(SyntaxKind::FieldDeclaration);
+        e.assert_token(SyntaxKind::IdentifierToken, "ys");
+        e.assert_node(SyntaxKind::TypeClause);
+        e.assert_token(SyntaxKind::ColonToken, ":");
+        e.assert_node(SyntaxKind::ArrayType);
+        e.assert_token(SyntaxKind::OpenBracketToken, "[");
+        e.assert_node(SyntaxKind::NameType);
+        e.assert_token(SyntaxKind::IdentifierToken, "i8");
+        e.assert_token(SyntaxKind::CloseBracketToken, "]");
+        e.assert_token(SyntaxKind::CloseBraceToken, "}");
+        e.assert_at_end();
+    }
+
+    #[test]
+    fn parse_struct_construction_and_field_assignment() {
+        let tree = SyntaxTree::parse_str("P { x: 1 }.x = 2", &mut NullErrorReporter::new());
+        let mut e = AssertingIterator::new(root_expression(&tree));
+        e.assert_node(SyntaxKind::FieldAssignmentExpression);
+        e.assert_node(SyntaxKind::FieldAccessExpression);
+        e.assert_node(SyntaxKind::StructConstructionExpression);
+        e.assert_token(SyntaxKind::IdentifierToken, "P");
+        e.assert_token(SyntaxKind::OpenBraceToken, "{");
+        e.assert_node(SyntaxKind::FieldInitializer);
+        e.assert_token(SyntaxKind::IdentifierToken, "x");
+        e.assert_token(SyntaxKind::ColonToken, ":");
+        e.assert_node(SyntaxKind::LiteralExpression);
+        e.assert_token(SyntaxKind::NumberToken, "1");
+        e.assert_token(SyntaxKind::CloseBraceToken, "}");
+        e.assert_token(SyntaxKind::DotToken, ".");
+        e.assert_token(SyntaxKind::IdentifierToken, "x");
+        e.assert_token(SyntaxKind::EqualsToken, "=");
+        e.assert_node(SyntaxKind::LiteralExpression);
+        e.assert_token(SyntaxKind::NumberToken, "2");
+        e.assert_at_end();
+    }
+
+    #[test]
+    fn parse_nullable_type_and_null_operators() {
+        let tree = SyntaxTree::parse_str(
+            "struct P { x: int? } a ?? b?.x == null",
+            &mut NullErrorReporter::new(),
+        );
+        let mut e = AssertingIterator::new(tree.root());
+        e.assert_node(SyntaxKind::CompilationUnit);
+        e.assert_node(SyntaxKind::StructDeclaration);
+        e.assert_token(SyntaxKind::StructKeyword, "struct");
+        e.assert_token(SyntaxKind::IdentifierToken, "P");
+        e.assert_token(SyntaxKind::OpenBraceToken, "{");
+        e.assert_node(SyntaxKind::FieldDeclaration);
+        e.assert_token(SyntaxKind::IdentifierToken, "x");
+        e.assert_node(SyntaxKind::TypeClause);
+        e.assert_token(SyntaxKind::ColonToken, ":");
+        e.assert_node(SyntaxKind::NullableType);
+        e.assert_node(SyntaxKind::NameType);
+        e.assert_token(SyntaxKind::IdentifierToken, "int");
+        e.assert_token(SyntaxKind::QuestionToken, "?");
+        e.assert_token(SyntaxKind::CloseBraceToken, "}");
+        e.assert_node(SyntaxKind::GlobalExpression);
+        e.assert_node(SyntaxKind::BinaryExpression);
+        e.assert_node(SyntaxKind::NameExpression);
+        e.assert_token(SyntaxKind::IdentifierToken, "a");
+        e.assert_token(SyntaxKind::QuestionQuestionToken, "??");
+        e.assert_node(SyntaxKind::BinaryExpression);
+        e.assert_node(SyntaxKind::FieldAccessExpression);
+        e.assert_node(SyntaxKind::NameExpression);
+        e.assert_token(SyntaxKind::IdentifierToken, "b");
+        e.assert_token(SyntaxKind::QuestionDotToken, "?.");
+        e.assert_token(SyntaxKind::IdentifierToken, "x");
+        e.assert_token(SyntaxKind::EqualsEqualsToken, "==");
+        e.assert_node(SyntaxKind::LiteralExpression);
+        e.assert_token(SyntaxKind::NullKeyword, "null");
+        e.assert_at_end();
+    }
+
+    #[test]
+    fn parse_function_declaration() {
+        let tree =
+            SyntaxTree::parse_str("fn f(a: int, b): bool => a", &mut NullErrorReporter::new());
+        let mut e = AssertingIterator::new(tree.root());
+        e.assert_node(SyntaxKind::CompilationUnit);
+        e.assert_node(SyntaxKind::FunctionDeclaration);
+        e.assert_token(SyntaxKind::FnKeyword, "fn");
+        e.assert_token(SyntaxKind::IdentifierToken, "f");
+        e.assert_token(SyntaxKind::OpenParenthesisToken, "(");
+        e.assert_node(SyntaxKind::Parameter);
+        e.assert_token(SyntaxKind::IdentifierToken, "a");
+        e.assert_node(SyntaxKind::TypeClause);
+        e.assert_token(SyntaxKind::ColonToken, ":");
+        e.assert_node(SyntaxKind::NameType);
+        e.assert_token(SyntaxKind::IdentifierToken, "int");
+        e.assert_token(SyntaxKind::CommaToken, ",");
+        e.assert_node(SyntaxKind::Parameter);
+        e.assert_token(SyntaxKind::IdentifierToken, "b");
+        e.assert_token(SyntaxKind::CloseParenthesisToken, ")");
+        e.assert_node(SyntaxKind::TypeClause);
+        e.assert_token(SyntaxKind::ColonToken, ":");
+        e.assert_node(SyntaxKind::NameType);
+        e.assert_token(SyntaxKind::IdentifierToken, "bool");
+        e.assert_token(SyntaxKind::EqualsGreaterToken, "=>");
+        e.assert_node(SyntaxKind::NameExpression);
+        e.assert_token(SyntaxKind::IdentifierToken, "a");
+        e.assert_at_end();
+    }
+
+    #[test]
+    fn parse_annotated_assignment_and_casts() {
+        let tree =
+            SyntaxTree::parse_str("x: i8? = -a as i8 * b as i8", &mut NullErrorReporter::new());
+        let mut e = AssertingIterator::new(root_expression(&tree));
+        e.assert_node(SyntaxKind::AssignmentExpression);
+        e.assert_token(SyntaxKind::IdentifierToken, "x");
+        e.assert_node(SyntaxKind::TypeClause);
+        e.assert_token(SyntaxKind::ColonToken, ":");
+        e.assert_node(SyntaxKind::NullableType);
+        e.assert_node(SyntaxKind::NameType);
+        e.assert_token(SyntaxKind::IdentifierToken, "i8");
+        e.assert_token(SyntaxKind::QuestionToken, "?");
+        e.assert_token(SyntaxKind::EqualsToken, "=");
+        e.assert_node(SyntaxKind::BinaryExpression);
+        e.assert_node(SyntaxKind::CastExpression);
+        e.assert_node(SyntaxKind::UnaryExpression);
+        e.assert_token(SyntaxKind::MinusToken, "-");
+        e.assert_node(SyntaxKind::NameExpression);
+        e.assert_token(SyntaxKind::IdentifierToken, "a");
+        e.assert_token(SyntaxKind::AsKeyword, "as");
+        e.assert_node(SyntaxKind::NameType);
+        e.assert_token(SyntaxKind::IdentifierToken, "i8");
+        e.assert_token(SyntaxKind::StarToken, "*");
+        e.assert_node(SyntaxKind::CastExpression);
+        e.assert_node(SyntaxKind::NameExpression);
+        e.assert_token(SyntaxKind::IdentifierToken, "b");
+        e.assert_token(SyntaxKind::AsKeyword, "as");
+        e.assert_node(SyntaxKind::NameType);
+        e.assert_token(SyntaxKind::IdentifierToken, "i8");
+        e.assert_at_end();
+    }
+
+    #[test]
+    fn parse_generic_declaration() {
+        let tree = SyntaxTree::parse_str(
+            "fn f<T, U>(x: P<T, [U]>) => x",
+            &mut NullErrorReporter::new(),
+        );
+        let mut e = AssertingIterator::new(tree.root());
+        e.assert_node(SyntaxKind::CompilationUnit);
+        e.assert_node(SyntaxKind::FunctionDeclaration);
+        e.assert_token(SyntaxKind::FnKeyword, "fn");
+        e.assert_token(SyntaxKind::IdentifierToken, "f");
+        e.assert_node(SyntaxKind::TypeParameterList);
+        e.assert_token(SyntaxKind::LessToken, "<");
+        e.assert_token(SyntaxKind::IdentifierToken, "T");
+        e.assert_token(SyntaxKind::CommaToken, ",");
+        e.assert_token(SyntaxKind::IdentifierToken, "U");
+        e.assert_token(SyntaxKind::GreaterToken, ">");
+        e.assert_token(SyntaxKind::OpenParenthesisToken, "(");
+        e.assert_node(SyntaxKind::Parameter);
+        e.assert_token(SyntaxKind::IdentifierToken, "x");
+        e.assert_node(SyntaxKind::TypeClause);
+        e.assert_token(SyntaxKind::ColonToken, ":");
+        e.assert_node(SyntaxKind::GenericType);
+        e.assert_token(SyntaxKind::IdentifierToken, "P");
+        e.assert_node(SyntaxKind::TypeArgumentList);
+        e.assert_token(SyntaxKind::LessToken, "<");
+        e.assert_node(SyntaxKind::NameType);
+        e.assert_token(SyntaxKind::IdentifierToken, "T");
+        e.assert_token(SyntaxKind::CommaToken, ",");
+        e.assert_node(SyntaxKind::ArrayType);
+        e.assert_token(SyntaxKind::OpenBracketToken, "[");
+        e.assert_node(SyntaxKind::NameType);
+        e.assert_token(SyntaxKind::IdentifierToken, "U");
+        e.assert_token(SyntaxKind::CloseBracketToken, "]");
+        e.assert_token(SyntaxKind::GreaterToken, ">");
+        e.assert_token(SyntaxKind::CloseParenthesisToken, ")");
+        e.assert_token(SyntaxKind::EqualsGreaterToken, "=>");
+        e.assert_node(SyntaxKind::NameExpression);
+        e.assert_token(SyntaxKind::IdentifierToken, "x");
+        e.assert_at_end();
+    }
+
+    #[test]
+    fn parse_generic_names() {
+        let tree = SyntaxTree::parse_str("f<int>(O<T>.A) < b", &mut NullErrorReporter::new());
+        let mut e = AssertingIterator::new(root_expression(&tree));
+        e.assert_node(SyntaxKind::BinaryExpression);
+        e.assert_node(SyntaxKind::CallExpression);
+        e.assert_token(SyntaxKind::IdentifierToken, "f");
+        e.assert_node(SyntaxKind::TypeArgumentList);
+        e.assert_token(SyntaxKind::LessToken, "<");
+        e.assert_node(SyntaxKind::NameType);
+        e.assert_token(SyntaxKind::IdentifierToken, "int");
+        e.assert_token(SyntaxKind::GreaterToken, ">");
+        e.assert_token(SyntaxKind::OpenParenthesisToken, "(");
+        e.assert_node(SyntaxKind::FieldAccessExpression);
+        e.assert_node(SyntaxKind::GenericNameExpression);
+        e.assert_token(SyntaxKind::IdentifierToken, "O");
+        e.assert_node(SyntaxKind::TypeArgumentList);
+        e.assert_token(SyntaxKind::LessToken, "<");
+        e.assert_node(SyntaxKind::NameType);
+        e.assert_token(SyntaxKind::IdentifierToken, "T");
+        e.assert_token(SyntaxKind::GreaterToken, ">");
+        e.assert_token(SyntaxKind::DotToken, ".");
+        e.assert_token(SyntaxKind::IdentifierToken, "A");
+        e.assert_token(SyntaxKind::CloseParenthesisToken, ")");
+        e.assert_token(SyntaxKind::LessToken, "<");
+        e.assert_node(SyntaxKind::NameExpression);
+        e.assert_token(SyntaxKind::IdentifierToken, "b");
+        e.assert_at_end();
+
+        // Without a `(`, `.` or `{` after the `>`, the angle brackets compare.
+        let tree = SyntaxTree::parse_str("f(a < b, c > d)", &mut NullErrorReporter::new());
+        let mut e = AssertingIterator::new(root_expression(&tree));
+        e.assert_node(SyntaxKind::CallExpression);
+        e.assert_token(SyntaxKind::IdentifierToken, "f");
+        e.assert_token(SyntaxKind::OpenParenthesisToken, "(");
+        e.assert_node(SyntaxKind::BinaryExpression);
+        e.assert_node(SyntaxKind::NameExpression);
+        e.assert_token(SyntaxKind::IdentifierToken, "a");
+        e.assert_token(SyntaxKind::LessToken, "<");
+        e.assert_node(SyntaxKind::NameExpression);
+        e.assert_token(SyntaxKind::IdentifierToken, "b");
+        e.assert_token(SyntaxKind::CommaToken, ",");
+        e.assert_node(SyntaxKind::BinaryExpression);
+        e.assert_node(SyntaxKind::NameExpression);
+        e.assert_token(SyntaxKind::IdentifierToken, "c");
+        e.assert_token(SyntaxKind::GreaterToken, ">");
+        e.assert_node(SyntaxKind::NameExpression);
+        e.assert_token(SyntaxKind::IdentifierToken, "d");
+        e.assert_token(SyntaxKind::CloseParenthesisToken, ")");
+        e.assert_at_end();
+    }
+
+    #[test]
+    fn parse_update_expressions() {
+        let tree = SyntaxTree::parse_str("a[i++] += --x * 2", &mut NullErrorReporter::new());
+        let mut e = AssertingIterator::new(root_expression(&tree));
+        e.assert_node(SyntaxKind::CompoundAssignmentExpression);
+        e.assert_node(SyntaxKind::IndexExpression);
+        e.assert_node(SyntaxKind::NameExpression);
+        e.assert_token(SyntaxKind::IdentifierToken, "a");
+        e.assert_token(SyntaxKind::OpenBracketToken, "[");
+        e.assert_node(SyntaxKind::PostfixUpdateExpression);
+        e.assert_node(SyntaxKind::NameExpression);
+        e.assert_token(SyntaxKind::IdentifierToken, "i");
+        e.assert_token(SyntaxKind::PlusPlusToken, "++");
+        e.assert_token(SyntaxKind::CloseBracketToken, "]");
+        e.assert_token(SyntaxKind::PlusEqualsToken, "+=");
+        e.assert_node(SyntaxKind::BinaryExpression);
+        e.assert_node(SyntaxKind::PrefixUpdateExpression);
+        e.assert_token(SyntaxKind::MinusMinusToken, "--");
+        e.assert_node(SyntaxKind::NameExpression);
+        e.assert_token(SyntaxKind::IdentifierToken, "x");
+        e.assert_token(SyntaxKind::StarToken, "*");
+        e.assert_node(SyntaxKind::LiteralExpression);
+        e.assert_token(SyntaxKind::NumberToken, "2");
+        e.assert_at_end();
+
+        // Compound assignments are right associative.
+        let tree = SyntaxTree::parse_str("x -= y %= 3", &mut NullErrorReporter::new());
+        let mut e = AssertingIterator::new(root_expression(&tree));
+        e.assert_node(SyntaxKind::CompoundAssignmentExpression);
+        e.assert_node(SyntaxKind::NameExpression);
+        e.assert_token(SyntaxKind::IdentifierToken, "x");
+        e.assert_token(SyntaxKind::MinusEqualsToken, "-=");
+        e.assert_node(SyntaxKind::CompoundAssignmentExpression);
+        e.assert_node(SyntaxKind::NameExpression);
+        e.assert_token(SyntaxKind::IdentifierToken, "y");
+        e.assert_token(SyntaxKind::PercentEqualsToken, "%=");
+        e.assert_node(SyntaxKind::LiteralExpression);
+        e.assert_token(SyntaxKind::NumberToken, "3");
+        e.assert_at_end();
+    }
+
+    #[test]
+    fn parse_interpolated_string() {
+        let tree = SyntaxTree::parse_str(r#"$"a{x + 1}b{"c"}""#, &mut NullErrorReporter::new());
+        let mut e = AssertingIterator::new(root_expression(&tree));
+        e.assert_node(SyntaxKind::InterpolatedStringExpression);
+        e.assert_token(SyntaxKind::InterpolatedStringStartToken, "$\"");
+        e.assert_token(SyntaxKind::InterpolatedStringTextToken, "a");
+        e.assert_node(SyntaxKind::Interpolation);
+        e.assert_token(SyntaxKind::OpenBraceToken, "{");
+        e.assert_node(SyntaxKind::BinaryExpression);
+        e.assert_node(SyntaxKind::NameExpression);
+        e.assert_token(SyntaxKind::IdentifierToken, "x");
+        e.assert_token(SyntaxKind::PlusToken, "+");
+        e.assert_node(SyntaxKind::LiteralExpression);
+        e.assert_token(SyntaxKind::NumberToken, "1");
+        e.assert_token(SyntaxKind::CloseBraceToken, "}");
+        e.assert_token(SyntaxKind::InterpolatedStringTextToken, "b");
+        e.assert_node(SyntaxKind::Interpolation);
+        e.assert_token(SyntaxKind::OpenBraceToken, "{");
+        e.assert_node(SyntaxKind::LiteralExpression);
+        e.assert_token(SyntaxKind::StringToken, "\"c\"");
+        e.assert_token(SyntaxKind::CloseBraceToken, "}");
+        e.assert_token(SyntaxKind::InterpolatedStringEndToken, "\"");
+        e.assert_at_end();
+
+        // A hole holding more than an expression is reported once, and the
+        // rest of the string still parses.
+        let mut error_reporter = StringErrorReporter::new();
+        let tree = SyntaxTree::parse_str(r#"$"{1 2 {3}} ok""#, &mut error_reporter);
+        assert_eq!(1, error_reporter.errors().len());
+        assert_eq!(
+            &DiagnosticKind::UnexpectedToken {
+                expected_kind: SyntaxKind::CloseBraceToken,
+                actual_kind: SyntaxKind::NumberToken,
+            },
+            error_reporter.errors()[0].kind()
+        );
+        assert_eq!(
+            SyntaxKind::InterpolatedStringExpression,
+            root_expression(&tree).kind()
+        );
+    }
+
+    #[test]
+    fn parse_lambda_and_invocation() {
+        let tree = SyntaxTree::parse_str(
+            "f = (x: fn(int): int, y) => (x)(y)",
+            &mut NullErrorReporter::new(),
+        );
+        let mut e = AssertingIterator::new(root_expression(&tree));
+        e.assert_node(SyntaxKind::AssignmentExpression);
+        e.assert_token(SyntaxKind::IdentifierToken, "f");
+        e.assert_token(SyntaxKind::EqualsToken, "=");
+        e.assert_node(SyntaxKind::LambdaExpression);
+        e.assert_token(SyntaxKind::OpenParenthesisToken, "(");
+        e.assert_node(SyntaxKind::Parameter);
+        e.assert_token(SyntaxKind::IdentifierToken, "x");
+        e.assert_node(SyntaxKind::TypeClause);
+        e.assert_token(SyntaxKind::ColonToken, ":");
+        e.assert_node(SyntaxKind::FunctionType);
+        e.assert_token(SyntaxKind::FnKeyword, "fn");
+        e.assert_token(SyntaxKind::OpenParenthesisToken, "(");
+        e.assert_node(SyntaxKind::NameType);
+        e.assert_token(SyntaxKind::IdentifierToken, "int");
+        e.assert_token(SyntaxKind::CloseParenthesisToken, ")");
+        e.assert_node(SyntaxKind::TypeClause);
+        e.assert_token(SyntaxKind::ColonToken, ":");
+        e.assert_node(SyntaxKind::NameType);
+        e.assert_token(SyntaxKind::IdentifierToken, "int");
+        e.assert_token(SyntaxKind::CommaToken, ",");
+        e.assert_node(SyntaxKind::Parameter);
+        e.assert_token(SyntaxKind::IdentifierToken, "y");
+        e.assert_token(SyntaxKind::CloseParenthesisToken, ")");
+        e.assert_token(SyntaxKind::EqualsGreaterToken, "=>");
+        e.assert_node(SyntaxKind::InvocationExpression);
+        e.assert_node(SyntaxKind::ParenthesizedExpression);
+        e.assert_token(SyntaxKind::OpenParenthesisToken, "(");
+        e.assert_node(SyntaxKind::NameExpression);
+        e.assert_token(SyntaxKind::IdentifierToken, "x");
+        e.assert_token(SyntaxKind::CloseParenthesisToken, ")");
+        e.assert_token(SyntaxKind::OpenParenthesisToken, "(");
+        e.assert_node(SyntaxKind::NameExpression);
+        e.assert_token(SyntaxKind::IdentifierToken, "y");
+        e.assert_token(SyntaxKind::CloseParenthesisToken, ")");
+        e.assert_at_end();
+
+        // Calls chain, and a function type can be a type argument.
+        let tree = SyntaxTree::parse_str("f<fn(): T>()()", &mut NullErrorReporter::new());
+        let mut e = AssertingIterator::new(root_expression(&tree));
+        e.assert_node(SyntaxKind::InvocationExpression);
+        e.assert_node(SyntaxKind::CallExpression);
+        e.assert_token(SyntaxKind::IdentifierToken, "f");
+        e.assert_node(SyntaxKind::TypeArgumentList);
+        e.assert_token(SyntaxKind::LessToken, "<");
+        e.assert_node(SyntaxKind::FunctionType);
+        e.assert_token(SyntaxKind::FnKeyword, "fn");
+        e.assert_token(SyntaxKind::OpenParenthesisToken, "(");
+        e.assert_token(SyntaxKind::CloseParenthesisToken, ")");
+        e.assert_node(SyntaxKind::TypeClause);
+        e.assert_token(SyntaxKind::ColonToken, ":");
+        e.assert_node(SyntaxKind::NameType);
+        e.assert_token(SyntaxKind::IdentifierToken, "T");
+        e.assert_token(SyntaxKind::GreaterToken, ">");
+        e.assert_token(SyntaxKind::OpenParenthesisToken, "(");
+        e.assert_token(SyntaxKind::CloseParenthesisToken, ")");
+        e.assert_token(SyntaxKind::OpenParenthesisToken, "(");
+        e.assert_token(SyntaxKind::CloseParenthesisToken, ")");
+        e.assert_at_end();
+    }
+
+    #[test]
+    fn parse_match_expression() {
+        let tree = SyntaxTree::parse_str(
+            "match p { E.A(1, x) => x, _ => 0 }",
+            &mut NullErrorReporter::new(),
+        );
+        let mut e = AssertingIterator::new(root_expression(&tree));
+        e.assert_node(SyntaxKind::MatchExpression);
+        e.assert_token(SyntaxKind::MatchKeyword, "match");
+        e.assert_node(SyntaxKind::NameExpression);
+        e.assert_token(SyntaxKind::IdentifierToken, "p");
+        e.assert_token(SyntaxKind::OpenBraceToken, "{");
+        e.assert_node(SyntaxKind::MatchArm);
+        e.assert_node(SyntaxKind::VariantPattern);
+        e.assert_token(SyntaxKind::IdentifierToken, "E");
+        e.assert_token(SyntaxKind::DotToken, ".");
+        e.assert_token(SyntaxKind::IdentifierToken, "A");
+        e.assert_node(SyntaxKind::PatternPayload);
+        e.assert_token(SyntaxKind::OpenParenthesisToken, "(");
+        e.assert_node(SyntaxKind::LiteralPattern);
+        e.assert_token(SyntaxKind::NumberToken, "1");
+        e.assert_token(SyntaxKind::CommaToken, ",");
+        e.assert_node(SyntaxKind::BindingPattern);
+        e.assert_token(SyntaxKind::IdentifierToken, "x");
+        e.assert_token(SyntaxKind::CloseParenthesisToken, ")");
+        e.assert_token(SyntaxKind::EqualsGreaterToken, "=>");
+        e.assert_node(SyntaxKind::NameExpression);
+        e.assert_token(SyntaxKind::IdentifierToken, "x");
+        e.assert_token(SyntaxKind::CommaToken, ",");
+        e.assert_node(SyntaxKind::MatchArm);
+        e.assert_node(SyntaxKind::BindingPattern);
+        e.assert_token(SyntaxKind::IdentifierToken, "_");
+        e.assert_token(SyntaxKind::EqualsGreaterToken, "=>");
+        e.assert_node(SyntaxKind::LiteralExpression);
+        e.assert_token(SyntaxKind::NumberToken, "0");
+        e.assert_token(SyntaxKind::CloseBraceToken, "}");
+        e.assert_at_end();
+    }
+
+    #[test]
+    fn parse_enum_declaration_and_variant_construction() {
+        let tree =
+            SyntaxTree::parse_str("enum E { A(int), B } E.A(1)", &mut NullErrorReporter::new());
+        let mut e = AssertingIterator::new(tree.root());
+        e.assert_node(SyntaxKind::CompilationUnit);
+        e.assert_node(SyntaxKind::EnumDeclaration);
+        e.assert_token(SyntaxKind::EnumKeyword, "enum");
+        e.assert_token(SyntaxKind::IdentifierToken, "E");
+        e.assert_token(SyntaxKind::OpenBraceToken, "{");
+        e.assert_node(SyntaxKind::VariantDeclaration);
+        e.assert_token(SyntaxKind::IdentifierToken, "A");
+        e.assert_node(SyntaxKind::VariantPayload);
+        e.assert_token(SyntaxKind::OpenParenthesisToken, "(");
+        e.assert_node(SyntaxKind::NameType);
+        e.assert_token(SyntaxKind::IdentifierToken, "int");
+        e.assert_token(SyntaxKind::CloseParenthesisToken, ")");
+        e.assert_token(SyntaxKind::CommaToken, ",");
+        e.assert_node(SyntaxKind::VariantDeclaration);
+        e.assert_token(SyntaxKind::IdentifierToken, "B");
+        e.assert_token(SyntaxKind::CloseBraceToken, "}");
+        e.assert_node(SyntaxKind::GlobalExpression);
+        e.assert_node(SyntaxKind::MemberCallExpression);
+        e.assert_node(SyntaxKind::NameExpression);
+        e.assert_token(SyntaxKind::IdentifierToken, "E");
+        e.assert_token(SyntaxKind::DotToken, ".");
+        e.assert_token(SyntaxKind::IdentifierToken, "A");
+        e.assert_token(SyntaxKind::OpenParenthesisToken, "(");
+        e.assert_node(SyntaxKind::LiteralExpression);
+        e.assert_token(SyntaxKind::NumberToken, "1");
+        e.assert_token(SyntaxKind::CloseParenthesisToken, ")");
+        e.assert_at_end();
+    }
+
+    #[test]
+    fn parse_several_members() {
+        let tree = SyntaxTree::parse_str("struct P {} a = 1 a", &mut NullErrorReporter::new());
+        let kinds = tree
+            .root()
+            .members
+            .iter()
+            .map(|m| m.kind())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                SyntaxKind::StructDeclaration,
+                SyntaxKind::GlobalExpression,
+                SyntaxKind::GlobalExpression
+            ],
+            kinds
+        );
+    }
+
+    /// The kinds of the expressions that `input` parses into, one per member.
+    fn member_expression_kinds(input: &str) -> Vec<SyntaxKind> {
+        let mut error_reporter = StringErrorReporter::new();
+        let tree = SyntaxTree::parse_str(input, &mut error_reporter);
+        assert!(!error_reporter.had_error(), "{}", input);
+        tree.root()
+            .members
+            .iter()
+            .map(|member| match member {
+                MemberSyntax::GlobalExpression { expression } => expression.kind(),
+                member => member.kind(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parse_line_breaks_between_members() {
+        use SyntaxKind::*;
+        for (input, kinds) in [
+            // An expression ends before a token that cannot continue it.
+            ("x = 5\ny = 6", vec![AssignmentExpression; 2]),
+            ("x = 5\n6", vec![AssignmentExpression, LiteralExpression]),
+            ("a\n++b", vec![NameExpression, PrefixUpdateExpression]),
+            (
+                "fn f() => 1\nf()",
+                vec![FunctionDeclaration, CallExpression],
+            ),
+            // A line break alone ends nothing.
+            ("total = a\n  - b", vec![AssignmentExpression]),
+            ("b = a\n(a + 1) * 2", vec![AssignmentExpression]),
+            ("xs = [1, 2]\n[0]", vec![AssignmentExpression]),
+            ("x = 1\n  * 2\n  .y", vec![AssignmentExpression]),
+            ("a &&\n-b", vec![BinaryExpression]),
+            ("fn f() => 1\n-1", vec![FunctionDeclaration]),
+        ] {
+            assert_eq!(kinds, member_expression_kinds(input), "{}", input);
+        }
+    }
+
     struct AssertingIterator<'n> {
         nodes: Vec<&'n dyn SyntaxNodeExt>,
         cursor: usize,
@@ -420,7 +1818,7 @@ mod tests {
         let input = format!("a{}b{}c", op1text, op2text);
         let tree = SyntaxTree::parse_str(&input, &mut NullErrorReporter::new());
 
-        let mut e = AssertingIterator::new(tree.root());
+        let mut e = AssertingIterator::new(root_expression(&tree));
 
         if op1precedence >= op2precedence {
             e.assert_node(SyntaxKind::BinaryExpression);
@@ -468,7 +1866,7 @@ mod tests {
         let input = format!("{}a{}b", op1text, op2text);
         let tree = SyntaxTree::parse_str(&input, &mut NullErrorReporter::new());
 
-        let mut e = AssertingIterator::new(tree.root());
+        let mut e = AssertingIterator::new(root_expression(&tree));
 
         if op1precedence >= op2precedence {
             e.assert_node(SyntaxKind::BinaryExpression);
@@ -570,6 +1968,17 @@ mod tests {
         );
     }
 
+    #[test]
+    fn missing_close_bracket() {
+        check_bad(
+            "[1, 2",
+            vec![DiagnosticKind::UnexpectedToken {
+                expected_kind: SyntaxKind::CloseBracketToken,
+                actual_kind: SyntaxKind::EndOfFileToken,
+            }],
+        );
+    }
+
     #[test]
     fn missing_close_parenthesis() {
         check_bad(
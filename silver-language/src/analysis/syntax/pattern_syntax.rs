@@ -0,0 +1,114 @@
+use crate::analysis::{silver_value::SilverValue, text::text_span::TextSpan};
+
+use super::{
+    separated_syntax_list::SeparatedSyntaxList, syntax_kind::SyntaxKind,
+    syntax_node::SyntaxNodeExt, syntax_token::SyntaxToken,
+};
+
+/// The left-hand side of a match arm, such as `1`, `x` or
+/// `Shape.Circle(r)`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PatternSyntax {
+    Literal {
+        literal_token: SyntaxToken,
+        value: Option<SilverValue>,
+    },
+    /// Matches anything, binding it to the name unless that is `_`.
+    Binding { identifier_token: SyntaxToken },
+    Variant {
+        type_identifier_token: SyntaxToken,
+        dot_token: SyntaxToken,
+        identifier_token: SyntaxToken,
+        payload: Option<Box<PatternPayloadSyntax>>,
+    },
+}
+
+impl SyntaxNodeExt for PatternSyntax {
+    fn kind(&self) -> SyntaxKind {
+        match self {
+            PatternSyntax::Literal { .. } => SyntaxKind::LiteralPattern,
+            PatternSyntax::Binding { .. } => SyntaxKind::BindingPattern,
+            PatternSyntax::Variant { .. } => SyntaxKind::VariantPattern,
+        }
+    }
+
+    fn children(&self) -> Vec<&dyn SyntaxNodeExt> {
+        match self {
+            PatternSyntax::Literal { literal_token, .. } => vec![literal_token],
+            PatternSyntax::Binding { identifier_token } => vec![identifier_token],
+            PatternSyntax::Variant {
+                type_identifier_token,
+                dot_token,
+                identifier_token,
+                payload,
+            } => {
+                let mut children: Vec<&dyn SyntaxNodeExt> =
+                    vec![type_identifier_token, dot_token, identifier_token];
+                if let Some(payload) = payload {
+                    children.push(payload.as_ref());
+                }
+                children
+            }
+        }
+    }
+
+    fn value(&self) -> Option<&SilverValue> {
+        None
+    }
+
+    fn text(&self) -> Option<&str> {
+        None
+    }
+
+    fn span(&self) -> TextSpan {
+        match self {
+            PatternSyntax::Literal { literal_token, .. } => literal_token.span(),
+            PatternSyntax::Binding { identifier_token } => identifier_token.span(),
+            PatternSyntax::Variant {
+                type_identifier_token,
+                identifier_token,
+                payload,
+                ..
+            } => {
+                let end = match payload {
+                    Some(payload) => payload.span().end,
+                    None => identifier_token.span().end,
+                };
+                type_identifier_token.span().start..end
+            }
+        }
+    }
+}
+
+/// The parenthesized patterns matched against a variant's payload.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PatternPayloadSyntax {
+    pub(crate) open_parenthesis_token: SyntaxToken,
+    pub(crate) patterns: SeparatedSyntaxList<PatternSyntax>,
+    pub(crate) close_parenthesis_token: SyntaxToken,
+}
+
+impl SyntaxNodeExt for PatternPayloadSyntax {
+    fn kind(&self) -> SyntaxKind {
+        SyntaxKind::PatternPayload
+    }
+
+    fn children(&self) -> Vec<&dyn SyntaxNodeExt> {
+        let mut children: Vec<&dyn SyntaxNodeExt> = vec![&self.open_parenthesis_token];
+        children.extend(self.patterns.children());
+        children.push(&self.close_parenthesis_token);
+        children
+    }
+
+    fn value(&self) -> Option<&SilverValue> {
+        None
+    }
+
+    fn text(&self) -> Option<&str> {
+        None
+    }
+
+    fn span(&self) -> TextSpan {
+        self.open_parenthesis_token.span().start..self.close_parenthesis_token.span().end
+    }
+}
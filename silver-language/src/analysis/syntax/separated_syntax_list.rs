@@ -0,0 +1,37 @@
+use super::{syntax_node::SyntaxNodeExt, syntax_token::SyntaxToken};
+
+/// A list of nodes with the separator tokens that sit between them, such as
+/// the comma-separated arguments of a call.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct SeparatedSyntaxList<T> {
+    nodes: Vec<T>,
+    separators: Vec<SyntaxToken>,
+}
+
+impl<T> SeparatedSyntaxList<T> {
+    pub(crate) fn new(nodes: Vec<T>, separators: Vec<SyntaxToken>) -> Self {
+        Self { nodes, separators }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &T> {
+        self.nodes.iter()
+    }
+}
+
+impl<T: SyntaxNodeExt> SeparatedSyntaxList<T> {
+    /// The nodes interleaved with their separators, in source order.
+    pub(crate) fn children(&self) -> Vec<&dyn SyntaxNodeExt> {
+        let mut children = Vec::<&dyn SyntaxNodeExt>::new();
+        for (i, node) in self.nodes.iter().enumerate() {
+            children.push(node);
+            if let Some(separator) = self.separators.get(i) {
+                children.push(separator);
+            }
+        }
+        children
+    }
+}
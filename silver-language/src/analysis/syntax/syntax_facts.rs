@@ -1,5 +1,18 @@
+use unicode_normalization::UnicodeNormalization;
+use unicode_xid::UnicodeXID;
+
+use crate::analysis::silver_type::SilverType;
+
 use super::syntax_kind::SyntaxKind;
 
+/// How tightly `as` binds: as tightly as a unary operator, so that `-x as i8`
+/// casts `-x` and `a * b as i8` casts only `b`.
+pub(crate) const CAST_PRECEDENCE: usize = 7;
+
+/// How tightly a prefix `++` or `--` binds: like a unary operator, so that
+/// `++x * 2` increments `x` before doubling it.
+pub(crate) const UPDATE_PRECEDENCE: usize = 7;
+
 pub(crate) trait Operator {
     fn unary_operator_precedence(&self) -> usize;
     fn binary_operator_precedence(&self) -> usize;
@@ -8,25 +21,33 @@ pub(crate) trait Operator {
 impl Operator for SyntaxKind {
     fn unary_operator_precedence(&self) -> usize {
         match self {
-            SyntaxKind::PlusToken | SyntaxKind::MinusToken | SyntaxKind::BangToken => 6,
+            SyntaxKind::PlusToken | SyntaxKind::MinusToken | SyntaxKind::BangToken => 7,
             _ => 0,
         }
     }
 
     fn binary_operator_precedence(&self) -> usize {
         match self {
-            // */
-            SyntaxKind::StarToken | SyntaxKind::SlashToken => 5,
+            // */%
+            SyntaxKind::StarToken | SyntaxKind::SlashToken | SyntaxKind::PercentToken => 6,
             // +-
-            SyntaxKind::PlusToken | SyntaxKind::MinusToken => 4,
+            SyntaxKind::PlusToken | SyntaxKind::MinusToken => 5,
 
-            SyntaxKind::EqualsEqualsToken | SyntaxKind::BangEqualsToken => 3,
+            SyntaxKind::EqualsEqualsToken
+            | SyntaxKind::BangEqualsToken
+            | SyntaxKind::LessToken
+            | SyntaxKind::LessEqualsToken
+            | SyntaxKind::GreaterToken
+            | SyntaxKind::GreaterEqualsToken => 4,
 
             // &&
-            SyntaxKind::AmpersandAmpersandToken => 2,
+            SyntaxKind::AmpersandAmpersandToken => 3,
 
             // ||
-            SyntaxKind::PipePipeToken => 1,
+            SyntaxKind::PipePipeToken => 2,
+
+            // ??
+            SyntaxKind::QuestionQuestionToken => 1,
             _ => 0,
         }
     }
@@ -43,29 +64,136 @@ impl SyntaxKindWithText for SyntaxKind {
             SyntaxKind::MinusToken => Some("-"),
             SyntaxKind::StarToken => Some("*"),
             SyntaxKind::SlashToken => Some("/"),
+            SyntaxKind::PercentToken => Some("%"),
+            SyntaxKind::PlusPlusToken => Some("++"),
+            SyntaxKind::MinusMinusToken => Some("--"),
+            SyntaxKind::PlusEqualsToken => Some("+="),
+            SyntaxKind::MinusEqualsToken => Some("-="),
+            SyntaxKind::StarEqualsToken => Some("*="),
+            SyntaxKind::SlashEqualsToken => Some("/="),
+            SyntaxKind::PercentEqualsToken => Some("%="),
+            SyntaxKind::AmpersandAmpersandEqualsToken => Some("&&="),
+            SyntaxKind::PipePipeEqualsToken => Some("||="),
             SyntaxKind::OpenParenthesisToken => Some("("),
             SyntaxKind::CloseParenthesisToken => Some(")"),
+            SyntaxKind::OpenBracketToken => Some("["),
+            SyntaxKind::CloseBracketToken => Some("]"),
+            SyntaxKind::OpenBraceToken => Some("{"),
+            SyntaxKind::CloseBraceToken => Some("}"),
             SyntaxKind::BangToken => Some("!"),
             SyntaxKind::AmpersandAmpersandToken => Some("&&"),
             SyntaxKind::PipePipeToken => Some("||"),
             SyntaxKind::EqualsEqualsToken => Some("=="),
             SyntaxKind::BangEqualsToken => Some("!="),
             SyntaxKind::EqualsToken => Some("="),
+            SyntaxKind::EqualsGreaterToken => Some("=>"),
+            SyntaxKind::LessToken => Some("<"),
+            SyntaxKind::LessEqualsToken => Some("<="),
+            SyntaxKind::GreaterToken => Some(">"),
+            SyntaxKind::GreaterEqualsToken => Some(">="),
+            SyntaxKind::CommaToken => Some(","),
+            SyntaxKind::ColonToken => Some(":"),
+            SyntaxKind::DotToken => Some("."),
+            SyntaxKind::QuestionToken => Some("?"),
+            SyntaxKind::QuestionQuestionToken => Some("??"),
+            SyntaxKind::QuestionDotToken => Some("?."),
             SyntaxKind::TrueKeyword => Some("true"),
             SyntaxKind::FalseKeyword => Some("false"),
+            SyntaxKind::StructKeyword => Some("struct"),
+            SyntaxKind::EnumKeyword => Some("enum"),
+            SyntaxKind::MatchKeyword => Some("match"),
+            SyntaxKind::NullKeyword => Some("null"),
+            SyntaxKind::FnKeyword => Some("fn"),
+            SyntaxKind::AsKeyword => Some("as"),
             _ => None,
         }
     }
 }
 
+/// The binary operator that a compound assignment such as `+=`, or an
+/// increment or decrement, applies to its target.
+pub(crate) fn update_operator_kind(kind: SyntaxKind) -> Option<SyntaxKind> {
+    let kind = match kind {
+        SyntaxKind::PlusEqualsToken | SyntaxKind::PlusPlusToken => SyntaxKind::PlusToken,
+        SyntaxKind::MinusEqualsToken | SyntaxKind::MinusMinusToken => SyntaxKind::MinusToken,
+        SyntaxKind::StarEqualsToken => SyntaxKind::StarToken,
+        SyntaxKind::SlashEqualsToken => SyntaxKind::SlashToken,
+        SyntaxKind::PercentEqualsToken => SyntaxKind::PercentToken,
+        SyntaxKind::AmpersandAmpersandEqualsToken => SyntaxKind::AmpersandAmpersandToken,
+        SyntaxKind::PipePipeEqualsToken => SyntaxKind::PipePipeToken,
+        _ => return None,
+    };
+    Some(kind)
+}
+
+/// Whether `kind` is `++` or `--`.
+pub(crate) fn is_update_operator(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::PlusPlusToken | SyntaxKind::MinusMinusToken
+    )
+}
+
+/// Every keyword, with the kind of token it lexes as.
+pub(crate) const KEYWORDS: &[(&str, SyntaxKind)] = &[
+    ("true", SyntaxKind::TrueKeyword),
+    ("false", SyntaxKind::FalseKeyword),
+    ("struct", SyntaxKind::StructKeyword),
+    ("enum", SyntaxKind::EnumKeyword),
+    ("match", SyntaxKind::MatchKeyword),
+    ("null", SyntaxKind::NullKeyword),
+    ("fn", SyntaxKind::FnKeyword),
+    ("as", SyntaxKind::AsKeyword),
+];
+
 pub(crate) fn keyword_kind(text: &str) -> SyntaxKind {
-    match text {
-        "true" => SyntaxKind::TrueKeyword,
-        "false" => SyntaxKind::FalseKeyword,
-        _ => SyntaxKind::IdentifierToken,
+    KEYWORDS
+        .iter()
+        .find(|(keyword, _)| *keyword == text)
+        .map_or(SyntaxKind::IdentifierToken, |&(_, kind)| kind)
+}
+
+/// The type named by a numeric literal suffix such as the `i32` in `10i32`.
+/// Only the canonical type names are suffixes, not aliases like `int`.
+pub(crate) fn number_suffix_type(suffix: &str) -> Option<SilverType> {
+    SilverType::lookup(suffix).filter(|ty| ty.is_numeric() && ty.name() == Some(suffix))
+}
+
+/// The type that the suffix of the numeric literal `text` names, if it has
+/// an integer suffix.
+pub(crate) fn integer_suffix_type(text: &str) -> Option<SilverType> {
+    let start = text.rfind(['i', 'u'])?;
+    number_suffix_type(&text[start..])
+}
+
+/// The character the escape sequence `\c` in a string stands for.
+pub(crate) fn escaped_character(c: char) -> Option<char> {
+    match c {
+        'n' => Some('\n'),
+        'r' => Some('\r'),
+        't' => Some('\t'),
+        '0' => Some('\0'),
+        '\\' | '"' | '{' | '}' => Some(c),
+        _ => None,
     }
 }
 
+/// Whether `c` may begin an identifier (UAX #31 `XID_Start`, plus `_`).
+pub(crate) fn is_identifier_start(c: char) -> bool {
+    c == '_' || c.is_xid_start()
+}
+
+/// Whether `c` may continue an identifier (UAX #31 `XID_Continue`).
+pub(crate) fn is_identifier_continue(c: char) -> bool {
+    c.is_xid_continue()
+}
+
+/// Put an identifier into NFC, so that canonically equivalent spellings
+/// name the same symbol.
+pub(crate) fn normalize_identifier(text: &str) -> String {
+    text.nfc().collect()
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
@@ -90,4 +218,11 @@ mod tests {
             assert_eq!(tokens[0].text(), text);
         }
     }
+
+    #[test]
+    fn normalize_identifier_composes() {
+        assert_eq!(normalize_identifier("cafe\u{301}"), "caf\u{e9}");
+        assert_eq!(normalize_identifier("caf\u{e9}"), "caf\u{e9}");
+        assert_eq!(normalize_identifier("foo_bar1"), "foo_bar1");
+    }
 }
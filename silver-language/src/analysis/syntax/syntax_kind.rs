@@ -10,27 +10,92 @@ pub enum SyntaxKind {
     NumberToken,
     WhitespaceToken,
     IdentifierToken,
+    StringToken,
+    InterpolatedStringTextToken,
 
     // Fixed tokens
     PlusToken,
     MinusToken,
     StarToken,
     SlashToken,
+    PercentToken,
+    PlusPlusToken,
+    MinusMinusToken,
+    PlusEqualsToken,
+    MinusEqualsToken,
+    StarEqualsToken,
+    SlashEqualsToken,
+    PercentEqualsToken,
+    AmpersandAmpersandEqualsToken,
+    PipePipeEqualsToken,
     OpenParenthesisToken,
     CloseParenthesisToken,
+    OpenBracketToken,
+    CloseBracketToken,
+    OpenBraceToken,
+    CloseBraceToken,
     BangToken,
     AmpersandAmpersandToken,
     PipePipeToken,
     EqualsEqualsToken,
     BangEqualsToken,
     EqualsToken,
+    EqualsGreaterToken,
+    LessToken,
+    LessEqualsToken,
+    GreaterToken,
+    GreaterEqualsToken,
+    CommaToken,
+    ColonToken,
+    DotToken,
+    QuestionToken,
+    QuestionQuestionToken,
+    QuestionDotToken,
+    InterpolatedStringStartToken,
+    InterpolatedStringEndToken,
 
     // Keywords
     TrueKeyword,
     FalseKeyword,
+    StructKeyword,
+    EnumKeyword,
+    MatchKeyword,
+    NullKeyword,
+    FnKeyword,
+    AsKeyword,
 
     // Nodes
     Root,
+    CompilationUnit,
+    TypeClause,
+    FieldDeclaration,
+    FieldInitializer,
+    VariantDeclaration,
+    VariantPayload,
+    MatchArm,
+    PatternPayload,
+    Parameter,
+    TypeParameterList,
+    TypeArgumentList,
+    Interpolation,
+
+    // Members
+    StructDeclaration,
+    EnumDeclaration,
+    FunctionDeclaration,
+    GlobalExpression,
+
+    // Types
+    NameType,
+    ArrayType,
+    NullableType,
+    GenericType,
+    FunctionType,
+
+    // Patterns
+    LiteralPattern,
+    BindingPattern,
+    VariantPattern,
 
     // Expressions
     LiteralExpression,
@@ -38,7 +103,24 @@ pub enum SyntaxKind {
     BinaryExpression,
     ParenthesizedExpression,
     NameExpression,
+    GenericNameExpression,
     AssignmentExpression,
+    CompoundAssignmentExpression,
+    PrefixUpdateExpression,
+    PostfixUpdateExpression,
+    CallExpression,
+    ArrayExpression,
+    IndexExpression,
+    ElementAssignmentExpression,
+    StructConstructionExpression,
+    FieldAccessExpression,
+    FieldAssignmentExpression,
+    MemberCallExpression,
+    MatchExpression,
+    CastExpression,
+    LambdaExpression,
+    InvocationExpression,
+    InterpolatedStringExpression,
 }
 
 impl Display for SyntaxKind {
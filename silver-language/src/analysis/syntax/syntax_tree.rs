@@ -2,33 +2,42 @@ use std::{
     collections::VecDeque,
     io::{self, Write},
     sync::Arc,
+    time::Duration,
 };
 
-use crate::analysis::{errors::error_reporter::ErrorReporter, text::source_text::SourceText};
+use crate::analysis::{
+    diagnostic_kind::DiagnosticKind,
+    errors::{
+        error_reporter::ErrorReporter, null_error_reporter::NullErrorReporter,
+        string_error_reporter::StringErrorReporter,
+    },
+    text::source_text::SourceText,
+};
 
 use super::{
-    expression_syntax::ExpressionSyntax, lexer::Lexer, parser::Parser, syntax_node::SyntaxNodeExt,
-    syntax_token::SyntaxToken,
+    compilation_unit_syntax::CompilationUnitSyntax, lexer::Lexer, parser::Parser,
+    syntax_kind::SyntaxKind, syntax_node::SyntaxNodeExt, syntax_token::SyntaxToken,
 };
 
 pub struct SyntaxTree {
-    root: ExpressionSyntax,
-    // TODO the end-of-file token will be used for diagnostics
-    #[allow(dead_code)]
-    end_of_file_token: SyntaxToken,
+    root: CompilationUnitSyntax,
     text: Arc<SourceText>,
+    lexing_time: Duration,
+    parsing_time: Duration,
 }
 
 impl<'reporter> SyntaxTree {
     pub(crate) fn new(
-        root: ExpressionSyntax,
-        end_of_file_token: SyntaxToken,
+        root: CompilationUnitSyntax,
         text: Arc<SourceText>,
+        lexing_time: Duration,
+        parsing_time: Duration,
     ) -> Self {
         Self {
             root,
-            end_of_file_token,
             text,
+            lexing_time,
+            parsing_time,
         }
     }
 
@@ -53,7 +62,57 @@ impl<'reporter> SyntaxTree {
         Lexer::get_tokens(text, error_reporter)
     }
 
-    pub(crate) fn root(&self) -> &ExpressionSyntax {
+    /// Whether `text` is a whole submission rather than the start of one.
+    /// It is not when every error in it comes from the input ending too
+    /// early, as with a missing closing brace, an operator with no right
+    /// operand, or an interpolated string cut off inside a hole, since more
+    /// input could still fix those. Any other error makes it complete, so
+    /// that the error can be reported right away.
+    pub fn is_complete(text: &str) -> bool {
+        let source = Arc::new(SourceText::from(text.to_string()));
+        let mut error_reporter = StringErrorReporter::new();
+        Self::parse(source.clone(), &mut error_reporter);
+        if !error_reporter.had_error() {
+            return true;
+        }
+        let end = text.trim_end().len();
+        let open_strings = Self::open_interpolated_strings(&Self::parse_tokens(
+            source,
+            &mut NullErrorReporter::new(),
+        ));
+        !error_reporter.errors().iter().all(|error| {
+            error.span().start >= end
+                || (*error.kind() == DiagnosticKind::UnterminatedString
+                    && open_strings.contains(&error.span().start))
+        })
+    }
+
+    /// The positions of the interpolated strings that the input ends inside
+    /// a hole of.
+    fn open_interpolated_strings(tokens: &VecDeque<SyntaxToken>) -> Vec<usize> {
+        let mut open = vec![];
+        // The strings being read, with how many braces are open in each.
+        let mut strings: Vec<(usize, usize)> = vec![];
+        for token in tokens {
+            match (token.kind(), strings.last_mut()) {
+                (SyntaxKind::InterpolatedStringStartToken, _) => {
+                    strings.push((token.position(), 0))
+                }
+                (SyntaxKind::OpenBraceToken, Some((_, depth))) => *depth += 1,
+                (SyntaxKind::CloseBraceToken, Some((_, depth))) if *depth > 0 => *depth -= 1,
+                (SyntaxKind::InterpolatedStringEndToken, Some(&mut (start, depth))) => {
+                    strings.pop();
+                    if depth > 0 {
+                        open.push(start);
+                    }
+                }
+                _ => {}
+            }
+        }
+        open
+    }
+
+    pub(crate) fn root(&self) -> &CompilationUnitSyntax {
         &self.root
     }
 
@@ -61,6 +120,16 @@ impl<'reporter> SyntaxTree {
         &self.text
     }
 
+    /// How long the text took to lex.
+    pub fn lexing_time(&self) -> Duration {
+        self.lexing_time
+    }
+
+    /// How long the tokens took to parse, apart from lexing them.
+    pub fn parsing_time(&self) -> Duration {
+        self.parsing_time
+    }
+
     pub fn pretty_print(&self, writer: &mut dyn Write) -> io::Result<()> {
         self.pretty_print_recursive(&self.root, writer, String::new(), true)
     }
@@ -92,3 +161,40 @@ impl<'reporter> SyntaxTree {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tells_incomplete_submissions_from_errors() {
+        for text in [
+            "x = 1",
+            "x = 1\n",
+            // These have errors that more input cannot fix.
+            "1 + )",
+            "(1 + 2 3",
+            r#""abc"#,
+            r#"$"abc"#,
+            r#"$"a {b} "#,
+            "1 + ¤",
+            "f(1,\n2 3",
+        ] {
+            assert!(SyntaxTree::is_complete(text), "{:?}", text);
+        }
+        for text in [
+            "(1 +",
+            "1 + ",
+            "1 +\n",
+            "fn f() =>\n match x {\n 1 => 2",
+            "x as",
+            "[1, 2",
+            "struct S { x: int",
+            r#"$"a {b"#,
+            r#"$"a {$"b {c"#,
+            r#"$"a {S { x: 1 } + S { x: 2"#,
+        ] {
+            assert!(!SyntaxTree::is_complete(text), "{:?}", text);
+        }
+    }
+}
@@ -0,0 +1,212 @@
+use crate::analysis::{silver_value::SilverValue, text::text_span::TextSpan};
+
+use super::{
+    separated_syntax_list::SeparatedSyntaxList, syntax_kind::SyntaxKind,
+    syntax_node::SyntaxNodeExt, syntax_token::SyntaxToken,
+};
+
+/// A type as written in source, such as `int`, `[Point]`, `int?`,
+/// `Pair<int, bool>` or `fn(int): bool`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypeSyntax {
+    Name {
+        identifier_token: SyntaxToken,
+    },
+    Generic {
+        identifier_token: SyntaxToken,
+        type_argument_list: TypeArgumentListSyntax,
+    },
+    Array {
+        open_bracket_token: SyntaxToken,
+        element_type: Box<TypeSyntax>,
+        close_bracket_token: SyntaxToken,
+    },
+    Nullable {
+        element_type: Box<TypeSyntax>,
+        question_token: SyntaxToken,
+    },
+    Function {
+        fn_keyword: SyntaxToken,
+        open_parenthesis_token: SyntaxToken,
+        parameters: SeparatedSyntaxList<TypeSyntax>,
+        close_parenthesis_token: SyntaxToken,
+        type_clause: Box<TypeClauseSyntax>,
+    },
+}
+
+impl SyntaxNodeExt for TypeSyntax {
+    fn kind(&self) -> SyntaxKind {
+        match self {
+            TypeSyntax::Name { .. } => SyntaxKind::NameType,
+            TypeSyntax::Generic { .. } => SyntaxKind::GenericType,
+            TypeSyntax::Array { .. } => SyntaxKind::ArrayType,
+            TypeSyntax::Nullable { .. } => SyntaxKind::NullableType,
+            TypeSyntax::Function { .. } => SyntaxKind::FunctionType,
+        }
+    }
+
+    fn children(&self) -> Vec<&dyn SyntaxNodeExt> {
+        match self {
+            TypeSyntax::Name { identifier_token } => vec![identifier_token],
+            TypeSyntax::Generic {
+                identifier_token,
+                type_argument_list,
+            } => vec![identifier_token, type_argument_list],
+            TypeSyntax::Array {
+                open_bracket_token,
+                element_type,
+                close_bracket_token,
+            } => vec![
+                open_bracket_token,
+                element_type.as_ref(),
+                close_bracket_token,
+            ],
+            TypeSyntax::Nullable {
+                element_type,
+                question_token,
+            } => vec![element_type.as_ref(), question_token],
+            TypeSyntax::Function {
+                fn_keyword,
+                open_parenthesis_token,
+                parameters,
+                close_parenthesis_token,
+                type_clause,
+            } => {
+                let mut children: Vec<&dyn SyntaxNodeExt> =
+                    vec![fn_keyword, open_parenthesis_token];
+                children.extend(parameters.children());
+                children.push(close_parenthesis_token);
+                children.push(type_clause.as_ref());
+                children
+            }
+        }
+    }
+
+    fn value(&self) -> Option<&SilverValue> {
+        None
+    }
+
+    fn text(&self) -> Option<&str> {
+        None
+    }
+
+    fn span(&self) -> TextSpan {
+        match self {
+            TypeSyntax::Name { identifier_token } => identifier_token.span(),
+            TypeSyntax::Generic {
+                identifier_token,
+                type_argument_list,
+            } => identifier_token.span().start..type_argument_list.span().end,
+            TypeSyntax::Array {
+                open_bracket_token,
+                close_bracket_token,
+                ..
+            } => open_bracket_token.span().start..close_bracket_token.span().end,
+            TypeSyntax::Nullable {
+                element_type,
+                question_token,
+            } => element_type.span().start..question_token.span().end,
+            TypeSyntax::Function {
+                fn_keyword,
+                type_clause,
+                ..
+            } => fn_keyword.span().start..type_clause.span().end,
+        }
+    }
+}
+
+/// The `: type` that gives something its type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeClauseSyntax {
+    pub(crate) colon_token: SyntaxToken,
+    pub(crate) type_syntax: TypeSyntax,
+}
+
+impl SyntaxNodeExt for TypeClauseSyntax {
+    fn kind(&self) -> SyntaxKind {
+        SyntaxKind::TypeClause
+    }
+
+    fn children(&self) -> Vec<&dyn SyntaxNodeExt> {
+        vec![&self.colon_token, &self.type_syntax]
+    }
+
+    fn value(&self) -> Option<&SilverValue> {
+        None
+    }
+
+    fn text(&self) -> Option<&str> {
+        None
+    }
+
+    fn span(&self) -> TextSpan {
+        self.colon_token.span().start..self.type_syntax.span().end
+    }
+}
+
+/// The `<T, U>` that makes a declaration generic.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeParameterListSyntax {
+    pub(crate) less_token: SyntaxToken,
+    pub(crate) parameters: SeparatedSyntaxList<SyntaxToken>,
+    pub(crate) greater_token: SyntaxToken,
+}
+
+impl SyntaxNodeExt for TypeParameterListSyntax {
+    fn kind(&self) -> SyntaxKind {
+        SyntaxKind::TypeParameterList
+    }
+
+    fn children(&self) -> Vec<&dyn SyntaxNodeExt> {
+        let mut children: Vec<&dyn SyntaxNodeExt> = vec![&self.less_token];
+        children.extend(self.parameters.children());
+        children.push(&self.greater_token);
+        children
+    }
+
+    fn value(&self) -> Option<&SilverValue> {
+        None
+    }
+
+    fn text(&self) -> Option<&str> {
+        None
+    }
+
+    fn span(&self) -> TextSpan {
+        self.less_token.span().start..self.greater_token.span().end
+    }
+}
+
+/// The `<int, bool>` that gives the type arguments of a generic type or
+/// function.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeArgumentListSyntax {
+    pub(crate) less_token: SyntaxToken,
+    pub(crate) arguments: SeparatedSyntaxList<TypeSyntax>,
+    pub(crate) greater_token: SyntaxToken,
+}
+
+impl SyntaxNodeExt for TypeArgumentListSyntax {
+    fn kind(&self) -> SyntaxKind {
+        SyntaxKind::TypeArgumentList
+    }
+
+    fn children(&self) -> Vec<&dyn SyntaxNodeExt> {
+        let mut children: Vec<&dyn SyntaxNodeExt> = vec![&self.less_token];
+        children.extend(self.arguments.children());
+        children.push(&self.greater_token);
+        children
+    }
+
+    fn value(&self) -> Option<&SilverValue> {
+        None
+    }
+
+    fn text(&self) -> Option<&str> {
+        None
+    }
+
+    fn span(&self) -> TextSpan {
+        self.less_token.span().start..self.greater_token.span().end
+    }
+}
@@ -1,4 +1,4 @@
-use std::ops::Index;
+use std::{fmt::Display, ops::Index};
 
 use super::{text_line::TextLine, text_span::TextSpan};
 
@@ -100,9 +100,9 @@ impl From<String> for SourceText {
     }
 }
 
-impl ToString for SourceText {
-    fn to_string(&self) -> String {
-        self.text.clone()
+impl Display for SourceText {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text)
     }
 }
 
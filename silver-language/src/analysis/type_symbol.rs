@@ -0,0 +1,110 @@
+use std::rc::Rc;
+
+use super::{silver_type::SilverType, variable_symbol::VariableSymbol};
+
+/// A struct type declared by a program, with its fields in declaration order.
+///
+/// A generic struct is declared with type parameters, which its fields may
+/// use. Each use of it gives type arguments for them, and the symbol for
+/// that use has the arguments in place of the parameters in its fields.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct TypeSymbol {
+    name: String,
+    type_parameters: Vec<String>,
+    type_arguments: Vec<SilverType>,
+    fields: Vec<VariableSymbol>,
+}
+
+impl TypeSymbol {
+    pub(crate) fn new(
+        name: String,
+        type_parameters: Vec<String>,
+        fields: Vec<VariableSymbol>,
+    ) -> Self {
+        Self {
+            name,
+            type_parameters,
+            type_arguments: vec![],
+            fields,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn type_parameters(&self) -> &[String] {
+        &self.type_parameters
+    }
+
+    /// The types given for the type parameters, which are empty for the
+    /// declaration of a generic struct itself.
+    pub fn type_arguments(&self) -> &[SilverType] {
+        &self.type_arguments
+    }
+
+    /// Whether this is a generic struct still waiting for type arguments.
+    pub fn is_generic_definition(&self) -> bool {
+        !self.type_parameters.is_empty() && self.type_arguments.is_empty()
+    }
+
+    /// The fields as declared, where the struct itself is named by a
+    /// recursive reference.
+    pub fn fields(&self) -> &[VariableSymbol] {
+        &self.fields
+    }
+
+    /// The type of the field at `index`, with this struct in place of any
+    /// reference to it.
+    pub fn field_type(self: &Rc<Self>, index: usize) -> SilverType {
+        self.fields[index]
+            .ty()
+            .unfold(&self.name, &|type_arguments| {
+                // Inside a generic declaration, the reference is to the struct
+                // with its own type parameters as the type arguments.
+                if self.is_generic_definition() {
+                    SilverType::Struct(Rc::new(self.instantiate(type_arguments.to_vec())))
+                } else {
+                    SilverType::Struct(self.clone())
+                }
+            })
+    }
+
+    /// The position of the field called `name`.
+    pub fn field_index(&self, name: &str) -> Option<usize> {
+        self.fields.iter().position(|field| field.name() == name)
+    }
+
+    /// The struct with `type_arguments` given for its type parameters.
+    pub(crate) fn instantiate(&self, type_arguments: Vec<SilverType>) -> Self {
+        let arguments = self
+            .type_parameters
+            .iter()
+            .cloned()
+            .zip(type_arguments)
+            .collect::<Vec<_>>();
+        let mut symbol = self.substitute(&arguments);
+        symbol.type_arguments = arguments.into_iter().map(|(_, ty)| ty).collect();
+        symbol
+    }
+
+    /// The struct with each type parameter named in `arguments` replaced by
+    /// the type given for it.
+    pub(crate) fn substitute(&self, arguments: &[(String, SilverType)]) -> Self {
+        self.map_types(&|ty| ty.substitute(arguments))
+    }
+
+    /// The struct with `map` applied to its type arguments and field types.
+    pub(crate) fn map_types(&self, map: &dyn Fn(&SilverType) -> SilverType) -> Self {
+        Self {
+            name: self.name.clone(),
+            type_parameters: self.type_parameters.clone(),
+            type_arguments: self.type_arguments.iter().map(map).collect(),
+            fields: self
+                .fields
+                .iter()
+                .map(|field| VariableSymbol::new(field.name().to_string(), map(field.ty())))
+                .collect(),
+        }
+    }
+}
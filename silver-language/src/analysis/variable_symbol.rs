@@ -4,18 +4,37 @@ use super::silver_type::SilverType;
 pub struct VariableSymbol {
     name: String,
     ty: SilverType,
+    annotated: bool,
 }
 
 impl VariableSymbol {
     pub(crate) fn new(name: String, ty: SilverType) -> Self {
-        Self { name, ty }
+        Self {
+            name,
+            ty,
+            annotated: false,
+        }
+    }
+
+    /// A variable declared with a type annotation, which keeps that type
+    /// through later assignments.
+    pub(crate) fn annotated(name: String, ty: SilverType) -> Self {
+        Self {
+            name,
+            ty,
+            annotated: true,
+        }
     }
 
     pub fn name(&self) -> &str {
         &self.name
     }
 
-    pub fn ty(&self) -> SilverType {
-        self.ty
+    pub fn ty(&self) -> &SilverType {
+        &self.ty
+    }
+
+    pub fn is_annotated(&self) -> bool {
+        self.annotated
     }
 }
@@ -0,0 +1,326 @@
+use std::{fs, io::Write};
+
+use crossterm::{
+    terminal::{Clear, ClearType},
+    ExecutableCommand,
+};
+use silver_language::analysis::{
+    compilation::Compilation,
+    errors::{error_reporter::ErrorReporter, string_error_reporter::StringErrorReporter},
+    global_scope::GlobalScope,
+    syntax::syntax_tree::SyntaxTree,
+};
+
+use crate::repl::Repl;
+
+/// A meta-command, run by a submission that starts with its name.
+pub(crate) struct Command {
+    pub(crate) name: &'static str,
+    /// The argument it takes, if any, as `#help` shows it.
+    argument: Option<&'static str>,
+    pub(crate) description: &'static str,
+    run: fn(&mut Repl, &str) -> anyhow::Result<()>,
+}
+
+impl Command {
+    fn usage(&self) -> String {
+        match self.argument {
+            Some(argument) => format!("{} {}", self.name, argument),
+            None => self.name.to_string(),
+        }
+    }
+}
+
+pub(crate) const COMMANDS: &[Command] = &[
+    Command {
+        name: "#help",
+        argument: None,
+        description: "Show this help",
+        run: help,
+    },
+    Command {
+        name: "#showTree",
+        argument: None,
+        description: "Show/hide parse trees",
+        run: show_tree,
+    },
+    Command {
+        name: "#showProgram",
+        argument: None,
+        description: "Show/hide bound trees",
+        run: show_program,
+    },
+    Command {
+        name: "#showSource",
+        argument: None,
+        description: "Show/hide bound trees as Silver source",
+        run: show_source,
+    },
+    Command {
+        name: "#stats",
+        argument: None,
+        description: "Show/hide timings and counts for each submission",
+        run: show_stats,
+    },
+    Command {
+        name: "#cls",
+        argument: None,
+        description: "Clear the console",
+        run: cls,
+    },
+    Command {
+        name: "#vars",
+        argument: None,
+        description: "List the variables with their types and values",
+        run: vars,
+    },
+    Command {
+        name: "#type",
+        argument: Some("<expr>"),
+        description: "Show the type of an expression without evaluating it",
+        run: type_of,
+    },
+    Command {
+        name: "#reset",
+        argument: None,
+        description: "Forget all variables, functions and types",
+        run: reset,
+    },
+    Command {
+        name: "#load",
+        argument: Some("<file>"),
+        description: "Run the program in a file, or the submissions #save wrote to it",
+        run: load,
+    },
+    Command {
+        name: "#save",
+        argument: Some("<file>"),
+        description: "Write the submissions that ran without errors to a file",
+        run: save,
+    },
+];
+
+/// Run the meta-command that `text` starts with on the argument after it.
+pub(crate) fn run(repl: &mut Repl, text: &str) -> anyhow::Result<()> {
+    let text = text.trim();
+    let (name, argument) = match text.find(char::is_whitespace) {
+        Some(index) => (&text[..index], text[index..].trim()),
+        None => (text, ""),
+    };
+    let command = match COMMANDS.iter().find(|command| command.name == name) {
+        Some(command) => command,
+        None => {
+            let message = format!("Unknown command '{}'. Try #help.", name);
+            return repl.print_error(&message);
+        }
+    };
+    if argument.is_empty() != command.argument.is_none() {
+        return repl.print_error(&format!("Usage: {}", command.usage()));
+    }
+    (command.run)(repl, argument)
+}
+
+fn help(repl: &mut Repl, _: &str) -> anyhow::Result<()> {
+    let usages: Vec<_> = COMMANDS.iter().map(Command::usage).collect();
+    let width = usages.iter().map(String::len).max().unwrap_or_default() + 1;
+    writeln!(repl.stdout, " -- HELP --")?;
+    for (command, usage) in COMMANDS.iter().zip(usages) {
+        writeln!(
+            repl.stdout,
+            "{:<width$}: {}",
+            usage,
+            command.description,
+            width = width
+        )?;
+    }
+    Ok(())
+}
+
+fn show_tree(repl: &mut Repl, _: &str) -> anyhow::Result<()> {
+    repl.view_options.show_tree = !repl.view_options.show_tree;
+    writeln!(
+        repl.stdout,
+        "{}",
+        if repl.view_options.show_tree {
+            "Showing parse trees."
+        } else {
+            "Not showing parse trees."
+        }
+    )?;
+    Ok(())
+}
+
+fn show_program(repl: &mut Repl, _: &str) -> anyhow::Result<()> {
+    repl.view_options.show_program = !repl.view_options.show_program;
+    writeln!(
+        repl.stdout,
+        "{}",
+        if repl.view_options.show_program {
+            "Showing bound trees."
+        } else {
+            "Not showing bound trees."
+        }
+    )?;
+    Ok(())
+}
+
+fn show_source(repl: &mut Repl, _: &str) -> anyhow::Result<()> {
+    repl.view_options.show_source = !repl.view_options.show_source;
+    writeln!(
+        repl.stdout,
+        "{}",
+        if repl.view_options.show_source {
+            "Showing bound trees as source."
+        } else {
+            "Not showing bound trees as source."
+        }
+    )?;
+    Ok(())
+}
+
+fn show_stats(repl: &mut Repl, _: &str) -> anyhow::Result<()> {
+    repl.view_options.show_stats = !repl.view_options.show_stats;
+    writeln!(
+        repl.stdout,
+        "{}",
+        if repl.view_options.show_stats {
+            "Showing statistics."
+        } else {
+            "Not showing statistics."
+        }
+    )?;
+    Ok(())
+}
+
+fn cls(repl: &mut Repl, _: &str) -> anyhow::Result<()> {
+    repl.stdout.execute(Clear(ClearType::All))?;
+    Ok(())
+}
+
+fn vars(repl: &mut Repl, _: &str) -> anyhow::Result<()> {
+    let mut variables: Vec<_> = repl.scope.variables().collect();
+    if variables.is_empty() {
+        writeln!(repl.stdout, "No variables have been assigned.")?;
+    }
+    variables.sort_by(|(a, _), (b, _)| a.name().cmp(b.name()));
+    for (variable, value) in variables {
+        writeln!(
+            repl.stdout,
+            "{}: {} = {}",
+            variable.name(),
+            variable.ty(),
+            value
+        )?;
+    }
+    Ok(())
+}
+
+fn type_of(repl: &mut Repl, expression: &str) -> anyhow::Result<()> {
+    let mut error_reporter = StringErrorReporter::new();
+    let parse_tree = SyntaxTree::parse_str(expression, &mut error_reporter);
+    let ty = Compilation::new(&parse_tree, &mut error_reporter).type_of(&repl.scope);
+    if error_reporter.had_error() {
+        return repl.print_diagnostics(&parse_tree, error_reporter.errors());
+    }
+    match ty {
+        Some(ty) => writeln!(repl.stdout, "{}", ty)?,
+        None => repl.print_error("There is no expression to give the type of.")?,
+    }
+    Ok(())
+}
+
+fn reset(repl: &mut Repl, _: &str) -> anyhow::Result<()> {
+    repl.scope = GlobalScope::new();
+    repl.submissions.clear();
+    writeln!(repl.stdout, "Forgot all variables, functions and types.")?;
+    Ok(())
+}
+
+/// The line `#save` writes between submissions. `#` is not a token, so no
+/// line of a submission that ran without errors is only that.
+const SUBMISSION_SEPARATOR: &str = "#";
+
+/// Run each submission in a file in turn, stopping at the first that
+/// reports errors. A file without separators is a single submission.
+fn load(repl: &mut Repl, path: &str) -> anyhow::Result<()> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(error) => {
+            return repl.print_error(&format!("Could not read '{}': {}", path, error));
+        }
+    };
+    for submission in split_submissions(&text) {
+        let count = repl.submissions.len();
+        repl.evaluate(&submission)?;
+        if repl.submissions.len() == count {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Write the submissions so that `#load` runs them again one by one.
+fn save(repl: &mut Repl, path: &str) -> anyhow::Result<()> {
+    match fs::write(path, join_submissions(&repl.submissions)) {
+        Ok(()) => {
+            let count = repl.submissions.len();
+            writeln!(repl.stdout, "Saved {} submissions to '{}'.", count, path)?;
+            Ok(())
+        }
+        Err(error) => repl.print_error(&format!("Could not write '{}': {}", path, error)),
+    }
+}
+
+fn join_submissions(submissions: &[String]) -> String {
+    let mut text = String::new();
+    for (i, submission) in submissions.iter().enumerate() {
+        if i > 0 {
+            text += SUBMISSION_SEPARATOR;
+            text.push('\n');
+        }
+        text += submission.trim_end();
+        text.push('\n');
+    }
+    text
+}
+
+fn split_submissions(text: &str) -> Vec<String> {
+    let mut submissions = vec![String::new()];
+    for line in text.lines() {
+        if line.trim() == SUBMISSION_SEPARATOR {
+            submissions.push(String::new());
+        } else {
+            let submission = submissions.last_mut().unwrap();
+            *submission += line;
+            submission.push('\n');
+        }
+    }
+    submissions.retain(|submission| !submission.trim().is_empty());
+    submissions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn saved_submissions_load_one_by_one() {
+        let submissions = [
+            "a = 10".to_string(),
+            "b = 3\n".to_string(),
+            "total = a\n  - b".to_string(),
+            "fn f() =>\n    1\n\n(f())".to_string(),
+        ];
+        let text = join_submissions(&submissions);
+        let loaded = split_submissions(&text);
+        assert_eq!(submissions.len(), loaded.len());
+        for (submission, loaded) in submissions.iter().zip(&loaded) {
+            assert_eq!(submission.trim_end(), loaded.trim_end());
+        }
+        assert!(split_submissions(&join_submissions(&[])).is_empty());
+        assert_eq!(
+            vec!["a = 1\nb = 2\n".to_string()],
+            split_submissions("a = 1\nb = 2")
+        );
+    }
+}
@@ -0,0 +1,170 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+/// How many submissions the history keeps, dropping the oldest beyond it.
+const MAX_ENTRIES: usize = 1000;
+
+/// Earlier submissions, oldest first, kept in a file so that they outlive
+/// the session. Reading or writing the file may fail, as when there is no
+/// data directory, and the history then simply lasts for the session.
+pub(crate) struct History {
+    entries: Vec<String>,
+    path: Option<PathBuf>,
+}
+
+impl History {
+    /// The history saved in the user's data directory.
+    pub(crate) fn load() -> Self {
+        Self::load_from(data_directory().map(|directory| directory.join("silver").join("history")))
+    }
+
+    /// A history that is not saved, and so only lasts for the session.
+    pub(crate) fn empty() -> Self {
+        Self::load_from(None)
+    }
+
+    fn load_from(path: Option<PathBuf>) -> Self {
+        let mut entries: Vec<String> = path
+            .as_deref()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .map(|text| text.lines().map(decode).collect())
+            .unwrap_or_default();
+        let excess = entries.len().saturating_sub(MAX_ENTRIES);
+        entries.drain(..excess);
+        Self { entries, path }
+    }
+
+    pub(crate) fn entries(&self) -> &[String] {
+        &self.entries
+    }
+
+    /// Add a submission, unless it repeats the last one, and save the
+    /// history.
+    pub(crate) fn add(&mut self, entry: String) {
+        if entry.trim().is_empty() || self.entries.last() == Some(&entry) {
+            return;
+        }
+        self.entries.push(entry);
+        if self.entries.len() > MAX_ENTRIES {
+            self.entries.remove(0);
+        }
+        if let Some(path) = &self.path {
+            let _ = save(path, &self.entries);
+        }
+    }
+
+    /// The index of the latest entry before `before` that contains `query`.
+    pub(crate) fn search(&self, query: &str, before: usize) -> Option<usize> {
+        if query.is_empty() {
+            return None;
+        }
+        self.entries[..before]
+            .iter()
+            .rposition(|entry| entry.contains(query))
+    }
+}
+
+fn save(path: &Path, entries: &[String]) -> std::io::Result<()> {
+    if let Some(directory) = path.parent() {
+        fs::create_dir_all(directory)?;
+    }
+    let mut text = String::new();
+    for entry in entries {
+        text += &encode(entry);
+        text.push('\n');
+    }
+    fs::write(path, text)
+}
+
+/// Where the platform keeps per-user application data.
+fn data_directory() -> Option<PathBuf> {
+    let directory = |name| env::var_os(name).filter(|value| !value.is_empty());
+    if cfg!(windows) {
+        directory("APPDATA").map(PathBuf::from)
+    } else if cfg!(target_os = "macos") {
+        directory("HOME").map(|home| Path::new(&home).join("Library/Application Support"))
+    } else {
+        directory("XDG_DATA_HOME")
+            .map(PathBuf::from)
+            .or_else(|| directory("HOME").map(|home| Path::new(&home).join(".local/share")))
+    }
+}
+
+/// An entry as one line of the history file, with its line breaks escaped
+/// so that a multi-line submission stays one entry.
+fn encode(entry: &str) -> String {
+    entry.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn decode(line: &str) -> String {
+    let mut entry = String::new();
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                entry.push('\n');
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                entry.push('\\');
+                chars.next();
+            }
+            (c, _) => entry.push(c),
+        }
+    }
+    entry
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_entries_as_single_lines() {
+        for entry in ["x = 1", "x = 1 +\n2", r#""a\nb" + "\\""#, "\\", "\n\n"] {
+            let line = encode(entry);
+            assert!(!line.contains('\n'), "{:?}", entry);
+            assert_eq!(entry, decode(&line));
+        }
+    }
+
+    #[test]
+    fn saves_and_loads_entries() {
+        let path = env::temp_dir()
+            .join(format!("silver-history-{}", std::process::id()))
+            .join("history");
+        let mut history = History::load_from(Some(path.clone()));
+        history.add("x = 1 +\n2".to_string());
+        history.add("x".to_string());
+        history.add("x".to_string());
+        history.add(" ".to_string());
+        let history = History::load_from(Some(path.clone()));
+        assert_eq!(["x = 1 +\n2", "x"], history.entries());
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn keeps_the_latest_entries() {
+        let mut history = History::load_from(None);
+        for i in 0..MAX_ENTRIES + 5 {
+            history.add(i.to_string());
+        }
+        assert_eq!(MAX_ENTRIES, history.entries().len());
+        assert_eq!("5", history.entries()[0]);
+    }
+
+    #[test]
+    fn searches_back_from_an_entry() {
+        let mut history = History::load_from(None);
+        for entry in ["x = 1", "y = 2", "x + y"] {
+            history.add(entry.to_string());
+        }
+        assert_eq!(Some(2), history.search("x", 3));
+        assert_eq!(Some(0), history.search("x", 2));
+        assert_eq!(None, history.search("x", 0));
+        assert_eq!(None, history.search("z", 3));
+        assert_eq!(None, history.search("", 3));
+    }
+}
@@ -0,0 +1,633 @@
+use std::{
+    io::{Stdout, Write},
+    ops::Range,
+};
+
+use crossterm::{
+    cursor::{MoveDown, MoveRight, MoveUp},
+    event::{self, Event, KeyCode, KeyModifiers},
+    style::{Attribute, Color, ResetColor, SetAttribute, SetForegroundColor},
+    terminal::{self, Clear, ClearType},
+    QueueableCommand,
+};
+use silver_language::analysis::{
+    completion::{CompletionKind, Completions},
+    syntax::classification::{self, Classification, ClassifiedSpan},
+};
+
+use crate::history::History;
+
+/// The width of `silver ➤ ` and `silver | `, the prompts before the first
+/// line of a submission and the lines after it.
+const PROMPT_WIDTH: usize = 9;
+
+/// Reads submissions in raw mode, where every line of a submission stays
+/// editable until it is submitted, and earlier submissions can be recalled.
+pub(crate) struct LineEditor {
+    history: History,
+}
+
+/// A Ctrl+R search back through the history.
+struct Search {
+    query: String,
+    /// The entry matching `query`, if any.
+    index: Option<usize>,
+    /// The submission as it was before searching, restored by Esc.
+    original: String,
+}
+
+impl LineEditor {
+    pub(crate) fn new(history: History) -> Self {
+        Self { history }
+    }
+
+    /// Read the next submission. Enter submits it once `is_complete` accepts
+    /// its text, and otherwise starts a new line. Tab completes the word
+    /// before the cursor with what `complete` offers for the text and the
+    /// cursor's position in it. `None` means Ctrl+D ended the input.
+    pub(crate) fn read_submission(
+        &mut self,
+        stdout: &mut Stdout,
+        is_complete: impl Fn(&str) -> bool,
+        complete: impl Fn(&str, usize) -> Option<Completions>,
+    ) -> crossterm::Result<Option<String>> {
+        let _raw_mode = RawMode::enable()?;
+        let mut document = Document::new();
+        let mut view = View::default();
+        // Where Up and Down are in the history, and the submission that was
+        // being written before moving into it.
+        let mut history_index = self.history.entries().len();
+        let mut draft = String::new();
+        let mut search: Option<Search> = None;
+        view.render(stdout, &document, None)?;
+        loop {
+            let key = match event::read()? {
+                Event::Key(key) => key,
+                Event::Resize(..) => {
+                    view.render(stdout, &document, search_status(&search).as_deref())?;
+                    continue;
+                }
+                Event::Mouse(_) => continue,
+            };
+            let control = key.modifiers.contains(KeyModifiers::CONTROL);
+            if let Some(current) = &mut search {
+                let entries = self.history.entries();
+                let index = match key.code {
+                    KeyCode::Char('r') if control => {
+                        let before = current.index.unwrap_or(entries.len());
+                        self.history
+                            .search(&current.query, before)
+                            .or(current.index)
+                    }
+                    KeyCode::Char(c) if !control => {
+                        current.query.push(c);
+                        let before = current.index.map_or(entries.len(), |index| index + 1);
+                        self.history.search(&current.query, before)
+                    }
+                    KeyCode::Backspace => {
+                        current.query.pop();
+                        self.history.search(&current.query, entries.len())
+                    }
+                    KeyCode::Esc | KeyCode::Char('c') | KeyCode::Char('g') => {
+                        document.set_text(&current.original);
+                        search = None;
+                        view.render(stdout, &document, None)?;
+                        continue;
+                    }
+                    // Any other key takes the match and goes on as usual.
+                    _ => {
+                        if let Some(index) = current.index {
+                            history_index = index;
+                        }
+                        search = None;
+                        None
+                    }
+                };
+                if let Some(current) = &mut search {
+                    current.index = index;
+                    match index {
+                        Some(index) => {
+                            let entry = &entries[index];
+                            document.set_text(entry);
+                            document.move_to(entry.rfind(&current.query).unwrap());
+                        }
+                        None => document.set_text(&current.original),
+                    }
+                    view.render(stdout, &document, search_status(&search).as_deref())?;
+                    continue;
+                }
+            }
+            // Completion candidates stay listed until the next key.
+            let mut candidates = None;
+            match key.code {
+                KeyCode::Enter if is_complete(&document.text()) => break,
+                KeyCode::Enter => document.insert_line(),
+                KeyCode::Char('d') if control && document.text().is_empty() => {
+                    view.finish(stdout)?;
+                    return Ok(None);
+                }
+                KeyCode::Char('c') if control => document = Document::new(),
+                KeyCode::Char('r') if control => {
+                    search = Some(Search {
+                        query: String::new(),
+                        index: None,
+                        original: document.text(),
+                    })
+                }
+                KeyCode::Char(c) if !control && !key.modifiers.contains(KeyModifiers::ALT) => {
+                    document.insert(c)
+                }
+                KeyCode::Tab => match complete(&document.text(), document.position()) {
+                    Some(completions) => {
+                        // A single candidate replaces the whole word, but
+                        // several only extend the text before the cursor.
+                        let span = completions.span();
+                        if completions.candidates().len() > 1 {
+                            let range = span.start..document.position();
+                            document.replace(range, completions.common_prefix());
+                            candidates = Some(candidates_status(&completions));
+                        } else {
+                            document.replace(span, completions.common_prefix());
+                        }
+                    }
+                    None => (0..4).for_each(|_| document.insert(' ')),
+                },
+                KeyCode::Backspace => document.backspace(),
+                KeyCode::Delete => document.delete(),
+                KeyCode::Left => document.left(),
+                KeyCode::Right => document.right(),
+                KeyCode::Home => document.home(),
+                KeyCode::End => document.end(),
+                KeyCode::Up => {
+                    let entries = self.history.entries();
+                    if !document.up() && history_index > 0 {
+                        if history_index == entries.len() {
+                            draft = document.text();
+                        }
+                        history_index -= 1;
+                        document.set_text(&entries[history_index]);
+                    }
+                }
+                KeyCode::Down => {
+                    let entries = self.history.entries();
+                    if !document.down() && history_index < entries.len() {
+                        history_index += 1;
+                        document.set_text(entries.get(history_index).unwrap_or(&draft));
+                    }
+                }
+                KeyCode::Esc => document = Document::new(),
+                _ => continue,
+            }
+            let status = search_status(&search).or(candidates);
+            view.render(stdout, &document, status.as_deref())?;
+        }
+        // Clear any status before moving on.
+        view.render(stdout, &document, None)?;
+        view.finish(stdout)?;
+
+        let text = document.text();
+        self.history.add(text.clone());
+        Ok(Some(text))
+    }
+}
+
+/// The candidates to complete a word with, to show below the submission.
+fn candidates_status(completions: &Completions) -> String {
+    const MAX_SHOWN: usize = 20;
+    let candidates = completions.candidates();
+    let mut shown: Vec<_> = candidates
+        .iter()
+        .take(MAX_SHOWN)
+        .map(|candidate| match candidate.kind() {
+            CompletionKind::Keyword => candidate.text().to_string(),
+            _ => format!("{}: {}", candidate.text(), candidate.detail()),
+        })
+        .collect();
+    if candidates.len() > MAX_SHOWN {
+        shown.push(format!("and {} more", candidates.len() - MAX_SHOWN));
+    }
+    shown.join("   ")
+}
+
+/// The line shown below the submission while searching the history.
+fn search_status(search: &Option<Search>) -> Option<String> {
+    search.as_ref().map(|search| match search.index {
+        None if !search.query.is_empty() => format!("(failed history search) {}", search.query),
+        _ => format!("(history search) {}", search.query),
+    })
+}
+
+/// Raw mode for as long as this lives, so that a panic or an error while
+/// editing still leaves the terminal usable.
+struct RawMode;
+
+impl RawMode {
+    fn enable() -> crossterm::Result<Self> {
+        terminal::enable_raw_mode()?;
+        Ok(Self)
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Draws a document below the prompt, remembering how many rows down from
+/// its first line the cursor was left so that it can be redrawn in place.
+#[derive(Default)]
+struct View {
+    cursor_row: usize,
+    last_row: usize,
+}
+
+impl View {
+    /// Draw `document`, with `status` on a row of its own below it.
+    fn render(
+        &mut self,
+        stdout: &mut Stdout,
+        document: &Document,
+        status: Option<&str>,
+    ) -> crossterm::Result<()> {
+        let width = match terminal::size()? {
+            (0, _) => 80,
+            (width, _) => width as usize,
+        };
+        // A line longer than the terminal wraps onto more rows.
+        let rows = |line: &str| (PROMPT_WIDTH + line.chars().count() - 1) / width + 1;
+
+        if self.cursor_row > 0 {
+            stdout.queue(MoveUp(self.cursor_row as u16))?;
+        }
+        write!(stdout, "\r")?;
+        stdout.queue(Clear(ClearType::FromCursorDown))?;
+        let text = document.text();
+        let spans = classification::classify(&text);
+        let mut start = 0;
+        for (index, line) in document.lines.iter().enumerate() {
+            if index > 0 {
+                write!(stdout, "\r\n")?;
+            }
+            write_prompt(stdout, index == 0)?;
+            write_highlighted(stdout, &text, start..start + line.len(), &spans)?;
+            start += line.len() + 1;
+        }
+        if let Some(status) = status {
+            write!(stdout, "\r\n{}", status)?;
+        }
+
+        // Writing leaves the cursor on the last row, so move it back up to
+        // where the document's cursor is.
+        let status_rows =
+            status.map_or(0, |status| (status.chars().count().max(1) - 1) / width + 1);
+        let total_rows: usize =
+            document.lines.iter().map(|line| rows(line)).sum::<usize>() + status_rows;
+        let line_rows: usize = document.lines[..document.line]
+            .iter()
+            .map(|line| rows(line))
+            .sum();
+        let position = PROMPT_WIDTH + document.column;
+        let last_row = rows(&document.lines[document.line]) - 1;
+        let (row, column) = if position / width > last_row {
+            // The cursor is past a line that exactly fills its last row.
+            (last_row, width - 1)
+        } else {
+            (position / width, position % width)
+        };
+        self.cursor_row = line_rows + row;
+        self.last_row = total_rows - 1;
+        if self.last_row > self.cursor_row {
+            stdout.queue(MoveUp((self.last_row - self.cursor_row) as u16))?;
+        }
+        write!(stdout, "\r")?;
+        if column > 0 {
+            stdout.queue(MoveRight(column as u16))?;
+        }
+        stdout.flush()?;
+        Ok(())
+    }
+
+    /// Leave the cursor at the start of the row after the document.
+    fn finish(&mut self, stdout: &mut Stdout) -> crossterm::Result<()> {
+        if self.last_row > self.cursor_row {
+            stdout.queue(MoveDown((self.last_row - self.cursor_row) as u16))?;
+        }
+        write!(stdout, "\r\n")?;
+        stdout.flush()?;
+        Ok(())
+    }
+}
+
+fn write_prompt(stdout: &mut Stdout, first_line: bool) -> crossterm::Result<()> {
+    stdout.queue(SetForegroundColor(Color::Yellow))?;
+    stdout.queue(SetAttribute(Attribute::Bold))?;
+    write!(stdout, "silver ")?;
+    stdout.queue(SetForegroundColor(Color::Green))?;
+    write!(stdout, "{}", if first_line { "➤" } else { "|" })?;
+    stdout.queue(ResetColor)?;
+    stdout.queue(SetAttribute(Attribute::Reset))?;
+    write!(stdout, " ")?;
+    Ok(())
+}
+
+/// Write the part of `text` in `range`, coloured by the spans that classify
+/// it.
+fn write_highlighted(
+    stdout: &mut Stdout,
+    text: &str,
+    range: Range<usize>,
+    spans: &[ClassifiedSpan],
+) -> crossterm::Result<()> {
+    for span in spans {
+        let start = span.span().start.max(range.start);
+        let end = span.span().end.min(range.end);
+        if start >= end {
+            continue;
+        }
+        let unmatched = span.classification() == Classification::UnmatchedBracket;
+        if unmatched {
+            stdout.queue(SetAttribute(Attribute::Reverse))?;
+        }
+        match color(span.classification()) {
+            Some(color) => {
+                stdout.queue(SetForegroundColor(color))?;
+                write!(stdout, "{}", &text[start..end])?;
+                stdout.queue(ResetColor)?;
+            }
+            None => write!(stdout, "{}", &text[start..end])?,
+        }
+        if unmatched {
+            stdout.queue(SetAttribute(Attribute::Reset))?;
+        }
+    }
+    Ok(())
+}
+
+fn color(classification: Classification) -> Option<Color> {
+    match classification {
+        Classification::Number => Some(Color::Cyan),
+        Classification::String => Some(Color::Magenta),
+        Classification::Keyword => Some(Color::Blue),
+        Classification::Identifier => Some(Color::DarkYellow),
+        Classification::Operator => Some(Color::DarkGrey),
+        Classification::UnmatchedBracket | Classification::BadToken => Some(Color::Red),
+        Classification::Trivia => None,
+    }
+}
+
+/// The lines of a submission being edited, with the cursor among them.
+#[derive(Debug)]
+struct Document {
+    lines: Vec<String>,
+    /// The line holding the cursor.
+    line: usize,
+    /// How many characters into its line the cursor is.
+    column: usize,
+}
+
+impl Document {
+    fn new() -> Self {
+        Self {
+            lines: vec![String::new()],
+            line: 0,
+            column: 0,
+        }
+    }
+
+    fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    /// Replace the whole document with `text`, leaving the cursor at its end.
+    fn set_text(&mut self, text: &str) {
+        self.lines = text.split('\n').map(str::to_string).collect();
+        self.end_of_document();
+    }
+
+    fn end_of_document(&mut self) {
+        self.line = self.lines.len() - 1;
+        self.end();
+    }
+
+    fn current_line(&mut self) -> &mut String {
+        &mut self.lines[self.line]
+    }
+
+    /// The byte index into the current line of the cursor.
+    fn index(&self) -> usize {
+        let line = &self.lines[self.line];
+        line.char_indices()
+            .nth(self.column)
+            .map_or(line.len(), |(index, _)| index)
+    }
+
+    fn line_length(&self) -> usize {
+        self.lines[self.line].chars().count()
+    }
+
+    fn insert(&mut self, c: char) {
+        let index = self.index();
+        self.current_line().insert(index, c);
+        self.column += 1;
+    }
+
+    /// Split the current line at the cursor.
+    fn insert_line(&mut self) {
+        let index = self.index();
+        let rest = self.current_line().split_off(index);
+        self.line += 1;
+        self.column = 0;
+        self.lines.insert(self.line, rest);
+    }
+
+    /// Delete the character before the cursor, joining the line to the one
+    /// above when the cursor is at its start.
+    fn backspace(&mut self) {
+        if self.column > 0 {
+            self.column -= 1;
+            let index = self.index();
+            self.current_line().remove(index);
+        } else if self.line > 0 {
+            let line = self.lines.remove(self.line);
+            self.line -= 1;
+            self.column = self.line_length();
+            self.current_line().push_str(&line);
+        }
+    }
+
+    /// Delete the character under the cursor, joining the next line to this
+    /// one when the cursor is at its end.
+    fn delete(&mut self) {
+        if self.column < self.line_length() {
+            let index = self.index();
+            self.current_line().remove(index);
+        } else if self.line + 1 < self.lines.len() {
+            let line = self.lines.remove(self.line + 1);
+            self.current_line().push_str(&line);
+        }
+    }
+
+    fn left(&mut self) {
+        if self.column > 0 {
+            self.column -= 1;
+        } else if self.line > 0 {
+            self.line -= 1;
+            self.end();
+        }
+    }
+
+    fn right(&mut self) {
+        if self.column < self.line_length() {
+            self.column += 1;
+        } else if self.line + 1 < self.lines.len() {
+            self.line += 1;
+            self.column = 0;
+        }
+    }
+
+    fn home(&mut self) {
+        self.column = 0;
+    }
+
+    fn end(&mut self) {
+        self.column = self.line_length();
+    }
+
+    /// The byte index of the cursor in the text.
+    fn position(&self) -> usize {
+        let before: usize = self.lines[..self.line]
+            .iter()
+            .map(|line| line.len() + 1)
+            .sum();
+        before + self.index()
+    }
+
+    /// Replace the text in `range` with `text`, leaving the cursor after it.
+    fn replace(&mut self, range: Range<usize>, text: &str) {
+        let mut new_text = self.text();
+        new_text.replace_range(range.clone(), text);
+        self.set_text(&new_text);
+        self.move_to(range.start + text.len());
+    }
+
+    /// Move the cursor to the byte `index` of the text.
+    fn move_to(&mut self, index: usize) {
+        let mut start = 0;
+        for (line, text) in self.lines.iter().enumerate() {
+            if index <= start + text.len() {
+                self.line = line;
+                self.column = text[..index - start].chars().count();
+                return;
+            }
+            start += text.len() + 1;
+        }
+    }
+
+    /// Move to the line above, returning false if there is none.
+    fn up(&mut self) -> bool {
+        if self.line == 0 {
+            return false;
+        }
+        self.line -= 1;
+        self.column = self.column.min(self.line_length());
+        true
+    }
+
+    /// Move to the line below, returning false if there is none.
+    fn down(&mut self) -> bool {
+        if self.line + 1 == self.lines.len() {
+            return false;
+        }
+        self.line += 1;
+        self.column = self.column.min(self.line_length());
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edit(text: &str) -> Document {
+        let mut document = Document::new();
+        document.set_text(text);
+        document
+    }
+
+    #[test]
+    fn edits_within_a_line() {
+        let mut document = edit("ab");
+        document.left();
+        document.insert('é');
+        document.insert('x');
+        assert_eq!("aéxb", document.text());
+        document.backspace();
+        document.home();
+        document.delete();
+        assert_eq!("éb", document.text());
+        document.end();
+        document.insert('c');
+        assert_eq!("ébc", document.text());
+    }
+
+    #[test]
+    fn edits_across_lines() {
+        let mut document = edit("x = 1 +2");
+        document.left();
+        document.insert_line();
+        assert_eq!("x = 1 +\n2", document.text());
+        assert_eq!((1, 0), (document.line, document.column));
+
+        // Backspace and Delete join lines at their ends.
+        document.backspace();
+        assert_eq!("x = 1 +2", document.text());
+        assert_eq!((0, 7), (document.line, document.column));
+        document.insert_line();
+        document.left();
+        document.delete();
+        assert_eq!("x = 1 +2", document.text());
+
+        // Left and Right cross line ends.
+        let mut document = edit("ab\ncd");
+        document.home();
+        document.left();
+        assert_eq!((0, 2), (document.line, document.column));
+        document.right();
+        assert_eq!((1, 0), (document.line, document.column));
+    }
+
+    #[test]
+    fn replaces_text_around_the_cursor() {
+        let mut document = edit("x = 1 +\nma + 2");
+        document.up();
+        document.down();
+        document.home();
+        document.right();
+        document.right();
+        assert_eq!(10, document.position());
+        document.replace(8..10, "maximum");
+        assert_eq!("x = 1 +\nmaximum + 2", document.text());
+        assert_eq!((1, 7), (document.line, document.column));
+        assert_eq!(15, document.position());
+    }
+
+    #[test]
+    fn moves_between_lines() {
+        let mut document = edit("abcd\nx\nabc");
+        assert!(document.up());
+        assert_eq!((1, 1), (document.line, document.column));
+        assert!(document.up());
+        assert!(!document.up());
+        assert_eq!((0, 1), (document.line, document.column));
+        document.end();
+        assert!(document.down());
+        assert!(document.down());
+        assert!(!document.down());
+        assert_eq!((2, 1), (document.line, document.column));
+        document.move_to(6);
+        assert_eq!((1, 1), (document.line, document.column));
+        document.move_to(7);
+        assert_eq!((2, 0), (document.line, document.column));
+    }
+}
@@ -0,0 +1,232 @@
+use std::io::{self, Stdout, Write};
+
+use crossterm::{
+    style::{Color, ResetColor, SetForegroundColor},
+    ExecutableCommand,
+};
+use silver_language::analysis::{
+    cancellation_token::CancellationToken,
+    compilation::Compilation,
+    completion,
+    diagnostic::Diagnostic,
+    errors::{
+        error_reporter::ErrorReporter, null_error_reporter::NullErrorReporter,
+        string_error_reporter::StringErrorReporter,
+    },
+    global_scope::GlobalScope,
+    statistics::Statistics,
+    syntax::syntax_tree::SyntaxTree,
+};
+
+use crate::{
+    commands::{self, COMMANDS},
+    history::History,
+    line_editor::LineEditor,
+    view_options::ViewOptions,
+};
+
+/// The native stack of the thread that the REPL runs on. The evaluation
+/// recurses on it at every call, so it is far larger than a default thread's.
+pub(crate) const STACK_SIZE: usize = 1 << 30;
+
+/// How deeply calls may nest in a submission, which `STACK_SIZE` leaves
+/// room for.
+const MAX_CALL_DEPTH: usize = 10_000;
+
+/// A session of the REPL: what its submissions have declared and assigned so
+/// far, and how it shows them.
+pub(crate) struct Repl {
+    pub(crate) stdout: Stdout,
+    pub(crate) scope: GlobalScope,
+    pub(crate) view_options: ViewOptions,
+    /// The submissions that ran without errors, in order.
+    pub(crate) submissions: Vec<String>,
+    line_editor: LineEditor,
+    /// Whether errors are coloured, which only a terminal shows.
+    colored: bool,
+    /// Cancels the submission being evaluated.
+    cancellation_token: CancellationToken,
+}
+
+impl Repl {
+    pub(crate) fn new() -> Self {
+        Self {
+            stdout: io::stdout(),
+            scope: GlobalScope::new(),
+            view_options: ViewOptions::default(),
+            submissions: vec![],
+            line_editor: LineEditor::new(History::load()),
+            colored: true,
+            cancellation_token: CancellationToken::new(),
+        }
+    }
+
+    /// A session that only evaluates, writing plain text without colours,
+    /// and that leaves the saved history alone.
+    pub(crate) fn plain() -> Self {
+        Self {
+            stdout: io::stdout(),
+            scope: GlobalScope::new(),
+            view_options: ViewOptions::default(),
+            submissions: vec![],
+            line_editor: LineEditor::new(History::empty()),
+            colored: false,
+            cancellation_token: CancellationToken::new(),
+        }
+    }
+
+    pub(crate) fn run(&mut self) -> anyhow::Result<()> {
+        // While a line is edited, the terminal is in raw mode and Ctrl+C is a
+        // key press rather than a signal, so only an evaluation is stopped.
+        let cancellation_token = self.cancellation_token.clone();
+        ctrlc::set_handler(move || cancellation_token.cancel())?;
+        let commands: Vec<_> = COMMANDS
+            .iter()
+            .map(|command| (command.name, command.description))
+            .collect();
+        loop {
+            let scope = &self.scope;
+            let complete =
+                |text: &str, position| completion::complete(text, position, scope, &commands);
+            let text =
+                match self
+                    .line_editor
+                    .read_submission(&mut self.stdout, is_complete, complete)?
+                {
+                    Some(text) => text,
+                    None => break,
+                };
+            if text.trim().is_empty() {
+                break;
+            }
+            if text.starts_with('#') {
+                commands::run(self, &text)?;
+            } else {
+                self.evaluate(&text)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Run a submission and show its value, or the errors it reported.
+    pub(crate) fn evaluate(&mut self, text: &str) -> anyhow::Result<()> {
+        let mut error_reporter = StringErrorReporter::new();
+        let parse_tree = SyntaxTree::parse_str(text, &mut error_reporter);
+        if self.view_options.show_tree {
+            parse_tree.pretty_print(&mut self.stdout)?;
+        }
+        // The evaluation below reports the errors.
+        if self.view_options.show_program {
+            Compilation::new(&parse_tree, &mut NullErrorReporter::new())
+                .print_program(&self.scope, &mut self.stdout)?;
+        }
+        if self.view_options.show_source {
+            Compilation::new(&parse_tree, &mut NullErrorReporter::new())
+                .print_program_source(&self.scope, &mut self.stdout)?;
+        }
+        let mut compilation = Compilation::new(&parse_tree, &mut error_reporter);
+        compilation.set_max_call_depth(MAX_CALL_DEPTH);
+        // A Ctrl+C from before this submission does not cancel it.
+        self.cancellation_token.reset();
+        let value = compilation.evaluate_cancellable(&mut self.scope, &self.cancellation_token);
+        let statistics = compilation.statistics().clone();
+        if error_reporter.had_error() {
+            self.print_diagnostics(&parse_tree, error_reporter.errors())?;
+        } else {
+            if let Some(value) = value {
+                writeln!(self.stdout, "{}", value)?;
+            }
+            self.submissions.push(text.to_string());
+        }
+        if self.view_options.show_stats {
+            self.print_statistics(&statistics)?;
+        }
+        Ok(())
+    }
+
+    fn print_statistics(&mut self, statistics: &Statistics) -> anyhow::Result<()> {
+        writeln!(
+            self.stdout,
+            "lexing {:.2?}, parsing {:.2?}, binding {:.2?}, evaluation {:.2?}",
+            statistics.lexing_time(),
+            statistics.parsing_time(),
+            statistics.binding_time(),
+            statistics.evaluation_time()
+        )?;
+        writeln!(
+            self.stdout,
+            "{} nodes evaluated, at most {} variables held",
+            statistics.evaluated_nodes(),
+            statistics.peak_variables()
+        )?;
+        Ok(())
+    }
+
+    /// Show each error with the line it is on, highlighting where it is.
+    pub(crate) fn print_diagnostics(
+        &mut self,
+        parse_tree: &SyntaxTree,
+        errors: &[Diagnostic],
+    ) -> anyhow::Result<()> {
+        let text = parse_tree.text();
+        for error in errors {
+            let line_index = text.get_line_index(error.span().start);
+            let line_number = line_index + 1;
+            let line = &text.lines()[line_index];
+            let character = error.span().start - line.start() + 1;
+            self.set_color(Some(Color::Red))?;
+            writeln!(self.stdout)?;
+            writeln!(
+                self.stdout,
+                "({}, {}) ERROR: {}",
+                line_number,
+                character,
+                error.message()
+            )?;
+
+            // Only the first line of an error that spans several is shown.
+            let end = error.span().end.clamp(error.span().start, line.end());
+            let prefix = &text[line.start()..error.span().start];
+            let highlight = &text[error.span().start..end];
+            let suffix = &text[end..line.end()];
+
+            self.set_color(None)?;
+            write!(self.stdout, "    {}", prefix)?;
+            self.set_color(Some(Color::Red))?;
+            write!(self.stdout, "{}", highlight)?;
+            self.set_color(None)?;
+            write!(self.stdout, "{}", suffix)?;
+            writeln!(self.stdout)?;
+        }
+        Ok(())
+    }
+
+    /// Show an error that is not in the text of a submission.
+    pub(crate) fn print_error(&mut self, message: &str) -> anyhow::Result<()> {
+        self.set_color(Some(Color::Red))?;
+        writeln!(self.stdout, "{}", message)?;
+        self.set_color(None)?;
+        Ok(())
+    }
+
+    /// Switch to `color`, or back to the default with `None`, unless the
+    /// output is plain text.
+    fn set_color(&mut self, color: Option<Color>) -> crossterm::Result<()> {
+        if self.colored {
+            match color {
+                Some(color) => self.stdout.execute(SetForegroundColor(color))?,
+                None => self.stdout.execute(ResetColor)?,
+            };
+        }
+        Ok(())
+    }
+}
+
+/// Whether Enter should submit `text` rather than start a new line: it is a
+/// meta-command, it is complete even if it has errors, or it ends with a
+/// blank line to submit it regardless.
+fn is_complete(text: &str) -> bool {
+    text.starts_with('#')
+        || text.rsplit('\n').next().unwrap().trim().is_empty()
+        || SyntaxTree::is_complete(text)
+}
@@ -1,9 +1,7 @@
+#[derive(Default)]
 pub(crate) struct ViewOptions {
     pub(crate) show_tree: bool,
-}
-
-impl Default for ViewOptions {
-    fn default() -> Self {
-        Self { show_tree: false }
-    }
+    pub(crate) show_program: bool,
+    pub(crate) show_source: bool,
+    pub(crate) show_stats: bool,
 }